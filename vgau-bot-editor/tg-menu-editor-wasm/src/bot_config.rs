@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::WorkflowMetadata;
+use crate::graph::models::NodeId;
+
+/// Title used when the graph is exported without an imported title
+pub const DEFAULT_MENU_TITLE: &str = "Данные для кнопок для телеграмм бота Верхневолжского ГАУ";
+
+/// Version of the bot_data.json format written by the exporter
+pub const CURRENT_SCHEMA_VERSION: u32 = 3;
+
+/// Version assumed for files written before `schema_version` existed
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MenuItem {
+    pub text: String,
+    pub callback_data: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submenu: Option<Vec<MenuItem>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub documents: Option<Vec<Document>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<ImageContent>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<LinkButton>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<TextMessage>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<Vec<Contacts>>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Document {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_data: Option<String>,
+    pub url: String,
+}
+
+/// Photo sent when the menu item is opened
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ImageContent {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<String>,
+}
+
+/// Inline button that opens a web page
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LinkButton {
+    pub text: String,
+    pub url: String,
+}
+
+/// Standalone text message sent when the menu item is opened
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TextMessage {
+    pub text: String,
+}
+
+/// Contacts card sent when the menu item is opened
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Contacts {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone, Debug, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FaqItem {
+    pub question: String,
+    pub answer: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+/// Contents of bot_data.json, also the source of its JSON Schema
+#[derive(Serialize, Deserialize, JsonSchema, Default, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct BotMenuConfig {
+    #[serde(default = "legacy_schema_version")]
+    pub schema_version: u32,
+    pub title: String,
+    pub main_menu: Vec<MenuItem>,
+    #[serde(default)]
+    pub faq: Vec<FaqItem>,
+    #[serde(skip)]
+    pub workflow_metadata: WorkflowMetadata,
+    /// Node every exported menu item came from, by its callback_data. Kept
+    /// next to the export like `workflow_metadata`, the bot never sees it.
+    #[serde(skip)]
+    pub node_ids: BTreeMap<String, NodeId>,
+}
+
+fn legacy_schema_version() -> u32 {
+    LEGACY_SCHEMA_VERSION
+}
@@ -0,0 +1,240 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Node, NodeId, NodeType};
+use crate::graph::validation::telegram::CALLBACK_DATA_MAX_BYTES;
+
+/// Callbacks the bot handles itself, generated values must not collide with them
+const RESERVED: &[&str] = &["back_to_main", "back_to_faq"];
+
+/// Prefixes the bot matches before looking up menu items
+const RESERVED_PREFIXES: &[&str] = &["faq_", "back_to_", "doc_page_"];
+
+/// Longest generated value.
+///
+/// The bot wraps callback_data into `doc_page_{callback}_{page}` for document
+/// pages, so generated values leave room for the prefix and the page number.
+pub const GENERATED_MAX_BYTES: usize = CALLBACK_DATA_MAX_BYTES - "doc_page_".len() - "_999".len();
+
+/// Documents the bot lists on one page
+pub const DOCUMENTS_PER_PAGE: usize = 5;
+
+/// callback_data of the button opening `page` of an item's documents, pages count from 1
+pub fn document_page(callback_data: &str, page: usize) -> String {
+    format!("doc_page_{}_{}", callback_data, page)
+}
+
+/// Whether the bot handles `callback_data` itself instead of opening a menu item
+pub fn is_reserved(callback_data: &str) -> bool {
+    RESERVED.contains(&callback_data) || RESERVED_PREFIXES.iter().any(|prefix| callback_data.starts_with(prefix))
+}
+
+/// Transliterate Cyrillic text into lowercase ASCII.
+///
+/// Follows GOST 7.79-2000 system B (the ASCII variant of ISO 9), without the
+/// apostrophes it uses for the hard and soft signs.
+pub fn transliterate(text: &str) -> String {
+    let chars: Vec<char> = text.chars().flat_map(|c| c.to_lowercase()).collect();
+    let mut result = String::with_capacity(chars.len() * 2);
+    
+    for (index, c) in chars.iter().enumerate() {
+        let latin = match c {
+            'а' => "a",
+            'б' => "b",
+            'в' => "v",
+            'г' | 'ґ' => "g",
+            'д' => "d",
+            'е' => "e",
+            'ё' => "yo",
+            'є' => "ye",
+            'ж' => "zh",
+            'з' => "z",
+            'и' | 'і' => "i",
+            'ї' => "yi",
+            'й' => "j",
+            'к' => "k",
+            'л' => "l",
+            'м' => "m",
+            'н' => "n",
+            'о' => "o",
+            'п' => "p",
+            'р' => "r",
+            'с' => "s",
+            'т' => "t",
+            'у' | 'ў' => "u",
+            'ф' => "f",
+            'х' => "x",
+            // "c" before i, e, y and j, "cz" everywhere else
+            'ц' => match chars.get(index + 1) {
+                Some('и' | 'і' | 'е' | 'є' | 'ы' | 'й') => "c",
+                _ => "cz",
+            },
+            'ч' => "ch",
+            'ш' => "sh",
+            'щ' => "shh",
+            'ъ' | 'ь' => "",
+            'ы' => "y",
+            'э' => "e",
+            'ю' => "yu",
+            'я' => "ya",
+            other => {
+                result.push(*other);
+                continue;
+            },
+        };
+        result.push_str(latin);
+    }
+    
+    result
+}
+
+/// Turn a title into a callback_data slug, e.g. "Правила приёма" into `pravila_priyoma`
+pub fn slugify(text: &str) -> String {
+    let latin = transliterate(text);
+    let words: Vec<&str> = latin
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    
+    fit(&words.join("_"), "", GENERATED_MAX_BYTES)
+}
+
+/// Truncate `base` so that `base` followed by `suffix` fits in `max_bytes`,
+/// cutting the suffix as well once nothing is left of `base`
+fn fit(base: &str, suffix: &str, max_bytes: usize) -> String {
+    let mut base = base.to_string();
+    let mut suffix = suffix.to_string();
+    while base.len() + suffix.len() > max_bytes {
+        if base.pop().is_none() {
+            suffix.pop();
+        }
+    }
+    
+    format!("{}{}", base.trim_end_matches('_'), suffix)
+}
+
+/// Assigns unique callback_data to every menu item of a graph.
+///
+/// Values set on a node are kept as they are ("pinned"). Items with an empty
+/// callback_data get a slug of their title; when that is already taken the
+/// parent title is appended, and a number after that. Items are visited in
+/// export order, so the same graph always gets the same values.
+pub struct CallbackDataGenerator<'a> {
+    graph: &'a SimpleNodeGraph,
+    taken: HashSet<String>,
+    visited: HashSet<NodeId>,
+    assigned: HashMap<NodeId, String>,
+}
+
+impl<'a> CallbackDataGenerator<'a> {
+    /// callback_data of every menu item, pinned or generated
+    pub fn assign(graph: &'a SimpleNodeGraph) -> HashMap<NodeId, String> {
+        Self::assign_except(graph, None)
+    }
+    
+    /// Fresh callback_data for one menu item, ignoring the value pinned on it
+    pub fn regenerate(graph: &'a SimpleNodeGraph, node_id: NodeId) -> Option<String> {
+        Self::assign_except(graph, Some(node_id)).remove(&node_id)
+    }
+    
+    fn assign_except(graph: &'a SimpleNodeGraph, unpinned: Option<NodeId>) -> HashMap<NodeId, String> {
+        let mut generator = Self {
+            graph,
+            taken: RESERVED.iter().map(|s| s.to_string()).collect(),
+            visited: HashSet::new(),
+            assigned: HashMap::new(),
+        };
+        
+        // Pinned values of items and documents win over generated ones
+        for node in graph.nodes() {
+            if !matches!(node.node_type, NodeType::MenuItem | NodeType::Document) || Some(node.id()) == unpinned {
+                continue;
+            }
+            let pinned = Self::pinned(node);
+            if !pinned.is_empty() {
+                generator.taken.insert(pinned.clone());
+                if node.node_type == NodeType::MenuItem {
+                    generator.assigned.insert(node.id(), pinned);
+                }
+            }
+        }
+        
+        for root_id in graph.get_root_menu_nodes() {
+            generator.visit(root_id, None);
+        }
+        
+        // Items only reachable through a sub_menu cycle
+        for node in graph.nodes() {
+            if node.node_type == NodeType::MenuItem {
+                generator.visit(node.id(), None);
+            }
+        }
+        
+        generator.assigned
+    }
+    
+    fn visit(&mut self, node_id: NodeId, parent_id: Option<NodeId>) {
+        if !self.visited.insert(node_id) {
+            return;
+        }
+        
+        if !self.assigned.contains_key(&node_id) {
+            let Some(node) = self.graph.get_node_data(node_id) else {
+                return;
+            };
+            let callback_data = self.generate(node, parent_id);
+            self.taken.insert(callback_data.clone());
+            self.assigned.insert(node_id, callback_data);
+        }
+        
+        for child_id in self.graph.get_child_menu_nodes(node_id) {
+            self.visit(child_id, Some(node_id));
+        }
+    }
+    
+    fn generate(&self, node: &Node, parent_id: Option<NodeId>) -> String {
+        let mut base = slugify(&Self::title(node));
+        if base.is_empty() {
+            base = "item".to_string();
+        }
+        if is_reserved(&base) {
+            base = fit(&format!("item_{}", base), "", GENERATED_MAX_BYTES);
+        }
+        
+        let mut candidates = vec![base.clone()];
+        let parent_slug = parent_id
+            .and_then(|id| self.graph.get_node_data(id))
+            .map(|parent| slugify(&Self::title(parent)))
+            // The first word is enough to tell siblings of different parents apart
+            .and_then(|slug| slug.split('_').next().map(str::to_string))
+            .filter(|slug| !slug.is_empty())
+            // A long parent word must leave room for the item's own title
+            .map(|mut slug| {
+                slug.truncate(GENERATED_MAX_BYTES / 2);
+                slug
+            });
+        if let Some(parent_slug) = &parent_slug {
+            candidates.push(fit(&base, &format!("_{}", parent_slug), GENERATED_MAX_BYTES));
+        }
+        
+        if let Some(free) = candidates.iter().find(|c| !c.is_empty() && !self.taken.contains(*c)) {
+            return free.clone();
+        }
+        
+        let last = candidates.pop().unwrap_or(base);
+        (2..)
+            .map(|n| fit(&last, &format!("_{}", n), GENERATED_MAX_BYTES))
+            .find(|candidate| !self.taken.contains(candidate))
+            .expect("numbered candidates are unbounded")
+    }
+    
+    fn pinned(node: &Node) -> String {
+        node.find_param("callback_data").map(|p| p.get_text()).unwrap_or_default()
+    }
+    
+    /// Text shown on the button, the same the exporter uses
+    fn title(node: &Node) -> String {
+        let name = node.find_param("name").map(|p| p.get_text()).unwrap_or_default();
+        if name.is_empty() { node.get_title().to_string() } else { name }
+    }
+}
@@ -0,0 +1,386 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::bot_config::{BotMenuConfig, Contacts, Document, FaqItem, ImageContent, LinkButton, MenuItem, TextMessage, CURRENT_SCHEMA_VERSION};
+use crate::callback_data::CallbackDataGenerator;
+use crate::graph::{Diagnostic, GraphValidator, Severity, SimpleNodeGraph, WorkflowMetadata};
+use crate::graph::models::{Node, NodeId, NodeType};
+
+/// Utility functions to export the node graph as bot menu data
+pub struct DataExporter;
+
+impl DataExporter {
+    /// Build the bot menu structure from the node graph
+    pub fn export_config(graph: &SimpleNodeGraph, title: &str, workflow_metadata: WorkflowMetadata) -> BotMenuConfig {
+        let mut config = BotMenuConfig {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            title: title.to_string(),
+            main_menu: Vec::new(),
+            faq: Vec::new(),
+            workflow_metadata,
+            node_ids: BTreeMap::new(),
+        };
+        
+        // Find all root menu items (nodes without input connections)
+        let root_nodes = graph.get_root_menu_nodes();
+        
+        let callbacks = CallbackDataGenerator::assign(graph);
+        
+        // Convert root nodes to menu items
+        for node_id in root_nodes {
+            if let Some(menu_item) = Self::build_menu_item(graph, node_id, &callbacks, &mut HashSet::new()) {
+                config.main_menu.push(menu_item);
+            }
+        }
+        
+        let nodes_by_callback: HashMap<&str, NodeId> = callbacks.iter()
+            .map(|(node_id, callback_data)| (callback_data.as_str(), *node_id))
+            .collect();
+        Self::collect_node_ids(&config.main_menu, &nodes_by_callback, &mut config.node_ids);
+        
+        // Find all FAQ items
+        let faq_nodes = graph.get_faq_nodes();
+        
+        // Convert FAQ nodes to FAQ items
+        for node_id in faq_nodes {
+            if let Some(faq_item) = Self::build_faq_item(graph, node_id) {
+                config.faq.push(faq_item);
+            }
+        }
+        
+        config
+    }
+    
+    /// Build the bot menu structure, refusing to when the validator finds errors
+    pub fn export_checked(graph: &SimpleNodeGraph, title: &str, workflow_metadata: WorkflowMetadata) -> Result<BotMenuConfig, Vec<Diagnostic>> {
+        let diagnostics = GraphValidator::validate(graph);
+        if GraphValidator::has_errors(&diagnostics) {
+            return Err(diagnostics.into_iter().filter(|d| d.severity == Severity::Error).collect());
+        }
+        
+        Ok(Self::export_config(graph, title, workflow_metadata))
+    }
+    
+    /// Export the node graph as pretty-printed bot_data.json
+    pub fn export_json(graph: &SimpleNodeGraph, title: &str, workflow_metadata: WorkflowMetadata) -> Result<String, String> {
+        let config = Self::export_checked(graph, title, workflow_metadata).map_err(|errors| {
+            let messages: Vec<String> = errors.iter().map(|e| e.message.clone()).collect();
+            format!("Graph has errors: {}", messages.join("; "))
+        })?;
+        
+        serde_json::to_string_pretty(&config)
+            .map_err(|e| format!("Error serializing JSON: {}", e))
+    }
+    
+    /// Copy menu items with their whole submenus, documents and content as a
+    /// bot_data.json fragment: one `MenuItem`, or a list of them for several
+    /// subtrees. Items inside another copied subtree are not repeated.
+    pub fn export_fragment(graph: &SimpleNodeGraph, node_ids: &[NodeId]) -> Result<String, String> {
+        let items: Vec<NodeId> = node_ids.iter().copied()
+            .filter(|id| graph.get_node_data(*id).is_some_and(|node| node.node_type == NodeType::MenuItem))
+            .collect();
+        
+        let mut descendants = HashMap::new();
+        for item in &items {
+            let below = Self::descendants(graph, *item);
+            // A cycle anywhere below, not only through the copied item, has no end to copy
+            let in_cycle = |id: &NodeId| Self::descendants(graph, *id).contains(id);
+            if in_cycle(item) || below.iter().any(in_cycle) {
+                return Err("Error copying menu items: submenu contains a cycle".to_string());
+            }
+            descendants.insert(*item, below);
+        }
+        
+        let roots: Vec<NodeId> = items.iter().copied()
+            .filter(|id| !items.iter().any(|other| descendants[other].contains(id)))
+            .collect();
+        
+        let callbacks = CallbackDataGenerator::assign(graph);
+        let menu_items: Vec<MenuItem> = roots.iter()
+            .filter_map(|id| Self::build_menu_item(graph, *id, &callbacks, &mut HashSet::new()))
+            .collect();
+        
+        let json = match menu_items.as_slice() {
+            [] => return Err("No menu items to copy".to_string()),
+            [item] => serde_json::to_string_pretty(item),
+            items => serde_json::to_string_pretty(items),
+        };
+        json.map_err(|e| format!("Error serializing menu items: {}", e))
+    }
+    
+    /// Node of every exported menu item and its submenus, by callback_data
+    fn collect_node_ids(items: &[MenuItem], nodes_by_callback: &HashMap<&str, NodeId>, node_ids: &mut BTreeMap<String, NodeId>) {
+        for item in items {
+            if let Some(node_id) = nodes_by_callback.get(item.callback_data.as_str()) {
+                node_ids.insert(item.callback_data.clone(), *node_id);
+            }
+            Self::collect_node_ids(item.submenu.as_deref().unwrap_or_default(), nodes_by_callback, node_ids);
+        }
+    }
+    
+    /// Menu items below a node, following sub_menu connections
+    fn descendants(graph: &SimpleNodeGraph, node_id: NodeId) -> HashSet<NodeId> {
+        let mut found = HashSet::new();
+        let mut stack = graph.get_child_menu_nodes(node_id);
+        while let Some(id) = stack.pop() {
+            if found.insert(id) {
+                stack.extend(graph.get_child_menu_nodes(id));
+            }
+        }
+        found
+    }
+    
+    /// Recursively build menu items. `ancestors` are the items above this one,
+    /// an item that is its own ancestor closes a sub_menu cycle and is left out.
+    fn build_menu_item(
+        graph: &SimpleNodeGraph,
+        node_id: NodeId,
+        callbacks: &HashMap<NodeId, String>,
+        ancestors: &mut HashSet<NodeId>,
+    ) -> Option<MenuItem> {
+        let node_data = graph.get_node_data(node_id)?;
+        if ancestors.contains(&node_id) {
+            return None;
+        }
+        
+        // Create menu item from node data
+        let mut menu_item = MenuItem {
+            text: node_data.get_title().to_string(),
+            callback_data: callbacks.get(&node_id).cloned().unwrap_or_default(),
+            description: None,
+            url: None,
+            submenu: None,
+            documents: None,
+            data: None,
+            text_content: None,
+            images: None,
+            links: None,
+            messages: None,
+            contacts: None,
+        };
+        
+        // Parse parameters from node
+        for param in node_data.get_params() {
+            match param.kind() {
+                "name" if !param.get_text().is_empty() => {
+                    menu_item.text = param.get_text();
+                },
+                "description" => {
+                    let desc = param.get_text();
+                    if !desc.is_empty() {
+                        menu_item.description = Some(desc);
+                    }
+                },
+                "url" => {
+                    let url = param.get_text();
+                    if !url.is_empty() {
+                        menu_item.url = Some(url);
+                    }
+                },
+                "text_content" => {
+                    let content = param.get_text();
+                    if !content.is_empty() {
+                        menu_item.text_content = Some(content);
+                    }
+                },
+                "data" => {
+                    let data = param.get_text();
+                    if !data.is_empty() {
+                        if let Ok(json) = serde_json::from_str(&data) {
+                            menu_item.data = Some(json);
+                        }
+                    }
+                },
+                _ => {}
+            }
+        }
+        
+        // Find child nodes (submenu items)
+        let children = graph.get_child_menu_nodes(node_id);
+        if !children.is_empty() {
+            let mut submenu = Vec::new();
+            ancestors.insert(node_id);
+            for child_id in children {
+                if let Some(child_item) = Self::build_menu_item(graph, child_id, callbacks, ancestors) {
+                    submenu.push(child_item);
+                }
+            }
+            ancestors.remove(&node_id);
+            
+            if !submenu.is_empty() {
+                menu_item.submenu = Some(submenu);
+            }
+        }
+        
+        // Find document nodes
+        let documents = graph.get_documents_for_node(node_id);
+        if !documents.is_empty() {
+            let mut doc_items = Vec::new();
+            for doc_id in documents {
+                if let Some(doc) = Self::build_document(graph, doc_id) {
+                    doc_items.push(doc);
+                }
+            }
+            
+            if !doc_items.is_empty() {
+                menu_item.documents = Some(doc_items);
+            }
+        }
+        
+        // Find content nodes, each kind goes to its own list
+        for content_id in graph.get_content_for_node(node_id) {
+            let Some(content) = graph.get_node_data(content_id) else {
+                continue;
+            };
+            
+            match content.node_type {
+                NodeType::Image => {
+                    if let Some(image) = Self::build_image(content) {
+                        menu_item.images.get_or_insert_with(Vec::new).push(image);
+                    }
+                },
+                NodeType::Link => {
+                    if let Some(link) = Self::build_link(content) {
+                        menu_item.links.get_or_insert_with(Vec::new).push(link);
+                    }
+                },
+                NodeType::TextContent => {
+                    let text = content.find_param("text").map(|p| p.get_text()).unwrap_or_default();
+                    if !text.is_empty() {
+                        menu_item.messages.get_or_insert_with(Vec::new).push(TextMessage { text });
+                    }
+                },
+                NodeType::Contacts => {
+                    if let Some(contacts) = Self::build_contacts(content) {
+                        menu_item.contacts.get_or_insert_with(Vec::new).push(contacts);
+                    }
+                },
+                _ => {}
+            }
+        }
+        
+        Some(menu_item)
+    }
+    
+    /// Build an image entry, images without a URL are skipped
+    fn build_image(node: &Node) -> Option<ImageContent> {
+        let url = node.find_param("url").map(|p| p.get_text()).unwrap_or_default();
+        let caption = node.find_param("caption").map(|p| p.get_text()).unwrap_or_default();
+        
+        if url.is_empty() {
+            return None;
+        }
+        
+        Some(ImageContent {
+            url,
+            caption: (!caption.is_empty()).then_some(caption),
+        })
+    }
+    
+    /// Build a link button, the node title is used when the text is empty
+    fn build_link(node: &Node) -> Option<LinkButton> {
+        let mut text = node.find_param("text").map(|p| p.get_text()).unwrap_or_default();
+        let url = node.find_param("url").map(|p| p.get_text()).unwrap_or_default();
+        
+        if text.is_empty() {
+            text = node.get_title().to_string();
+        }
+        
+        if url.is_empty() {
+            return None;
+        }
+        
+        Some(LinkButton { text, url })
+    }
+    
+    /// Build a contacts card, cards with no filled fields are skipped
+    fn build_contacts(node: &Node) -> Option<Contacts> {
+        let field = |id: &str| {
+            let value = node.find_param(id).map(|p| p.get_text()).unwrap_or_default();
+            (!value.is_empty()).then_some(value)
+        };
+        
+        let contacts = Contacts {
+            address: field("address"),
+            phone: field("phone"),
+            email: field("email"),
+            website: field("website"),
+        };
+        
+        if contacts == Contacts::default() {
+            None
+        } else {
+            Some(contacts)
+        }
+    }
+    
+    /// Build a document entry from a document node
+    fn build_document(graph: &SimpleNodeGraph, node_id: NodeId) -> Option<Document> {
+        let doc_data = graph.get_node_data(node_id)?;
+        
+        let mut doc = Document {
+            text: doc_data.get_title().to_string(),
+            callback_data: None,
+            url: String::new(),
+        };
+        
+        for param in doc_data.get_params() {
+            match param.kind() {
+                "text" if !param.get_text().is_empty() => {
+                    doc.text = param.get_text();
+                },
+                "callback_data" => {
+                    let cb = param.get_text();
+                    if !cb.is_empty() {
+                        doc.callback_data = Some(cb);
+                    }
+                },
+                "url" => {
+                    doc.url = param.get_text();
+                },
+                _ => {}
+            }
+        }
+        
+        // Only add if has URL
+        if !doc.url.is_empty() {
+            Some(doc)
+        } else {
+            None
+        }
+    }
+    
+    /// Build FAQ items
+    fn build_faq_item(graph: &SimpleNodeGraph, node_id: NodeId) -> Option<FaqItem> {
+        let node_data = graph.get_node_data(node_id)?;
+        
+        // Create FAQ item
+        let mut faq_item = FaqItem {
+            question: node_data.get_title().to_string(),
+            answer: String::new(),
+            tags: Vec::new(),
+        };
+        
+        // Parse parameters
+        for param in node_data.get_params() {
+            match param.kind() {
+                "question" if !param.get_text().is_empty() => {
+                    faq_item.question = param.get_text();
+                },
+                "answer" => {
+                    faq_item.answer = param.get_text();
+                },
+                "tag" | "tags" => {
+                    faq_item.tags = param.get_list();
+                },
+                _ => {}
+            }
+        }
+        
+        // Only return if has non-empty answer
+        if !faq_item.answer.is_empty() {
+            Some(faq_item)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use eframe::egui;
+
+use crate::graph::models::NodeId;
+use crate::i18n::Language;
+
+/// Screen distance within which a dragged node sticks to a guide, in pixels.
+/// Divide by the zoom to get the distance in graph coordinates.
+pub const SNAP_DISTANCE: f32 = 8.0;
+
+/// Positions lines from two rectangles are considered equal within
+const SAME_LINE: f32 = 0.5;
+
+/// Edge the selected nodes are lined up along
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alignment {
+    Left,
+    Center,
+    Top,
+}
+
+impl Alignment {
+    pub const ALL: [Alignment; 3] = [Alignment::Left, Alignment::Center, Alignment::Top];
+    
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            Alignment::Left => "align.left",
+            Alignment::Center => "align.center",
+            Alignment::Top => "align.top",
+        })
+    }
+}
+
+/// A line showing which nodes a dragged node lines up with, in graph coordinates
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Guide {
+    /// Whether the line runs top to bottom at `x == position`,
+    /// otherwise it runs left to right at `y == position`
+    pub vertical: bool,
+    pub position: f32,
+    /// Where the line starts and ends along its direction
+    pub from: f32,
+    pub to: f32,
+}
+
+/// Left edge, centre and right edge, or top edge, centre and bottom edge
+fn lines(rect: egui::Rect, vertical: bool) -> [f32; 3] {
+    if vertical {
+        [rect.min.x, rect.center().x, rect.max.x]
+    } else {
+        [rect.min.y, rect.center().y, rect.max.y]
+    }
+}
+
+fn extent(rect: egui::Rect, vertical: bool) -> (f32, f32) {
+    if vertical {
+        (rect.min.y, rect.max.y)
+    } else {
+        (rect.min.x, rect.max.x)
+    }
+}
+
+/// Offset that lines a dragged rectangle up with the nodes around it,
+/// and the guides to draw for it.
+///
+/// Edges and centres are compared on each axis separately, the closest pair
+/// within `threshold` wins. An axis without such a pair snaps the top-left
+/// corner to the grid when `grid` is given.
+pub fn snap(moving: egui::Rect, others: &[egui::Rect], threshold: f32, grid: Option<f32>) -> (egui::Vec2, Vec<Guide>) {
+    let mut offset = egui::Vec2::ZERO;
+    let mut guides = Vec::new();
+    
+    for vertical in [true, false] {
+        let own = lines(moving, vertical);
+        let closest = others.iter()
+            .flat_map(|other| lines(*other, vertical))
+            .flat_map(|line| own.iter().map(move |own| line - own))
+            .filter(|shift| shift.abs() <= threshold)
+            .min_by(|a, b| a.abs().total_cmp(&b.abs()));
+        
+        let shift = match (closest, grid) {
+            (Some(shift), _) => shift,
+            (None, Some(spacing)) => {
+                let corner = own[0];
+                (corner / spacing).round() * spacing - corner
+            },
+            (None, None) => 0.0,
+        };
+        if vertical {
+            offset.x = shift;
+        } else {
+            offset.y = shift;
+        }
+        
+        if closest.is_some() {
+            guides.extend(guides_along(moving.translate(offset), others, vertical));
+        }
+    }
+    
+    (offset, guides)
+}
+
+/// One guide for every line of `moving` that other rectangles share,
+/// long enough to reach all of them
+fn guides_along(moving: egui::Rect, others: &[egui::Rect], vertical: bool) -> Vec<Guide> {
+    let mut guides: Vec<Guide> = Vec::new();
+    for position in lines(moving, vertical) {
+        let (from, to) = extent(moving, vertical);
+        let mut guide = Guide { vertical, position, from, to };
+        let mut shared = false;
+        
+        for other in others {
+            if lines(*other, vertical).iter().any(|line| (line - position).abs() <= SAME_LINE) {
+                let (from, to) = extent(*other, vertical);
+                guide.from = guide.from.min(from);
+                guide.to = guide.to.max(to);
+                shared = true;
+            }
+        }
+        if shared && !guides.iter().any(|g| (g.position - position).abs() <= SAME_LINE) {
+            guides.push(guide);
+        }
+    }
+    guides
+}
+
+/// New positions lining up the nodes. Left and top use the outermost node,
+/// centres meet in the middle of the nodes' bounding box.
+pub fn align(rects: &[(NodeId, egui::Rect)], alignment: Alignment) -> Vec<(NodeId, egui::Pos2)> {
+    let Some(bounds) = rects.iter().map(|(_, rect)| *rect).reduce(|a, b| a.union(b)) else {
+        return Vec::new();
+    };
+    
+    rects.iter()
+        .map(|(id, rect)| {
+            let position = match alignment {
+                Alignment::Left => egui::pos2(bounds.min.x, rect.min.y),
+                Alignment::Center => egui::pos2(bounds.center().x - rect.width() / 2.0, rect.min.y),
+                Alignment::Top => egui::pos2(rect.min.x, bounds.min.y),
+            };
+            (*id, position)
+        })
+        .collect()
+}
+
+/// New positions leaving the same vertical gap between neighbouring nodes.
+/// The topmost and the bottommost node stay where they are.
+pub fn distribute_vertically(rects: &[(NodeId, egui::Rect)]) -> Vec<(NodeId, egui::Pos2)> {
+    let mut sorted = rects.to_vec();
+    sorted.sort_by(|(_, a), (_, b)| a.min.y.total_cmp(&b.min.y));
+    let (Some((_, first)), Some((_, last))) = (sorted.first(), sorted.last()) else {
+        return Vec::new();
+    };
+    if sorted.len() < 3 {
+        return Vec::new();
+    }
+    
+    let heights: f32 = sorted.iter().map(|(_, rect)| rect.height()).sum();
+    let gap = (last.max.y - first.min.y - heights) / (sorted.len() - 1) as f32;
+    
+    let mut y = first.min.y;
+    sorted.iter()
+        .map(|(id, rect)| {
+            let position = egui::pos2(rect.min.x, y);
+            y += rect.height() + gap;
+            (*id, position)
+        })
+        .collect()
+}
@@ -1,9 +1,51 @@
+use std::collections::{HashMap, HashSet};
+
 use eframe::egui;
 
-use crate::graph::models::{Node, NodeType, Connection};
+use crate::graph::alignment::{self, Alignment, Guide, SNAP_DISTANCE};
+use crate::graph::models::{Node, NodeId, NodeType, Connection, ParameterValue};
 use crate::graph::history::{HistoryManager, GraphSnapshot};
+use crate::graph::layout::TreeLayout;
 use crate::graph::rendering::GraphRenderer;
 use crate::graph::operations::GraphOperations;
+use crate::graph::project::GraphState;
+use crate::graph::validation::{Diagnostic, GraphValidator};
+use crate::callback_data::CallbackDataGenerator;
+use crate::export_data::DataExporter;
+use crate::i18n::Language;
+use crate::import_data::DataImporter;
+use crate::settings::{ConnectionStyle, EditorSettings};
+use crate::theme::Theme;
+
+/// How long the nodes take to move to a new layout
+const LAYOUT_ANIMATION_SECONDS: f32 = 0.4;
+
+/// Largest size of the minimap in the corner of the canvas
+const MINIMAP_SIZE: egui::Vec2 = egui::vec2(220.0, 160.0);
+
+/// Space left around nodes when fitting them into the view, in graph coordinates
+const FIT_MARGIN: f32 = 40.0;
+
+/// Screen distance within which the pointer picks a connection
+const CONNECTION_PICK_DISTANCE: f32 = 6.0;
+
+/// Nodes moving from their old positions to the ones of an automatic layout
+struct LayoutAnimation {
+    start: HashMap<NodeId, egui::Pos2>,
+    target: HashMap<NodeId, egui::Pos2>,
+    /// From 0 to 1
+    progress: f32,
+}
+
+/// Selected nodes being dragged with the mouse
+struct NodeDrag {
+    /// Positions of the dragged nodes when the drag started
+    start: HashMap<NodeId, egui::Pos2>,
+    /// Pointer movement since then, in graph coordinates
+    moved: egui::Vec2,
+    /// Guides the nodes are snapped to
+    guides: Vec<Guide>,
+}
 
 /// The main node graph that manages nodes and connections
 pub struct SimpleNodeGraph {
@@ -11,22 +53,54 @@ pub struct SimpleNodeGraph {
     nodes: Vec<Node>,
     /// All connections between nodes
     connections: Vec<Connection>,
-    /// Currently active (selected) node
-    pub active_node: Option<usize>,
+    /// Node that was clicked last, the one single-node actions apply to
+    pub active_node: Option<NodeId>,
+    /// All selected nodes, including the active one
+    selected_nodes: HashSet<NodeId>,
+    /// Connection picked on the canvas, selecting nodes deselects it
+    selected_connection: Option<Connection>,
+    /// Connection the open context menu was opened on
+    menu_connection: Option<Connection>,
+    /// Screen position where a rubber-band selection started
+    rubber_band: Option<egui::Pos2>,
+    /// Nodes being dragged, snapped positions are computed from where they started
+    node_drag: Option<NodeDrag>,
     /// Offset for panning the graph
     drag_offset: egui::Vec2,
     /// Zoom level of the graph
     zoom: f32,
-    /// Node that has been copied to clipboard
-    clipboard: Option<Node>,
+    /// Fragment copied last, also sent to the system clipboard
+    clipboard: Option<String>,
     /// Port that is currently being connected (node_id, port_id, is_input)
-    connecting_port: Option<(usize, String, bool)>,
+    connecting_port: Option<(NodeId, String, bool)>,
     /// Whether a context menu is currently open
     context_menu_open: bool,
-    /// Node that is currently being edited in a parameters dialog
-    editing_node: Option<usize>,
+    /// Whether "Редактировать параметры" asked for the inspector to be shown
+    inspector_requested: bool,
+    /// Why the last paste on the canvas failed, shown in the status bar
+    paste_error: Option<String>,
+    /// Screen area the graph was drawn in last frame
+    viewport: egui::Rect,
     /// History manager for undo/redo operations
     history_manager: HistoryManager,
+    /// Nesting of `batch` calls, snapshots are only taken outside of them
+    batch_depth: usize,
+    /// Bumped whenever nodes or connections may have changed
+    revision: u64,
+    /// Validation results and the revision they were computed for
+    diagnostics: Option<(u64, Vec<Diagnostic>)>,
+    /// Automatic layout that is still being animated
+    layout_animation: Option<LayoutAnimation>,
+    /// Whether the minimap is drawn over the canvas
+    pub show_minimap: bool,
+    /// Whether the grid is drawn behind the nodes
+    show_grid: bool,
+    /// Whether dragged nodes snap to the grid
+    snap_to_grid: bool,
+    /// How connections are drawn
+    connection_style: ConnectionStyle,
+    /// Language of the texts the graph makes up, such as diagnostics
+    language: Language,
 }
 
 impl Default for SimpleNodeGraph {
@@ -35,13 +109,29 @@ impl Default for SimpleNodeGraph {
             nodes: Vec::new(),
             connections: Vec::new(),
             active_node: None,
+            selected_nodes: HashSet::new(),
+            selected_connection: None,
+            menu_connection: None,
+            rubber_band: None,
+            node_drag: None,
             drag_offset: egui::Vec2::ZERO,
             zoom: 1.0,
             clipboard: None,
             connecting_port: None,
             context_menu_open: false,
-            editing_node: None,
+            inspector_requested: false,
+            paste_error: None,
+            viewport: egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(800.0, 600.0)),
             history_manager: HistoryManager::new(30), // Default history limit of 30 snapshots
+            batch_depth: 0,
+            revision: 0,
+            diagnostics: None,
+            layout_animation: None,
+            show_minimap: true,
+            show_grid: true,
+            snap_to_grid: false,
+            connection_style: ConnectionStyle::default(),
+            language: Language::default(),
         }
     }
 }
@@ -63,13 +153,13 @@ impl SimpleNodeGraph {
         // Create initial menu node
         let main_menu_id = self.add_menu_item(
             egui::pos2(100.0, 100.0),
-            "Главное меню".to_string()
+            self.language.tr("node.sample.main_menu").to_string()
         );
         
         // Create submenu node
         let submenu_id = self.add_menu_item(
             egui::pos2(400.0, 100.0),
-            "Подменю: О университете".to_string()
+            self.language.tr("node.sample.submenu").to_string()
         );
         
         // Connect main menu to submenu
@@ -81,7 +171,7 @@ impl SimpleNodeGraph {
         // Create a FAQ item
         self.add_faq_item(
             egui::pos2(100.0, 300.0),
-            "Часто задаваемый вопрос".to_string()
+            self.language.tr("node.sample.faq").to_string()
         );
     }
 
@@ -92,8 +182,8 @@ impl SimpleNodeGraph {
     }
 
     /// Add a menu item node at the specified position
-    pub fn add_menu_item(&mut self, position: egui::Pos2, title: String) -> usize {
-        let id = self.next_node_id();
+    pub fn add_menu_item(&mut self, position: egui::Pos2, title: String) -> NodeId {
+        let id = NodeId::new();
         let node = GraphOperations::create_menu_item(id, title, position);
         
         self.nodes.push(node);
@@ -103,8 +193,8 @@ impl SimpleNodeGraph {
     }
 
     /// Add an FAQ item node at the specified position
-    pub fn add_faq_item(&mut self, position: egui::Pos2, title: String) -> usize {
-        let id = self.next_node_id();
+    pub fn add_faq_item(&mut self, position: egui::Pos2, title: String) -> NodeId {
+        let id = NodeId::new();
         let node = GraphOperations::create_faq_item(id, title, position);
         
         self.nodes.push(node);
@@ -113,8 +203,25 @@ impl SimpleNodeGraph {
         id
     }
 
+    /// Add a content node (image, link, text or contacts) at the specified position
+    pub fn add_content_node(&mut self, node_type: NodeType, position: egui::Pos2, title: String) -> Result<NodeId, String> {
+        let id = NodeId::new();
+        let node = match node_type {
+            NodeType::Image => GraphOperations::create_image(id, title, position),
+            NodeType::Link => GraphOperations::create_link(id, title, position),
+            NodeType::TextContent => GraphOperations::create_text_content(id, title, position),
+            NodeType::Contacts => GraphOperations::create_contacts(id, title, position),
+            other => return Err(format!("{} is not a content node type", other.as_str())),
+        };
+        
+        self.nodes.push(node);
+        self.save_state();
+        
+        Ok(id)
+    }
+
     /// Connect two nodes if the connection is valid
-    pub fn connect_nodes(&mut self, from_node: usize, from_port: &str, to_node: usize, to_port: &str) -> bool {
+    pub fn connect_nodes(&mut self, from_node: NodeId, from_port: &str, to_node: NodeId, to_port: &str) -> bool {
         // Don't connect a node to itself
         if from_node == to_node {
             return false;
@@ -144,13 +251,16 @@ impl SimpleNodeGraph {
             return false;
         }
         
-        // Create the connection
-        let connection = GraphOperations::create_connection(
+        // Create the connection, after the children the port already has
+        let mut connection = GraphOperations::create_connection(
             from_node,
             from_port.to_string(),
             to_node,
             to_port.to_string()
         );
+        connection.order = self.child_connections(from_node, from_port)
+            .last()
+            .map_or(0, |last| last.order() + 1);
         
         self.connections.push(connection);
         self.save_state();
@@ -159,7 +269,7 @@ impl SimpleNodeGraph {
     }
 
     /// Delete a node and all its connections
-    pub fn delete_node(&mut self, node_id: usize) {
+    pub fn delete_node(&mut self, node_id: NodeId) {
         // Remove the node
         self.nodes.retain(|node| node.id() != node_id);
         
@@ -174,12 +284,364 @@ impl SimpleNodeGraph {
                 self.active_node = None;
             }
         }
+        self.selected_nodes.remove(&node_id);
+        
+        self.save_state();
+    }
+
+    /// Ids of the selected nodes, in graph order
+    pub fn selected_nodes(&self) -> Vec<NodeId> {
+        self.nodes.iter()
+            .map(|node| node.id())
+            .filter(|id| self.selected_nodes.contains(id))
+            .collect()
+    }
+
+    /// Check if a node is selected
+    pub fn is_selected(&self, node_id: NodeId) -> bool {
+        self.selected_nodes.contains(&node_id)
+    }
+
+    /// Select a node and make it active, replacing the selection unless `additive`
+    pub fn select_node(&mut self, node_id: NodeId, additive: bool) {
+        if !additive {
+            self.selected_nodes.clear();
+        }
+        self.selected_nodes.insert(node_id);
+        self.active_node = Some(node_id);
+        self.selected_connection = None;
+    }
+
+    /// Add a node to the selection or remove it, as shift/ctrl-click does
+    pub fn toggle_node_selection(&mut self, node_id: NodeId) {
+        if self.selected_nodes.remove(&node_id) {
+            if self.active_node == Some(node_id) {
+                self.active_node = None;
+            }
+        } else {
+            self.select_node(node_id, true);
+        }
+    }
+
+    /// Select every node of the graph, the first one becomes the active node
+    pub fn select_all_nodes(&mut self) {
+        self.selected_nodes = self.nodes.iter().map(|node| node.id()).collect();
+        self.active_node = self.nodes.first().map(|node| node.id());
+        self.selected_connection = None;
+    }
+
+    /// Deselect all nodes and the selected connection
+    pub fn clear_selection(&mut self) {
+        self.selected_nodes.clear();
+        self.active_node = None;
+        self.selected_connection = None;
+    }
+
+    /// Select the nodes overlapping a rectangle in graph coordinates
+    pub fn select_nodes_in_rect(&mut self, rect: egui::Rect, additive: bool) {
+        if !additive {
+            self.selected_nodes.clear();
+        }
+        for node in &self.nodes {
+            if rect.intersects(egui::Rect::from_min_size(node.position, node.size)) {
+                self.selected_nodes.insert(node.id());
+            }
+        }
+        self.selected_connection = None;
+    }
+
+    /// Move the selected nodes as one undo step
+    pub fn move_selected_nodes(&mut self, delta: egui::Vec2) {
+        if self.selected_nodes.is_empty() {
+            return;
+        }
+        self.translate_selected_nodes(delta);
+        self.save_state();
+    }
+
+    fn translate_selected_nodes(&mut self, delta: egui::Vec2) {
+        for node in &mut self.nodes {
+            if self.selected_nodes.contains(&node.id()) {
+                node.position += delta;
+            }
+        }
+    }
+
+    /// Move the selection by a pointer movement in graph coordinates while it
+    /// is dragged. Unless `free`, the selection sticks to the edges and centres
+    /// of other nodes and, with snapping turned on, to the grid. The snapping
+    /// distance is the same on screen at every zoom level. Nothing is recorded
+    /// until `finish_node_drag`.
+    pub fn drag_selected_nodes(&mut self, delta: egui::Vec2, free: bool) {
+        if self.selected_nodes.is_empty() {
+            return;
+        }
+        let selected = &self.selected_nodes;
+        let drag = self.node_drag.get_or_insert_with(|| NodeDrag {
+            start: self.nodes.iter()
+                .filter(|node| selected.contains(&node.id()))
+                .map(|node| (node.id(), node.position))
+                .collect(),
+            moved: egui::Vec2::ZERO,
+            guides: Vec::new(),
+        });
+        drag.moved += delta;
+        
+        let moving = self.nodes.iter()
+            .filter_map(|node| drag.start.get(&node.id()).map(|start| egui::Rect::from_min_size(*start + drag.moved, node.size)))
+            .reduce(|a, b| a.union(b));
+        let (offset, guides) = match moving {
+            Some(moving) if !free => {
+                let others: Vec<egui::Rect> = self.nodes.iter()
+                    .filter(|node| !drag.start.contains_key(&node.id()))
+                    .map(|node| node.rect())
+                    .collect();
+                let grid = self.snap_to_grid.then_some(GraphRenderer::GRID_SPACING);
+                alignment::snap(moving, &others, SNAP_DISTANCE / self.zoom, grid)
+            },
+            _ => (egui::Vec2::ZERO, Vec::new()),
+        };
+        drag.guides = guides;
+        
+        for node in &mut self.nodes {
+            if let Some(start) = drag.start.get(&node.id()) {
+                node.position = *start + drag.moved + offset;
+            }
+        }
+    }
+
+    /// Record a drag started with `drag_selected_nodes` as one undo step
+    pub fn finish_node_drag(&mut self) {
+        if self.node_drag.take().is_some() {
+            self.save_state();
+        }
+    }
+
+    /// Guides the dragged nodes are lined up with, in graph coordinates
+    pub fn drag_guides(&self) -> &[Guide] {
+        self.node_drag.as_ref().map(|drag| drag.guides.as_slice()).unwrap_or_default()
+    }
+
+    /// Line the selected nodes up as one undo step, returns whether any node moved
+    pub fn align_selected(&mut self, alignment: Alignment) -> bool {
+        let positions = alignment::align(&self.selected_rects(), alignment);
+        self.move_nodes_to(positions)
+    }
+
+    /// Leave the same vertical gap between the selected nodes as one undo step.
+    /// Needs at least three nodes, returns whether any node moved.
+    pub fn distribute_selected_vertically(&mut self) -> bool {
+        let positions = alignment::distribute_vertically(&self.selected_rects());
+        self.move_nodes_to(positions)
+    }
+
+    fn selected_rects(&self) -> Vec<(NodeId, egui::Rect)> {
+        self.nodes.iter()
+            .filter(|node| self.selected_nodes.contains(&node.id()))
+            .map(|node| (node.id(), node.rect()))
+            .collect()
+    }
+
+    fn move_nodes_to(&mut self, positions: Vec<(NodeId, egui::Pos2)>) -> bool {
+        self.finish_layout_animation();
+        let mut moved = false;
+        for (node_id, position) in positions {
+            if let Some(node) = self.nodes.iter_mut().find(|node| node.id() == node_id) {
+                if node.position != position {
+                    node.position = position;
+                    moved = true;
+                }
+            }
+        }
+        if moved {
+            self.save_state();
+        }
+        moved
+    }
+
+    /// Take over the editor settings that concern the canvas
+    pub fn apply_settings(&mut self, settings: &EditorSettings) {
+        self.show_grid = settings.show_grid;
+        self.snap_to_grid = settings.snap_to_grid;
+        self.connection_style = settings.connection_style;
+        self.history_manager.set_size_limit(settings.history_depth);
+        if self.language != settings.language {
+            self.language = settings.language;
+            // Diagnostics are worded in the language
+            self.revision += 1;
+        }
+    }
+    
+    /// Language of the texts the graph makes up, such as diagnostics
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    /// Style connections are drawn in
+    pub fn connection_style(&self) -> ConnectionStyle {
+        self.connection_style
+    }
+
+    /// Delete the selected nodes and their connections as one undo step,
+    /// returns how many nodes were deleted
+    pub fn delete_selected_nodes(&mut self) -> usize {
+        let selected = std::mem::take(&mut self.selected_nodes);
+        let before = self.nodes.len();
+        
+        self.nodes.retain(|node| !selected.contains(&node.id()));
+        self.connections.retain(|conn| {
+            !selected.contains(&conn.from_node()) && !selected.contains(&conn.to_node())
+        });
+        if self.active_node.is_some_and(|id| selected.contains(&id)) {
+            self.active_node = None;
+        }
+        
+        let deleted = before - self.nodes.len();
+        if deleted > 0 {
+            self.save_state();
+        }
+        deleted
+    }
+
+    /// Copy the selected nodes and the connections between them as one undo
+    /// step, then select the copies
+    pub fn duplicate_selected_nodes(&mut self) -> Vec<NodeId> {
+        let mut new_ids = HashMap::new();
+        let mut copies = Vec::new();
+        
+        for node in self.nodes.iter().filter(|node| self.selected_nodes.contains(&node.id())) {
+            let mut copy = node.clone();
+            copy.id = NodeId::new();
+            copy.position += egui::vec2(30.0, 30.0);
+            new_ids.insert(node.id(), copy.id());
+            copies.push(copy);
+        }
+        if copies.is_empty() {
+            return Vec::new();
+        }
+        
+        let connections: Vec<Connection> = self.connections.iter()
+            .filter_map(|conn| {
+                let from = new_ids.get(&conn.from_node())?;
+                let to = new_ids.get(&conn.to_node())?;
+                let mut copy = GraphOperations::create_connection(*from, conn.from_port().to_string(), *to, conn.to_port().to_string());
+                copy.order = conn.order();
+                Some(copy)
+            })
+            .collect();
+        
+        let ids: Vec<NodeId> = copies.iter().map(|node| node.id()).collect();
+        self.nodes.extend(copies);
+        self.connections.extend(connections);
+        
+        self.selected_nodes = ids.iter().copied().collect();
+        self.active_node = ids.first().copied();
+        self.save_state();
+        ids
+    }
+
+    /// Arrange nodes as a tree right away, as one undo step. Only the nodes in
+    /// `node_ids` are moved when given.
+    pub fn apply_layout(&mut self, node_ids: Option<&HashSet<NodeId>>) {
+        self.finish_layout_animation();
+        let target = TreeLayout::compute(self, node_ids);
+        if target.is_empty() {
+            return;
+        }
+        
+        for node in &mut self.nodes {
+            if let Some(position) = target.get(&node.id()) {
+                node.position = *position;
+            }
+        }
+        self.save_state();
+    }
+
+    /// Start moving the nodes to a tree layout, or only the selected ones.
+    /// The move is animated while drawing and undone as one step.
+    pub fn auto_layout(&mut self, selection_only: bool) -> bool {
+        self.finish_layout_animation();
+        let scope = (selection_only && !self.selected_nodes.is_empty()).then(|| self.selected_nodes.clone());
+        let target = TreeLayout::compute(self, scope.as_ref());
+        if target.is_empty() {
+            return false;
+        }
+        
+        let start = self.nodes.iter()
+            .filter(|node| target.contains_key(&node.id()))
+            .map(|node| (node.id(), node.position))
+            .collect();
+        self.layout_animation = Some(LayoutAnimation {
+            start,
+            target,
+            progress: 0.0,
+        });
+        true
+    }
+
+    /// Whether an automatic layout is still being animated
+    pub fn is_animating_layout(&self) -> bool {
+        self.layout_animation.is_some()
+    }
+
+    /// Move the nodes of a running layout animation to their final positions
+    pub fn finish_layout_animation(&mut self) {
+        if let Some(animation) = self.layout_animation.as_mut() {
+            animation.progress = 1.0;
+            self.step_layout_animation(0.0);
+        }
+    }
+
+    fn step_layout_animation(&mut self, dt: f32) {
+        let Some(animation) = self.layout_animation.as_mut() else {
+            return;
+        };
+        animation.progress = (animation.progress + dt / LAYOUT_ANIMATION_SECONDS).min(1.0);
+        
+        // Ease out, so nodes slow down as they arrive
+        let t = 1.0 - (1.0 - animation.progress).powi(3);
+        for node in &mut self.nodes {
+            if let (Some(start), Some(target)) = (animation.start.get(&node.id()), animation.target.get(&node.id())) {
+                node.position = start.lerp(*target, t);
+            }
+        }
+        
+        if animation.progress >= 1.0 {
+            self.layout_animation = None;
+            self.save_state();
+        }
+    }
+
+    /// Remove all nodes and connections as a single undoable step
+    pub fn clear(&mut self) {
+        self.replace_with(Self::default());
+    }
+
+    /// Empty graph in the language of this one, to build nodes aside before
+    /// they are swapped in with [`SimpleNodeGraph::replace_with`]
+    pub fn scratch(&self) -> Self {
+        Self {
+            language: self.language,
+            ..Self::default()
+        }
+    }
+
+    /// Take over the nodes and connections of `other` as a single undoable step
+    pub fn replace_with(&mut self, other: SimpleNodeGraph) {
+        self.nodes = other.nodes;
+        self.connections = other.connections;
+        self.active_node = None;
+        self.selected_nodes.clear();
+        self.selected_connection = None;
+        self.connecting_port = None;
+        self.node_drag = None;
+        self.layout_animation = None;
         
         self.save_state();
     }
 
     /// Delete a connection between two nodes
-    pub fn delete_connection(&mut self, from_node: usize, from_port: &str, to_node: usize, to_port: &str) {
+    pub fn delete_connection(&mut self, from_node: NodeId, from_port: &str, to_node: NodeId, to_port: &str) {
         self.connections.retain(|conn| {
             !(conn.from_node() == from_node && 
               conn.to_node() == to_node && 
@@ -190,18 +652,118 @@ impl SimpleNodeGraph {
         self.save_state();
     }
 
-    /// Get the next available node ID
-    fn next_node_id(&self) -> usize {
-        self.nodes.iter().map(|n| n.id()).max().unwrap_or(0) + 1
+    /// Connection drawn under a screen position, the closest one when several
+    /// are near. Nodes hide the connections behind them.
+    pub fn connection_at(&self, pos: egui::Pos2) -> Option<&Connection> {
+        // Nodes are drawn at `position * zoom`
+        let graph_pos = (pos.to_vec2() / self.zoom).to_pos2();
+        if self.nodes.iter().any(|node| egui::Rect::from_min_size(node.position, node.size).contains(graph_pos)) {
+            return None;
+        }
+        
+        self.connections.iter()
+            .filter_map(|conn| {
+                let (from, to) = GraphRenderer::connection_endpoints(conn, &self.nodes, self.zoom)?;
+                let distance = GraphRenderer::distance_to_link(pos, from, to, self.connection_style, self.zoom);
+                (distance <= CONNECTION_PICK_DISTANCE).then_some((conn, distance))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(conn, _)| conn)
+    }
+
+    /// Select a connection instead of the selected nodes
+    pub fn select_connection(&mut self, connection: &Connection) {
+        self.selected_nodes.clear();
+        self.active_node = None;
+        self.selected_connection = Some(connection.clone());
+    }
+
+    /// The selected connection, as long as it is still part of the graph
+    pub fn selected_connection(&self) -> Option<&Connection> {
+        let selected = self.selected_connection.as_ref()?;
+        self.connections.iter().find(|conn| conn.same_link(selected))
+    }
+
+    /// Delete the selected connection as one undo step
+    pub fn delete_selected_connection(&mut self) -> bool {
+        let Some(selected) = self.selected_connection.take() else {
+            return false;
+        };
+        let before = self.connections.len();
+        self.connections.retain(|conn| !conn.same_link(&selected));
+        if self.connections.len() == before {
+            return false;
+        }
+        
+        self.save_state();
+        true
+    }
+
+    /// Nodes a connection can be moved to, keeping where it starts
+    pub fn retarget_candidates(&self, connection: &Connection) -> Vec<NodeId> {
+        self.nodes.iter()
+            .map(|node| node.id())
+            .filter(|node_id| self.retarget_port(connection, *node_id).is_some())
+            .collect()
+    }
+
+    /// Connect the start of a connection to another node instead, as one undo
+    /// step. The connection keeps its place among its siblings.
+    pub fn retarget_connection(&mut self, connection: &Connection, node_id: NodeId) -> bool {
+        let Some(to_port) = self.retarget_port(connection, node_id) else {
+            return false;
+        };
+        let Some(conn) = self.connections.iter_mut().find(|conn| conn.same_link(connection)) else {
+            return false;
+        };
+        conn.to_node = node_id;
+        conn.to_port = to_port;
+        
+        let retargeted = conn.clone();
+        if self.selected_connection.as_ref().is_some_and(|selected| selected.same_link(connection)) {
+            self.selected_connection = Some(retargeted);
+        }
+        self.save_state();
+        true
+    }
+
+    /// Input port of `node_id` that a connection could be moved to
+    fn retarget_port(&self, connection: &Connection, node_id: NodeId) -> Option<String> {
+        if node_id == connection.from_node() || node_id == connection.to_node() {
+            return None;
+        }
+        let from = self.get_node_data(connection.from_node())?;
+        let to = self.get_node_data(node_id)?;
+        
+        to.inputs.iter()
+            .map(|port| port.id())
+            .find(|port_id| {
+                GraphOperations::is_valid_connection(from, connection.from_port(), to, port_id)
+                    && !self.connections.iter().any(|conn| {
+                        conn.from_node() == connection.from_node() && conn.from_port() == connection.from_port() &&
+                        conn.to_node() == node_id && conn.to_port() == *port_id
+                    })
+            })
+            .map(str::to_string)
     }
 
     /// Draw the graph
     pub fn draw(&mut self, ui: &mut egui::Ui) {
         // Calculate available space
         let available_rect = ui.available_rect_before_wrap();
+        self.viewport = available_rect;
+        let palette = Theme::current(ui.ctx()).palette();
+        let language = Language::current(ui.ctx());
         
         // Draw grid in the background
-        GraphRenderer::draw_grid(ui, available_rect, self.zoom);
+        if self.show_grid {
+            GraphRenderer::draw_grid(ui, available_rect, self.zoom);
+        }
+        
+        if self.layout_animation.is_some() {
+            self.step_layout_animation(ui.input(|i| i.stable_dt));
+            ui.ctx().request_repaint();
+        }
         
         // Store zoom level in UI memory for node rendering
         ui.memory_mut(|mem| mem.data.insert_temp(egui::Id::new("graph_zoom"), self.zoom));
@@ -266,6 +828,62 @@ impl SimpleNodeGraph {
             self.save_state();
         }
         
+        // Shift or Ctrl add to the selection instead of replacing it
+        let additive = ui.input(|i| i.modifiers.shift || i.modifiers.command);
+        
+        // Connections not covered by nodes are picked while nothing is dragged
+        let hovered_connection = if response.hovered() && self.rubber_band.is_none() && self.node_drag.is_none() {
+            ui.input(|i| i.pointer.hover_pos()).and_then(|pos| self.connection_at(pos)).cloned()
+        } else {
+            None
+        };
+        if hovered_connection.is_some() {
+            ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::PointingHand);
+        }
+        
+        // Clicking a connection selects it, clicking the empty canvas deselects everything
+        if response.clicked() {
+            if let Some(connection) = &hovered_connection {
+                self.select_connection(connection);
+            } else if !additive {
+                self.clear_selection();
+            }
+        }
+        
+        // Dragging on the empty canvas selects the nodes under a rubber band
+        if response.drag_started_by(egui::PointerButton::Primary) {
+            self.rubber_band = ui.input(|i| i.pointer.press_origin());
+        }
+        if let Some(origin) = self.rubber_band {
+            let current = ui.input(|i| i.pointer.interact_pos()).unwrap_or(origin);
+            let band = egui::Rect::from_two_pos(origin, current);
+            
+            ui.painter().rect(
+                band,
+                0.0,
+                palette.selection.gamma_multiply(0.16),
+                egui::Stroke::new(1.0, palette.selection),
+            );
+            
+            if !ui.input(|i| i.pointer.primary_down()) {
+                // Nodes are drawn at `position * zoom`
+                let rect = egui::Rect::from_min_max(
+                    (band.min.to_vec2() / self.zoom).to_pos2(),
+                    (band.max.to_vec2() / self.zoom).to_pos2(),
+                );
+                self.select_nodes_in_rect(rect, additive);
+                self.rubber_band = None;
+            }
+        }
+        
+        // A context menu opened on a connection is about that connection
+        if response.secondary_clicked() {
+            self.menu_connection = hovered_connection.clone();
+            if let Some(connection) = &hovered_connection {
+                self.select_connection(connection);
+            }
+        }
+        
         // Handle context menu
         response.context_menu(|ui| {
             // First ensure the context menu flag is set
@@ -273,42 +891,69 @@ impl SimpleNodeGraph {
             
             ui.set_min_width(150.0);
             ui.style_mut().spacing.item_spacing = egui::vec2(0.0, 8.0);
-            ui.style_mut().visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(45, 55, 72);
-            ui.style_mut().visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(59, 130, 246);
-            ui.style_mut().visuals.widgets.active.bg_fill = egui::Color32::from_rgb(29, 78, 216);
+            ui.style_mut().visuals.widgets.inactive.bg_fill = palette.surface;
+            ui.style_mut().visuals.widgets.hovered.bg_fill = palette.accent_hover;
+            ui.style_mut().visuals.widgets.active.bg_fill = palette.accent;
+            
+            if let Some(connection) = self.menu_connection.clone() {
+                self.draw_connection_menu(ui, &connection);
+                return;
+            }
             
             // Make the active node None when clicking on the background
             self.active_node = None;
             
             ui.add_space(4.0);
             
-            if ui.button(egui::RichText::new("Добавить пункт меню").size(14.0).color(egui::Color32::WHITE)).clicked() {
+            if ui.button(egui::RichText::new(language.tr("canvas.add_menu_item")).size(14.0)).clicked() {
                 let pointer_pos = ui.input(|i| i.pointer.interact_pos()).unwrap_or(egui::pos2(100.0, 100.0));
-                self.add_menu_item(pointer_pos, "Новый пункт меню".to_string());
+                self.add_menu_item(pointer_pos, language.tr("node.new_menu_item").to_string());
                 ui.close_menu();
             }
             
             ui.add_space(2.0);
             
-            if ui.button(egui::RichText::new("Добавить FAQ").size(14.0).color(egui::Color32::WHITE)).clicked() {
+            if ui.button(egui::RichText::new(language.tr("canvas.add_faq")).size(14.0)).clicked() {
                 let pointer_pos = ui.input(|i| i.pointer.interact_pos()).unwrap_or(egui::pos2(100.0, 100.0));
-                self.add_faq_item(pointer_pos, "Новый FAQ".to_string());
+                self.add_faq_item(pointer_pos, language.tr("node.new_faq").to_string());
                 ui.close_menu();
             }
             
-            if let Some(node) = &self.clipboard {
+            for (node_type, label, title) in [
+                (NodeType::Image, "canvas.add_image", "node.image"),
+                (NodeType::Link, "canvas.add_link", "node.link"),
+                (NodeType::TextContent, "canvas.add_text", "node.text"),
+                (NodeType::Contacts, "canvas.add_contacts", "node.contacts"),
+            ] {
                 ui.add_space(2.0);
                 
-                if ui.button(egui::RichText::new("Вставить узел").size(14.0).color(egui::Color32::WHITE)).clicked() {
+                if ui.button(egui::RichText::new(language.tr(label)).size(14.0)).clicked() {
                     let pointer_pos = ui.input(|i| i.pointer.interact_pos()).unwrap_or(egui::pos2(100.0, 100.0));
-                    
-                    let mut new_node = node.clone();
-                    new_node.id = self.next_node_id();
-                    new_node.position = pointer_pos;
-                    
-                    self.nodes.push(new_node);
-                    self.save_state();
-                    
+                    let _ = self.add_content_node(node_type, pointer_pos, language.tr(title).to_string());
+                    ui.close_menu();
+                }
+            }
+            
+            ui.add_space(2.0);
+            
+            if ui.button(egui::RichText::new(language.tr("canvas.pin_all_callback_data")).size(14.0)).clicked() {
+                self.pin_all_callback_data();
+                ui.close_menu();
+            }
+            
+            ui.add_space(2.0);
+            
+            if ui.button(egui::RichText::new(language.tr("canvas.auto_layout")).size(14.0)).clicked() {
+                self.auto_layout(false);
+                ui.close_menu();
+            }
+            
+            if let Some(fragment) = self.clipboard.clone() {
+                ui.add_space(2.0);
+                
+                if ui.button(egui::RichText::new(language.tr("canvas.paste")).size(14.0)).clicked() {
+                    let pointer_pos = ui.input(|i| i.pointer.interact_pos()).unwrap_or(egui::pos2(100.0, 100.0));
+                    self.paste_on_canvas(&fragment, None, (pointer_pos.to_vec2() / self.zoom).to_pos2());
                     ui.close_menu();
                 }
             }
@@ -316,9 +961,17 @@ impl SimpleNodeGraph {
             ui.add_space(4.0);
         });
         
-        // Draw connections
+        // Draw connections, the selected and the hovered one over a highlight
+        let selected_connection = self.selected_connection().cloned();
         for connection in &self.connections {
-            GraphRenderer::draw_connection(ui, connection, &self.nodes);
+            let highlight = if selected_connection.as_ref().is_some_and(|selected| selected.same_link(connection)) {
+                Some(palette.selection.gamma_multiply(0.6))
+            } else if hovered_connection.as_ref().is_some_and(|hovered| hovered.same_link(connection)) {
+                Some(palette.selection.gamma_multiply(0.3))
+            } else {
+                None
+            };
+            GraphRenderer::draw_connection(ui, connection, &self.nodes, self.connection_style, highlight);
         }
         
         // Draw pending connection if any
@@ -331,7 +984,7 @@ impl SimpleNodeGraph {
                     let to_pos = ui.input(|i| i.pointer.interact_pos()).unwrap_or(from_pos);
                     
                     // Default color for pending connection
-                    let mut connection_color = egui::Color32::from_rgba_unmultiplied(255, 255, 255, 128);
+                    let mut connection_color = palette.connection_pending;
                     
                     // Check if hovering over a compatible port
                     let mut _hovering_compatible_port = false;
@@ -378,11 +1031,11 @@ impl SimpleNodeGraph {
                                         if let Some(from_port_data) = from_port_data {
                                             if from_port_data.port_type() == port.port_type() {
                                                 // Compatible port - green connection
-                                                connection_color = egui::Color32::from_rgba_unmultiplied(100, 255, 100, 200);
+                                                connection_color = palette.connection_valid;
                                                 _hovering_compatible_port = true;
                                             } else {
                                                 // Incompatible port type - red connection
-                                                connection_color = egui::Color32::from_rgba_unmultiplied(255, 100, 100, 200);
+                                                connection_color = palette.connection_invalid;
                                             }
                                         }
                                         break;
@@ -393,44 +1046,57 @@ impl SimpleNodeGraph {
                     }
                     
                     // Draw pending connection with appropriate color
-                    GraphRenderer::draw_bezier_connection(
+                    GraphRenderer::draw_link(
                         ui,
                         if is_input { to_pos } else { from_pos },
                         if is_input { from_pos } else { to_pos },
-                        connection_color
+                        connection_color,
+                        self.connection_style,
                     );
                 }
             }
         }
         
-        // Store nodes to be moved
-        let mut nodes_to_move = Vec::new();
+        // Selected nodes move together, and are recorded once the drag ends
+        let mut selection_delta = egui::Vec2::ZERO;
+        let mut selection_dropped = false;
         let mut nodes_to_handle_context = Vec::new();
         
         // Create a clone of nodes to avoid borrowing issues
         let nodes_clone = self.nodes.clone();
         
+        // Flag nodes with problems while editing
+        let diagnostics = self.diagnostics().to_vec();
+        
         // Draw nodes
         for node in &nodes_clone {
-            let is_active = self.active_node.map_or(false, |id| id == node.id());
+            let is_active = self.selected_nodes.contains(&node.id());
             let mut response = None;
             
             GraphRenderer::draw_node(ui, node, is_active, &mut response);
             
+            let node_diagnostics: Vec<_> = diagnostics.iter().filter(|d| d.node_id == node.id()).collect();
+            GraphRenderer::draw_node_badge(ui, node, &node_diagnostics);
+            
             // Handle node interaction
             if let Some(response) = response {
                 if response.clicked() {
-                    self.active_node = Some(node.id());
+                    if additive {
+                        self.toggle_node_selection(node.id());
+                    } else {
+                        self.select_node(node.id(), false);
+                    }
                 }
                 
-                if response.dragged() {
-                    // Move the node
-                    if let Some(active_id) = self.active_node {
-                        if active_id == node.id() {
-                            let delta = response.drag_delta();
-                            nodes_to_move.push((node.id(), delta));
-                        }
-                    }
+                // Dragging a node outside the selection drags just that node
+                if response.drag_started_by(egui::PointerButton::Primary) && !self.is_selected(node.id()) {
+                    self.select_node(node.id(), additive);
+                }
+                if response.dragged_by(egui::PointerButton::Primary) && self.is_selected(node.id()) {
+                    selection_delta += response.drag_delta() / self.zoom;
+                }
+                if response.drag_released_by(egui::PointerButton::Primary) {
+                    selection_dropped = true;
                 }
                 
                 // Store node id and response for context menu handling after loop
@@ -438,28 +1104,67 @@ impl SimpleNodeGraph {
             }
         }
         
-        // Apply node movements
-        for (node_id, delta) in nodes_to_move {
-            if let Some(node) = self.nodes.iter_mut().find(|n| n.id() == node_id) {
-                node.position += delta;
-            }
+        // Apply node movements, Alt moves the nodes without snapping
+        if selection_delta != egui::Vec2::ZERO {
+            let free = ui.input(|i| i.modifiers.alt);
+            self.drag_selected_nodes(selection_delta, free);
         }
+        if selection_dropped {
+            self.finish_node_drag();
+        }
+        GraphRenderer::draw_guides(ui, self.drag_guides(), self.zoom);
         
         // Handle context menus for nodes outside the borrow
         for (node_id, response) in nodes_to_handle_context {
             response.context_menu(|ui| {
                 self.context_menu_open = true;
+                // Right-clicking outside the selection selects just that node
+                if !self.is_selected(node_id) {
+                    self.select_node(node_id, false);
+                }
                 self.active_node = Some(node_id);
                 
                 ui.set_min_width(150.0);
                 ui.style_mut().spacing.item_spacing = egui::vec2(0.0, 8.0);
-                ui.style_mut().visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(45, 55, 72);
-                ui.style_mut().visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(59, 130, 246);
-                ui.style_mut().visuals.widgets.active.bg_fill = egui::Color32::from_rgb(29, 78, 216);
+                ui.style_mut().visuals.widgets.inactive.bg_fill = palette.surface;
+                ui.style_mut().visuals.widgets.hovered.bg_fill = palette.accent_hover;
+                ui.style_mut().visuals.widgets.active.bg_fill = palette.accent;
                 
                 ui.add_space(4.0);
                 
-                if ui.button(egui::RichText::new("Удалить узел").size(14.0).color(egui::Color32::WHITE)).clicked() {
+                let selected = self.selected_nodes.len();
+                if selected > 1 {
+                    if ui.button(egui::RichText::new(language.tr_args("canvas.delete_selected", &[("count", &selected)])).size(14.0)).clicked() {
+                        self.delete_selected_nodes();
+                        ui.close_menu();
+                    }
+                    
+                    ui.add_space(2.0);
+                    
+                    if ui.button(egui::RichText::new(language.tr_args("canvas.duplicate_selected", &[("count", &selected)])).size(14.0)).clicked() {
+                        self.duplicate_selected_nodes();
+                        ui.close_menu();
+                    }
+                    
+                    ui.add_space(2.0);
+                    
+                    ui.menu_button(egui::RichText::new(language.tr("canvas.align")).size(14.0), |ui| {
+                        for alignment in Alignment::ALL {
+                            if ui.button(alignment.label(language)).clicked() {
+                                self.align_selected(alignment);
+                                ui.close_menu();
+                            }
+                        }
+                        if ui.add_enabled(selected > 2, egui::Button::new(language.tr("canvas.distribute_vertically"))).clicked() {
+                            self.distribute_selected_vertically();
+                            ui.close_menu();
+                        }
+                    });
+                    
+                    ui.separator();
+                }
+                
+                if ui.button(egui::RichText::new(language.tr("canvas.delete_node")).size(14.0)).clicked() {
                     // Store for later deletion to avoid borrow issues
                     ui.memory_mut(|mem| mem.data.insert_temp(egui::Id::new("node_to_delete"), node_id));
                     ui.close_menu();
@@ -467,45 +1172,162 @@ impl SimpleNodeGraph {
                 
                 ui.add_space(2.0);
                 
-                if ui.button(egui::RichText::new("Копировать узел").size(14.0).color(egui::Color32::WHITE)).clicked() {
-                    // Lookup in original collection and clone
-                    if let Some(found_node) = self.nodes.iter().find(|n| n.id() == node_id) {
-                        self.clipboard = Some(found_node.clone());
+                if ui.button(egui::RichText::new(language.tr("canvas.copy")).size(14.0))
+                    .on_hover_text(language.tr("canvas.copy_hint"))
+                    .clicked()
+                {
+                    if let Ok(fragment) = self.copy_selection() {
+                        ui.output_mut(|o| o.copied_text = fragment);
                     }
                     ui.close_menu();
                 }
                 
+                let is_menu_item = self.get_node_data(node_id).is_some_and(|node| node.node_type == NodeType::MenuItem);
+                if let Some(fragment) = self.clipboard.clone().filter(|_| is_menu_item) {
+                    ui.add_space(2.0);
+                    
+                    if ui.button(egui::RichText::new(language.tr("canvas.paste_as_child")).size(14.0)).clicked() {
+                        let position = self.get_node_data(node_id).map(|node| node.position).unwrap_or_default();
+                        self.paste_on_canvas(&fragment, Some(node_id), position);
+                        ui.close_menu();
+                    }
+                }
+                
                 ui.add_space(2.0);
                 
-                if ui.button(egui::RichText::new("Редактировать параметры").size(14.0).color(egui::Color32::WHITE)).clicked() {
-                    self.editing_node = Some(node_id);
+                if ui.button(egui::RichText::new(language.tr("canvas.edit_parameters")).size(14.0)).clicked() {
+                    self.select_node(node_id, false);
+                    self.inspector_requested = true;
                     ui.close_menu();
                 }
                 
+                if let Some(callback_data) = self.callback_data_of(node_id) {
+                    if callback_data.is_empty() {
+                        ui.add_space(2.0);
+                        
+                        if ui.button(egui::RichText::new(language.tr("canvas.pin_callback_data")).size(14.0))
+                            .on_hover_text(language.tr("canvas.pin_callback_data_hint"))
+                            .clicked()
+                        {
+                            self.pin_callback_data(node_id);
+                            ui.close_menu();
+                        }
+                    }
+                    
+                    ui.add_space(2.0);
+                    
+                    if ui.button(egui::RichText::new(language.tr("canvas.regenerate_callback_data")).size(14.0))
+                        .on_hover_text(language.tr("canvas.regenerate_callback_data_hint"))
+                        .clicked()
+                    {
+                        self.regenerate_callback_data(node_id);
+                        ui.close_menu();
+                    }
+                }
+                
                 ui.add_space(4.0);
             });
         }
         
         // Check if any node needs to be deleted (from context menu action)
-        if let Some(node_id) = ui.memory(|mem| mem.data.get_temp::<usize>(egui::Id::new("node_to_delete"))) {
+        if let Some(node_id) = ui.memory(|mem| mem.data.get_temp::<NodeId>(egui::Id::new("node_to_delete"))) {
             self.delete_node(node_id);
-            ui.memory_mut(|mem| mem.data.remove::<usize>(egui::Id::new("node_to_delete")));
+            ui.memory_mut(|mem| mem.data.remove::<NodeId>(egui::Id::new("node_to_delete")));
         }
         
-        // Handle keyboard shortcuts
-        ui.input(|i| {
-            // Delete selected node with Delete key
-            if i.key_pressed(egui::Key::Delete) {
-                if let Some(node_id) = self.active_node {
-                    self.delete_node(node_id);
+        // Handle keyboard shortcuts, unless the parameters dialog or another text field has the keyboard
+        let keyboard_free = ui.memory(|mem| mem.focus().is_none());
+        if keyboard_free {
+            let (delete, select_all, duplicate, fit_all, fit_selection) = ui.input(|i| (
+                i.key_pressed(egui::Key::Delete),
+                i.modifiers.command && i.key_pressed(egui::Key::A),
+                i.modifiers.command && i.key_pressed(egui::Key::D),
+                i.key_pressed(egui::Key::Home),
+                i.modifiers.is_none() && i.key_pressed(egui::Key::F),
+            ));
+            
+            // Ctrl+C and Ctrl+V arrive as events carrying the system clipboard
+            let (copy, pasted) = ui.input(|i| {
+                let copy = i.events.iter().any(|e| matches!(e, egui::Event::Copy));
+                let pasted = i.events.iter().find_map(|e| match e {
+                    egui::Event::Paste(text) => Some(text.clone()),
+                    _ => None,
+                });
+                (copy, pasted)
+            });
+            
+            if copy {
+                if let Ok(fragment) = self.copy_selection() {
+                    ui.output_mut(|o| o.copied_text = fragment);
                 }
             }
-        });
+            if let Some(text) = pasted {
+                let pointer_pos = ui.input(|i| i.pointer.hover_pos()).unwrap_or(self.viewport.center());
+                self.paste_on_canvas(&text, None, (pointer_pos.to_vec2() / self.zoom).to_pos2());
+            }
+            
+            if delete {
+                // A connection is never selected together with nodes
+                if !self.delete_selected_connection() {
+                    self.delete_selected_nodes();
+                }
+            }
+            if select_all {
+                self.select_all_nodes();
+            }
+            if duplicate {
+                self.duplicate_selected_nodes();
+            }
+            if fit_all {
+                self.fit_all();
+            }
+            if fit_selection {
+                self.fit_selection();
+            }
+        }
+        
+        self.draw_minimap(ui.ctx());
         
         // Reset context menu flag at the end of the draw call
         self.context_menu_open = false;
     }
 
+    /// Whether the inspector was asked for since the last call
+    pub fn take_inspector_request(&mut self) -> bool {
+        std::mem::take(&mut self.inspector_requested)
+    }
+
+    /// Why the last paste on the canvas failed, if it did since the last call
+    pub fn take_paste_error(&mut self) -> Option<String> {
+        self.paste_error.take()
+    }
+
+    /// Rename a node without taking a snapshot, call `save_state` once the
+    /// edit is finished
+    pub fn set_node_title(&mut self, node_id: NodeId, title: String) -> bool {
+        match self.get_node_mut(node_id) {
+            Some(node) => {
+                node.title = title;
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Set a parameter on every listed node that has it, without taking a
+    /// snapshot. Returns how many nodes were changed.
+    pub fn set_parameter_value(&mut self, node_ids: &[NodeId], param_id: &str, value: &ParameterValue) -> usize {
+        self.revision += 1;
+        let mut changed = 0;
+        for node in self.nodes.iter_mut().filter(|node| node_ids.contains(&node.id())) {
+            if let Some(param) = node.find_param_mut(param_id) {
+                param.value = value.clone();
+                changed += 1;
+            }
+        }
+        changed
+    }
+
     /// Create a snapshot of the current graph state
     fn create_snapshot(&self) -> GraphSnapshot {
         GraphSnapshot::new(
@@ -519,21 +1341,86 @@ impl SimpleNodeGraph {
 
     /// Save the current state to history
     pub fn save_state(&mut self) {
+        self.revision += 1;
+        if self.batch_depth > 0 {
+            return;
+        }
         let snapshot = self.create_snapshot();
         self.history_manager.add_snapshot(snapshot);
     }
 
     /// Restore the graph from a snapshot
     fn restore_from_snapshot(&mut self, snapshot: GraphSnapshot) {
+        self.revision += 1;
         self.nodes = snapshot.nodes;
         self.connections = snapshot.connections;
         self.active_node = snapshot.active_node;
         self.drag_offset = snapshot.drag_offset;
         self.zoom = snapshot.zoom;
+        
+        // Keep the selection, minus nodes the snapshot does not have
+        let nodes = &self.nodes;
+        self.selected_nodes.retain(|id| nodes.iter().any(|node| node.id() == *id));
+    }
+
+    /// Counter that changes whenever the nodes or connections may have
+    /// changed, for caching results computed from the graph
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    /// Validation results for the graph, recomputed only after it changed
+    pub fn diagnostics(&mut self) -> &[Diagnostic] {
+        if self.diagnostics.as_ref().map(|(revision, _)| *revision) != Some(self.revision) {
+            let diagnostics = GraphValidator::validate(self);
+            self.diagnostics = Some((self.revision, diagnostics));
+        }
+        self.diagnostics.as_ref().map(|(_, diagnostics)| diagnostics.as_slice()).unwrap_or_default()
+    }
+
+    /// Run several changes as a single undo step
+    pub fn batch<R>(&mut self, changes: impl FnOnce(&mut Self) -> R) -> R {
+        self.batch_depth += 1;
+        let result = changes(self);
+        self.batch_depth -= 1;
+        
+        self.save_state();
+        result
+    }
+
+    /// Copy the selected menu items, or the active one, with everything below
+    /// them as a bot_data.json fragment
+    pub fn copy_selection(&mut self) -> Result<String, String> {
+        let mut node_ids = self.selected_nodes();
+        if node_ids.is_empty() {
+            node_ids.extend(self.active_node);
+        }
+        
+        let fragment = DataExporter::export_fragment(self, &node_ids)?;
+        self.clipboard = Some(fragment.clone());
+        Ok(fragment)
+    }
+
+    /// Paste a bot_data.json fragment, under `parent_id` or as new root items,
+    /// and select what was pasted
+    pub fn paste_fragment(&mut self, text: &str, parent_id: Option<NodeId>, position: egui::Pos2) -> Result<Vec<NodeId>, String> {
+        let pasted = DataImporter::paste_fragment(self, text, parent_id, position)?;
+        
+        self.selected_nodes = pasted.iter().copied().collect();
+        self.active_node = pasted.first().copied();
+        Ok(pasted)
+    }
+
+    /// Paste from a canvas command, keeping the error for `take_paste_error`
+    fn paste_on_canvas(&mut self, text: &str, parent_id: Option<NodeId>, position: egui::Pos2) {
+        if let Err(e) = self.paste_fragment(text, parent_id, position) {
+            self.paste_error = Some(e);
+        }
     }
 
     /// Undo the last action
     pub fn undo(&mut self) -> bool {
+        self.finish_layout_animation();
         if let Some(snapshot) = self.history_manager.undo() {
             self.restore_from_snapshot(snapshot);
             true
@@ -544,6 +1431,7 @@ impl SimpleNodeGraph {
 
     /// Redo the last undone action
     pub fn redo(&mut self) -> bool {
+        self.finish_layout_animation();
         if let Some(snapshot) = self.history_manager.redo() {
             self.restore_from_snapshot(snapshot);
             true
@@ -562,8 +1450,337 @@ impl SimpleNodeGraph {
         self.history_manager.can_redo()
     }
 
+    /// Select a node and pan the view so it sits in the middle at normal zoom
+    pub fn focus_node(&mut self, node_id: NodeId) -> bool {
+        let Some(center) = self.get_node_data(node_id).map(|node| node.rect().center()) else {
+            return false;
+        };
+        
+        self.zoom = self.zoom.max(1.0);
+        self.center_view_on(center);
+        self.select_node(node_id, false);
+        
+        self.save_state();
+        true
+    }
+
+    /// Pan the view so that a node is in sight, keeping the zoom. Nothing
+    /// moves when it is visible already.
+    pub fn scroll_to_node(&mut self, node_id: NodeId) -> bool {
+        let Some(rect) = self.get_node_data(node_id).map(|node| node.rect()) else {
+            return false;
+        };
+        if self.visible_rect().contains_rect(rect) {
+            return false;
+        }
+        
+        self.center_view_on(rect.center());
+        self.save_state();
+        true
+    }
+
+    /// Area covered by all nodes, in graph coordinates
+    pub fn bounds(&self) -> Option<egui::Rect> {
+        self.nodes.iter()
+            .map(|node| node.rect())
+            .reduce(|a, b| a.union(b))
+    }
+
+    /// Area covered by the selected nodes, in graph coordinates
+    pub fn selection_bounds(&self) -> Option<egui::Rect> {
+        self.nodes.iter()
+            .filter(|node| self.selected_nodes.contains(&node.id()))
+            .map(|node| node.rect())
+            .reduce(|a, b| a.union(b))
+    }
+
+    /// Part of the graph that is visible on the canvas, in graph coordinates
+    pub fn visible_rect(&self) -> egui::Rect {
+        // Nodes are drawn at `position * zoom`
+        egui::Rect::from_min_max(
+            (self.viewport.min.to_vec2() / self.zoom).to_pos2(),
+            (self.viewport.max.to_vec2() / self.zoom).to_pos2()
+        )
+    }
+
+    /// Zoom and pan so that all nodes are visible
+    pub fn fit_all(&mut self) -> bool {
+        match self.bounds() {
+            Some(bounds) => {
+                self.fit_rect(bounds);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Zoom and pan so that the selected nodes are visible
+    pub fn fit_selection(&mut self) -> bool {
+        match self.selection_bounds() {
+            Some(bounds) => {
+                self.fit_rect(bounds);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Zoom and pan so that an area in graph coordinates fills the view,
+    /// without zooming in past the normal scale
+    pub fn fit_rect(&mut self, rect: egui::Rect) {
+        let rect = rect.expand(FIT_MARGIN);
+        let zoom = (self.viewport.width() / rect.width()).min(self.viewport.height() / rect.height());
+        self.zoom = zoom.clamp(0.1, 1.0);
+        self.center_view_on(rect.center());
+        
+        self.save_state();
+    }
+
+    /// Return to the normal scale, keeping the middle of the view in place
+    pub fn reset_zoom(&mut self) {
+        let center = self.visible_rect().center();
+        self.zoom = 1.0;
+        self.center_view_on(center);
+        
+        self.save_state();
+    }
+
+    /// Pan the view so that a point in graph coordinates is in its middle
+    pub fn center_view_on(&mut self, point: egui::Pos2) {
+        let delta = self.visible_rect().center() - point;
+        
+        // Panning moves the nodes, as dragging the canvas does
+        for node in &mut self.nodes {
+            node.position += delta;
+        }
+        self.drag_offset += delta;
+    }
+
+    /// Context menu of a connection: delete it, move its end to another node
+    /// or move it among its siblings
+    fn draw_connection_menu(&mut self, ui: &mut egui::Ui, connection: &Connection) {
+        let title = |graph: &Self, node_id: NodeId| {
+            graph.get_node_data(node_id).map(|node| node.get_title().to_string()).unwrap_or_default()
+        };
+        let language = Language::current(ui.ctx());
+        
+        ui.add_space(4.0);
+        
+        ui.label(egui::RichText::new(format!(
+            "{} → {}",
+            title(self, connection.from_node()),
+            title(self, connection.to_node()),
+        )).weak());
+        
+        ui.add_space(2.0);
+        
+        if ui.button(egui::RichText::new(language.tr("canvas.delete_connection")).size(14.0)).clicked() {
+            self.select_connection(connection);
+            self.delete_selected_connection();
+            ui.close_menu();
+        }
+        
+        ui.add_space(2.0);
+        
+        let candidates: Vec<(NodeId, String)> = self.retarget_candidates(connection).into_iter()
+            .map(|node_id| (node_id, title(self, node_id)))
+            .collect();
+        ui.add_enabled_ui(!candidates.is_empty(), |ui| {
+            ui.menu_button(egui::RichText::new(language.tr("canvas.retarget_connection")).size(14.0), |ui| {
+                egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    for (node_id, title) in candidates {
+                        if ui.button(title).clicked() {
+                            self.retarget_connection(connection, node_id);
+                            ui.close_menu();
+                        }
+                    }
+                });
+            });
+        });
+        
+        let siblings = self.get_children(connection.from_node(), connection.from_port());
+        if let Some(index) = siblings.iter().position(|id| *id == connection.to_node()).filter(|_| siblings.len() > 1) {
+            ui.add_space(2.0);
+            
+            if ui.add_enabled(index > 0, egui::Button::new(egui::RichText::new(language.tr("canvas.move_up")).size(14.0))).clicked() {
+                self.move_child(connection.from_node(), connection.from_port(), connection.to_node(), index - 1);
+                ui.close_menu();
+            }
+            
+            ui.add_space(2.0);
+            
+            if ui.add_enabled(index + 1 < siblings.len(), egui::Button::new(egui::RichText::new(language.tr("canvas.move_down")).size(14.0))).clicked() {
+                self.move_child(connection.from_node(), connection.from_port(), connection.to_node(), index + 1);
+                ui.close_menu();
+            }
+        }
+        
+        ui.add_space(4.0);
+    }
+
+    /// Draw the minimap in the bottom right corner of the canvas. Clicking it
+    /// centres the view there, dragging it pans the view.
+    fn draw_minimap(&mut self, ctx: &egui::Context) {
+        if !self.show_minimap {
+            return;
+        }
+        let Some(bounds) = self.bounds() else {
+            return;
+        };
+        
+        let world = bounds.expand(FIT_MARGIN);
+        let scale = (MINIMAP_SIZE.x / world.width()).min(MINIMAP_SIZE.y / world.height());
+        let size = world.size() * scale;
+        let to_minimap = |rect: egui::Rect, origin: egui::Pos2| egui::Rect::from_min_max(
+            origin + (rect.min - world.min) * scale,
+            origin + (rect.max - world.min) * scale
+        );
+        
+        let mut pan = None;
+        let mut center = None;
+        let mut released = false;
+        
+        // An area of its own keeps clicks from reaching the nodes and canvas below
+        egui::Area::new("graph_minimap")
+            .order(egui::Order::Foreground)
+            .fixed_pos(self.viewport.max - size - egui::vec2(12.0, 12.0))
+            .show(ctx, |ui| {
+                let (rect, response) = ui.allocate_exact_size(size, egui::Sense::click_and_drag());
+                
+                let palette = Theme::current(ctx).palette();
+                let nodes: Vec<(egui::Rect, egui::Color32)> = self.nodes.iter()
+                    .map(|node| (to_minimap(node.rect(), rect.min), palette.node_color(&node.node_type)))
+                    .collect();
+                let visible = to_minimap(self.visible_rect(), rect.min);
+                GraphRenderer::draw_minimap(ui, rect, &nodes, visible);
+                
+                let pointer = response.interact_pointer_pos();
+                if response.drag_started() && pointer.is_some_and(|pos| !visible.contains(pos)) || response.clicked() {
+                    // Jump to the point before dragging the view from there
+                    center = pointer.map(|pos| world.min + (pos - rect.min) / scale);
+                } else if response.dragged() {
+                    pan = Some(response.drag_delta() / scale);
+                }
+                released = response.drag_released() || response.clicked();
+            });
+        
+        if let Some(center) = center {
+            self.center_view_on(center);
+        }
+        if let Some(pan) = pan {
+            let view_center = self.visible_rect().center();
+            self.center_view_on(view_center + pan);
+        }
+        if released {
+            self.save_state();
+        }
+    }
+
+    /// Write the callback_data the exporter generates into the menu item, so it
+    /// no longer changes with the title
+    pub fn pin_callback_data(&mut self, node_id: NodeId) -> bool {
+        if !self.callback_data_of(node_id).is_some_and(|cb| cb.is_empty()) {
+            return false;
+        }
+        let Some(callback_data) = CallbackDataGenerator::assign(self).remove(&node_id) else {
+            return false;
+        };
+        
+        let changed = self.set_callback_data(node_id, callback_data);
+        if changed {
+            self.save_state();
+        }
+        changed
+    }
+
+    /// Replace the callback_data of a menu item with a fresh one from its current title
+    pub fn regenerate_callback_data(&mut self, node_id: NodeId) -> bool {
+        let Some(callback_data) = CallbackDataGenerator::regenerate(self, node_id) else {
+            return false;
+        };
+        
+        let changed = self.set_callback_data(node_id, callback_data);
+        if changed {
+            self.save_state();
+        }
+        changed
+    }
+
+    /// Pin the generated callback_data of every menu item as one undo step,
+    /// returns how many items changed
+    pub fn pin_all_callback_data(&mut self) -> usize {
+        let generated = CallbackDataGenerator::assign(self);
+        let mut changed = 0;
+        
+        for (node_id, callback_data) in generated {
+            if self.callback_data_of(node_id).is_some_and(|cb| cb.is_empty())
+                && self.set_callback_data(node_id, callback_data)
+            {
+                changed += 1;
+            }
+        }
+        
+        if changed > 0 {
+            self.save_state();
+        }
+        changed
+    }
+
+    fn callback_data_of(&self, node_id: NodeId) -> Option<String> {
+        self.get_node_data(node_id)
+            .filter(|node| node.node_type == NodeType::MenuItem)
+            .and_then(|node| node.find_param("callback_data"))
+            .map(|param| param.get_text())
+    }
+
+    fn set_callback_data(&mut self, node_id: NodeId, callback_data: String) -> bool {
+        if self.callback_data_of(node_id).as_ref() == Some(&callback_data) {
+            return false;
+        }
+        self.get_node_mut(node_id)
+            .and_then(|node| node.find_param_mut("callback_data"))
+            .is_some_and(|param| param.set_text_value(callback_data).is_ok())
+    }
+
+    /// Get all nodes of the graph
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Get all connections of the graph
+    pub fn connections(&self) -> &[Connection] {
+        &self.connections
+    }
+
+    /// Capture the complete graph state for saving into a project file
+    pub fn to_graph_state(&self) -> GraphState {
+        GraphState {
+            nodes: self.nodes.clone(),
+            connections: self.connections.clone(),
+            drag_offset: self.drag_offset,
+            zoom: self.zoom,
+        }
+    }
+
+    /// Replace the graph with a previously saved state and start a fresh history
+    pub fn load_graph_state(&mut self, state: GraphState) {
+        self.revision += 1;
+        self.nodes = state.nodes;
+        self.connections = state.connections;
+        self.drag_offset = state.drag_offset;
+        self.zoom = state.zoom;
+        self.active_node = None;
+        self.selected_nodes.clear();
+        self.selected_connection = None;
+        self.connecting_port = None;
+        self.node_drag = None;
+        self.layout_animation = None;
+        
+        self.initialize_history();
+    }
+
     /// Get all root menu nodes (no parent connection)
-    pub fn get_root_menu_nodes(&self) -> Vec<usize> {
+    pub fn get_root_menu_nodes(&self) -> Vec<NodeId> {
         self.nodes.iter()
             .filter(|node| node.node_type == NodeType::MenuItem)
             .filter(|node| {
@@ -577,18 +1794,194 @@ impl SimpleNodeGraph {
     }
 
     /// Get all FAQ nodes
-    pub fn get_faq_nodes(&self) -> Vec<usize> {
+    pub fn get_faq_nodes(&self) -> Vec<NodeId> {
         self.nodes.iter()
             .filter(|node| node.node_type == NodeType::FaqItem)
             .map(|node| node.id())
             .collect()
     }
 
-    /// Get all child menu nodes for a parent node
-    pub fn get_child_menu_nodes(&self, parent_id: usize) -> Vec<usize> {
+    /// Connections leaving a port, in the order their children are exported
+    fn child_connections(&self, parent_id: NodeId, port_id: &str) -> Vec<&Connection> {
+        let mut children: Vec<&Connection> = self.connections.iter()
+            .filter(|conn| conn.from_node() == parent_id && conn.from_port() == port_id)
+            .collect();
+        // The sort is stable, connections with the same order keep the order they were made in
+        children.sort_by_key(|conn| conn.order());
+        children
+    }
+
+    /// Get the nodes connected to an output port, in export order
+    pub fn get_children(&self, parent_id: NodeId, port_id: &str) -> Vec<NodeId> {
+        self.child_connections(parent_id, port_id).iter()
+            .map(|conn| conn.to_node())
+            .collect()
+    }
+
+    /// Move a child to `index` among the nodes connected to the same port of
+    /// its parent, as one undo step
+    pub fn move_child(&mut self, parent_id: NodeId, port_id: &str, child_id: NodeId, index: usize) -> bool {
+        let mut children = self.get_children(parent_id, port_id);
+        let Some(from) = children.iter().position(|id| *id == child_id) else {
+            return false;
+        };
+        let index = index.min(children.len() - 1);
+        if from == index {
+            return false;
+        }
+        children.remove(from);
+        children.insert(index, child_id);
+        
+        for conn in &mut self.connections {
+            if conn.from_node == parent_id && conn.from_port == port_id {
+                if let Some(position) = children.iter().position(|id| *id == conn.to_node) {
+                    conn.order = position;
+                }
+            }
+        }
+        self.save_state();
+        true
+    }
+
+    /// Output port a menu item or a document hangs from in the menu tree
+    fn tree_port(node_type: &NodeType) -> Option<&'static str> {
+        match node_type {
+            NodeType::MenuItem => Some("sub_menu"),
+            NodeType::Document => Some("documents"),
+            _ => None,
+        }
+    }
+
+    /// Whether a menu item is somewhere below another one
+    fn is_below(&self, node_id: NodeId, ancestor_id: NodeId) -> bool {
+        let mut visited = HashSet::new();
+        let mut stack = self.get_child_menu_nodes(ancestor_id);
+        while let Some(id) = stack.pop() {
+            if id == node_id {
+                return true;
+            }
+            if visited.insert(id) {
+                stack.extend(self.get_child_menu_nodes(id));
+            }
+        }
+        false
+    }
+
+    /// Move a menu item, a document or an FAQ item in the menu tree, as one
+    /// undo step.
+    ///
+    /// The node is taken from under `from_parent` and put under `to_parent`
+    /// at `index` among the nodes of its kind there, `None` meaning the top
+    /// level. Changing the parent rewires the connection. Top-level menu
+    /// items and FAQ items are ordered by their place in the node list.
+    /// Documents cannot be at the top level, FAQ items cannot have a parent
+    /// and a menu item cannot go below itself.
+    pub fn move_in_tree(&mut self, node_id: NodeId, from_parent: Option<NodeId>, to_parent: Option<NodeId>, index: usize) -> bool {
+        let Some(node_type) = self.get_node_data(node_id).map(|node| node.node_type.clone()) else {
+            return false;
+        };
+        if node_type == NodeType::FaqItem {
+            let faq = self.get_faq_nodes();
+            return from_parent.is_none() && to_parent.is_none() && self.move_in_node_order(&faq, node_id, index);
+        }
+        let Some(port) = Self::tree_port(&node_type) else {
+            return false;
+        };
+        
+        if from_parent == to_parent {
+            return match to_parent {
+                Some(parent_id) => self.move_child(parent_id, port, node_id, index),
+                None => {
+                    let roots = self.get_root_menu_nodes();
+                    node_type == NodeType::MenuItem && self.move_in_node_order(&roots, node_id, index)
+                },
+            };
+        }
+        
+        // Check everything first, the move must not stop half way
+        let is_parent = |conn: &Connection, parent_id: NodeId| {
+            conn.from_node() == parent_id && conn.from_port() == port && conn.to_node() == node_id
+        };
+        let taken = match from_parent {
+            Some(parent_id) => self.connections.iter().any(|conn| is_parent(conn, parent_id)),
+            None => self.get_root_menu_nodes().contains(&node_id),
+        };
+        if !taken {
+            return false;
+        }
+        match to_parent {
+            Some(parent_id) => {
+                let (Some(parent), Some(node)) = (self.get_node_data(parent_id), self.get_node_data(node_id)) else {
+                    return false;
+                };
+                if parent_id == node_id
+                    || self.is_below(parent_id, node_id)
+                    || self.connections.iter().any(|conn| is_parent(conn, parent_id))
+                    || !GraphOperations::is_valid_connection(parent, port, node, "parent_menu")
+                {
+                    return false;
+                }
+            },
+            None => {
+                // A menu item under several parents stays below the others
+                let parents = self.connections.iter()
+                    .filter(|conn| conn.to_node() == node_id && conn.to_port() == "parent_menu")
+                    .count();
+                if node_type != NodeType::MenuItem || parents != 1 {
+                    return false;
+                }
+            },
+        }
+        
+        self.batch(|graph| {
+            if let Some(parent_id) = from_parent {
+                graph.connections.retain(|conn| !is_parent(conn, parent_id));
+            }
+            match to_parent {
+                Some(parent_id) => {
+                    graph.connect_nodes(parent_id, port, node_id, "parent_menu");
+                    graph.move_child(parent_id, port, node_id, index);
+                },
+                None => {
+                    let roots = graph.get_root_menu_nodes();
+                    graph.move_in_node_order(&roots, node_id, index);
+                },
+            }
+        });
+        true
+    }
+
+    /// Move a node to `index` among `siblings`, which are listed in node
+    /// order, by moving it in the node list
+    fn move_in_node_order(&mut self, siblings: &[NodeId], node_id: NodeId, index: usize) -> bool {
+        let Some(from) = siblings.iter().position(|id| *id == node_id) else {
+            return false;
+        };
+        let index = index.min(siblings.len() - 1);
+        if from == index {
+            return false;
+        }
+        let others: Vec<NodeId> = siblings.iter().copied().filter(|id| *id != node_id).collect();
+        let Some(position) = self.nodes.iter().position(|node| node.id() == node_id) else {
+            return false;
+        };
+        
+        let node = self.nodes.remove(position);
+        let position_of = |nodes: &[Node], id: NodeId| nodes.iter().position(|node| node.id() == id);
+        let at = match others.get(index) {
+            Some(next) => position_of(&self.nodes, *next),
+            None => others.last().and_then(|last| position_of(&self.nodes, *last)).map(|last| last + 1),
+        };
+        self.nodes.insert(at.unwrap_or(self.nodes.len()), node);
+        
+        self.save_state();
+        true
+    }
+
+    /// Get all child menu nodes for a parent node, in export order
+    pub fn get_child_menu_nodes(&self, parent_id: NodeId) -> Vec<NodeId> {
         // Find all connections from parent's sub_menu output
-        self.connections.iter()
-            .filter(|conn| conn.from_node() == parent_id && conn.from_port() == "sub_menu")
+        self.child_connections(parent_id, "sub_menu").into_iter()
             .filter_map(|conn| {
                 // Find the connected node and check if it's a menu item
                 let node_id = conn.to_node();
@@ -602,29 +1995,33 @@ impl SimpleNodeGraph {
     }
 
     /// Get all document nodes connected to a menu node
-    pub fn get_documents_for_node(&self, menu_id: usize) -> Vec<usize> {
-        // Find all connections from menu's documents output
-        self.connections.iter()
-            .filter(|conn| conn.from_node() == menu_id && conn.from_port() == "documents")
-            .map(|conn| conn.to_node())
+    pub fn get_documents_for_node(&self, menu_id: NodeId) -> Vec<NodeId> {
+        self.get_children(menu_id, "documents")
+    }
+
+    /// Get all content nodes (images, links, texts, contacts) connected to a menu node
+    pub fn get_content_for_node(&self, menu_id: NodeId) -> Vec<NodeId> {
+        self.get_children(menu_id, "content").into_iter()
+            .filter(|node_id| self.nodes.iter().any(|node| node.id() == *node_id && node.node_type.is_content()))
             .collect()
     }
 
     /// Get the data for a node
-    pub fn get_node_data(&self, node_id: usize) -> Option<&Node> {
+    pub fn get_node_data(&self, node_id: NodeId) -> Option<&Node> {
         self.nodes.iter().find(|node| node.id() == node_id)
     }
 
     /// Get a mutable reference to a node by its ID
-    pub fn get_node_mut(&mut self, node_id: usize) -> Option<&mut Node> {
+    pub fn get_node_mut(&mut self, node_id: NodeId) -> Option<&mut Node> {
+        self.revision += 1;
         self.nodes.iter_mut().find(|node| node.id() == node_id)
     }
 
     /// Add a document node at the specified position
-    pub fn add_document(&mut self, position: egui::Pos2, title: String) -> Result<usize, String> {
+    pub fn add_document(&mut self, position: egui::Pos2, title: String) -> Result<NodeId, String> {
         // Check if we have document creation functionality
         if GraphOperations::supports_document_creation() {
-            let id = self.next_node_id();
+            let id = NodeId::new();
             let node = GraphOperations::create_document(id, title, position);
             
             self.nodes.push(node);
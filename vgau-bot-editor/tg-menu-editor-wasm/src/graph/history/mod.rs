@@ -1,13 +1,13 @@
 use eframe::egui;
 use std::collections::VecDeque;
-use crate::graph::models::{Node, Connection};
+use crate::graph::models::{Node, NodeId, Connection};
 
 /// Represents a snapshot of the graph state for undo/redo operations
 #[derive(Clone)]
 pub struct GraphSnapshot {
     pub(crate) nodes: Vec<Node>,
     pub(crate) connections: Vec<Connection>,
-    pub(crate) active_node: Option<usize>,
+    pub(crate) active_node: Option<NodeId>,
     pub(crate) drag_offset: egui::Vec2,
     pub(crate) zoom: f32,
 }
@@ -17,7 +17,7 @@ impl GraphSnapshot {
     pub fn new(
         nodes: Vec<Node>,
         connections: Vec<Connection>,
-        active_node: Option<usize>,
+        active_node: Option<NodeId>,
         drag_offset: egui::Vec2,
         zoom: f32,
     ) -> Self {
@@ -139,4 +139,16 @@ impl HistoryManager {
         self.history.push_back(snapshot);
         self.action_in_progress = false;
     }
+    
+    /// Change how many snapshots are kept, dropping the oldest ones
+    /// when the history is already longer
+    pub fn set_size_limit(&mut self, size_limit: usize) {
+        self.history_size_limit = size_limit.max(1);
+        while self.history.len() > self.history_size_limit {
+            self.history.pop_front();
+        }
+        while self.redo_stack.len() > self.history_size_limit {
+            self.redo_stack.pop_front();
+        }
+    }
 } 
\ No newline at end of file
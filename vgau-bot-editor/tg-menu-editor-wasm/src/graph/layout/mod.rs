@@ -0,0 +1,253 @@
+use std::collections::{HashMap, HashSet};
+
+use eframe::egui;
+
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{NodeId, NodeType};
+
+/// Horizontal space between neighbouring subtrees
+const SIBLING_GAP: f32 = 40.0;
+/// Vertical space between menu levels
+const LEVEL_GAP: f32 = 80.0;
+/// Space between a menu item and the nodes stacked beside it, and between those nodes
+const STACK_GAP: f32 = 20.0;
+/// Columns of the grid FAQ items and loose nodes are placed in
+const GRID_COLUMNS: usize = 4;
+
+/// Left and right extent of every level of a subtree
+type Contour = Vec<(f32, f32)>;
+
+/// A menu item laid out together with everything below it
+struct Subtree {
+    node_id: NodeId,
+    /// Width of the item itself, without the stack beside it
+    width: f32,
+    /// Documents and content stacked to the right of the item
+    stack: Vec<NodeId>,
+    /// Size of the item and its stack together
+    block: egui::Vec2,
+    /// Child subtrees with the offset of their left edge from this item's left edge
+    children: Vec<(Subtree, f32)>,
+    /// Left and right extent of every level of the subtree, relative to this
+    /// item's left edge. The first entry is the item's own block.
+    contour: Contour,
+}
+
+/// Layered tree layout of the menu hierarchy.
+///
+/// Menu items are placed with the Reingold–Tilford algorithm: each subtree is
+/// laid out on its own, sibling subtrees are then pushed apart until their
+/// contours no longer overlap on any level, and every item is centred above its
+/// children. Each level sits below the tallest block of the level above.
+/// Documents and content are stacked in a column beside their menu item, FAQ
+/// items and nodes that do not hang below a menu item go to a grid under the tree.
+pub struct TreeLayout<'a> {
+    graph: &'a SimpleNodeGraph,
+    scope: Option<&'a HashSet<NodeId>>,
+    placed: HashSet<NodeId>,
+}
+
+impl<'a> TreeLayout<'a> {
+    /// New positions of the nodes in `scope`, or of the whole graph.
+    ///
+    /// The result keeps the top-left corner of the nodes it moves, so laying out
+    /// a selection does not throw it across the canvas.
+    pub fn compute(graph: &'a SimpleNodeGraph, scope: Option<&'a HashSet<NodeId>>) -> HashMap<NodeId, egui::Pos2> {
+        let mut layout = Self {
+            graph,
+            scope,
+            placed: HashSet::new(),
+        };
+        
+        let roots: Vec<NodeId> = graph.nodes().iter()
+            .filter(|node| node.node_type == NodeType::MenuItem && layout.in_scope(node.id()))
+            .map(|node| node.id())
+            .filter(|id| !layout.has_parent_in_scope(*id))
+            .collect();
+        
+        let mut forest = Vec::new();
+        for root_id in roots {
+            layout.placed.insert(root_id);
+            forest.push(layout.build(root_id));
+        }
+        let (forest, _) = Self::arrange(forest);
+        
+        let mut level_heights = Vec::new();
+        for (tree, _) in &forest {
+            Self::measure_levels(tree, 0, &mut level_heights);
+        }
+        let mut level_tops = Vec::with_capacity(level_heights.len());
+        let mut y = 0.0;
+        for height in &level_heights {
+            level_tops.push(y);
+            y += height + LEVEL_GAP;
+        }
+        
+        let mut positions = HashMap::new();
+        for (tree, x) in &forest {
+            layout.place(tree, *x, 0, &level_tops, &mut positions);
+        }
+        layout.place_grid(y, &mut positions);
+        
+        layout.keep_origin(&mut positions);
+        positions
+    }
+    
+    fn in_scope(&self, node_id: NodeId) -> bool {
+        self.scope.is_none_or(|scope| scope.contains(&node_id))
+    }
+    
+    fn has_parent_in_scope(&self, node_id: NodeId) -> bool {
+        self.graph.connections().iter().any(|conn| {
+            conn.to_node() == node_id
+                && conn.from_port() == "sub_menu"
+                && self.in_scope(conn.from_node())
+                && self.graph.get_node_data(conn.from_node()).is_some_and(|node| node.node_type == NodeType::MenuItem)
+        })
+    }
+    
+    /// Lay out a menu item and its subtree. Items with several parents are
+    /// placed under the first one in export order.
+    fn build(&mut self, node_id: NodeId) -> Subtree {
+        let graph = self.graph;
+        let size = graph.get_node_data(node_id).map(|node| node.size()).unwrap_or_default();
+        
+        let stack: Vec<NodeId> = graph.get_documents_for_node(node_id).into_iter()
+            .chain(graph.get_content_for_node(node_id))
+            .filter(|id| self.in_scope(*id) && graph.get_node_data(*id).is_some() && self.placed.insert(*id))
+            .collect();
+        let mut block = size;
+        if !stack.is_empty() {
+            let sizes: Vec<egui::Vec2> = stack.iter()
+                .filter_map(|id| graph.get_node_data(*id))
+                .map(|node| node.size())
+                .collect();
+            let stack_width = sizes.iter().map(|s| s.x).fold(0.0, f32::max);
+            let stack_height = sizes.iter().map(|s| s.y).sum::<f32>() + STACK_GAP * (sizes.len() - 1) as f32;
+            block = egui::vec2(size.x + STACK_GAP + stack_width, size.y.max(stack_height));
+        }
+        
+        let mut children = Vec::new();
+        for child_id in graph.get_child_menu_nodes(node_id) {
+            if self.in_scope(child_id) && self.placed.insert(child_id) {
+                children.push(self.build(child_id));
+            }
+        }
+        
+        let mut contour = vec![(0.0, block.x)];
+        let (mut children, child_contour) = Self::arrange(children);
+        if let (Some((first, first_x)), Some((last, last_x))) = (children.first(), children.last()) {
+            let centre = (first_x + first.width / 2.0 + last_x + last.width / 2.0) / 2.0;
+            let shift = centre - size.x / 2.0;
+            
+            for (_, x) in &mut children {
+                *x -= shift;
+            }
+            contour.extend(child_contour.into_iter().map(|(left, right)| (left - shift, right - shift)));
+        }
+        
+        Subtree {
+            node_id,
+            width: size.x,
+            stack,
+            block,
+            children,
+            contour,
+        }
+    }
+    
+    /// Place sibling subtrees left to right as close as their contours allow.
+    /// Returns their offsets from the first one and the contour of the row.
+    fn arrange(subtrees: Vec<Subtree>) -> (Vec<(Subtree, f32)>, Contour) {
+        let mut placed = Vec::with_capacity(subtrees.len());
+        let mut row: Contour = Vec::new();
+        
+        for subtree in subtrees {
+            let x = if placed.is_empty() {
+                0.0
+            } else {
+                row.iter()
+                    .zip(&subtree.contour)
+                    .map(|((_, right), (left, _))| right + SIBLING_GAP - left)
+                    .fold(f32::MIN, f32::max)
+            };
+            
+            for (level, (left, right)) in subtree.contour.iter().enumerate() {
+                match row.get_mut(level) {
+                    Some(extent) => {
+                        extent.0 = extent.0.min(x + left);
+                        extent.1 = extent.1.max(x + right);
+                    },
+                    None => row.push((x + left, x + right)),
+                }
+            }
+            placed.push((subtree, x));
+        }
+        
+        (placed, row)
+    }
+    
+    fn measure_levels(tree: &Subtree, level: usize, heights: &mut Vec<f32>) {
+        if heights.len() <= level {
+            heights.push(0.0);
+        }
+        heights[level] = heights[level].max(tree.block.y);
+        for (child, _) in &tree.children {
+            Self::measure_levels(child, level + 1, heights);
+        }
+    }
+    
+    fn place(&self, tree: &Subtree, x: f32, level: usize, level_tops: &[f32], positions: &mut HashMap<NodeId, egui::Pos2>) {
+        let y = level_tops[level];
+        positions.insert(tree.node_id, egui::pos2(x, y));
+        
+        let mut stack_y = y;
+        for node_id in &tree.stack {
+            positions.insert(*node_id, egui::pos2(x + tree.width + STACK_GAP, stack_y));
+            stack_y += self.graph.get_node_data(*node_id).map(|node| node.size().y).unwrap_or_default() + STACK_GAP;
+        }
+        
+        for (child, offset) in &tree.children {
+            self.place(child, x + offset, level + 1, level_tops, positions);
+        }
+    }
+    
+    /// FAQ items first, then whatever the tree did not reach: items only
+    /// reachable through a cycle, documents and content without a menu item
+    fn place_grid(&self, top: f32, positions: &mut HashMap<NodeId, egui::Pos2>) {
+        let nodes = self.graph.nodes();
+        let loose: Vec<_> = nodes.iter()
+            .filter(|node| node.node_type == NodeType::FaqItem)
+            .chain(nodes.iter().filter(|node| node.node_type != NodeType::FaqItem))
+            .filter(|node| self.in_scope(node.id()) && !positions.contains_key(&node.id()))
+            .collect();
+        
+        let cell = loose.iter()
+            .map(|node| node.size())
+            .fold(egui::Vec2::ZERO, |max, size| max.max(size))
+            + egui::vec2(SIBLING_GAP, STACK_GAP);
+        for (index, node) in loose.into_iter().enumerate() {
+            let column = (index % GRID_COLUMNS) as f32;
+            let row = (index / GRID_COLUMNS) as f32;
+            positions.insert(node.id(), egui::pos2(column * cell.x, top + row * cell.y));
+        }
+    }
+    
+    /// Move the result so its top-left corner is where the nodes started
+    fn keep_origin(&self, positions: &mut HashMap<NodeId, egui::Pos2>) {
+        let corner = |points: &mut dyn Iterator<Item = egui::Pos2>| {
+            points.reduce(|a, b| a.min(b))
+        };
+        let before = corner(&mut positions.keys()
+            .filter_map(|id| self.graph.get_node_data(*id))
+            .map(|node| node.position()));
+        let after = corner(&mut positions.values().copied());
+        
+        if let (Some(before), Some(after)) = (before, after) {
+            let delta = before - after;
+            for position in positions.values_mut() {
+                *position += delta;
+            }
+        }
+    }
+}
@@ -1,9 +1,17 @@
+pub mod alignment;
 pub mod core;
 pub mod models;
 pub mod operations;
+pub mod outline;
 pub mod rendering;
 pub mod history;
+pub mod layout;
+pub mod project;
+pub mod search;
+pub mod validation;
 
 // Re-export main structures for easy access
 pub use core::SimpleNodeGraph;
+pub use project::{ProjectFile, GraphState, WorkflowMetadata};
+pub use validation::{Diagnostic, GraphValidator, Severity};
 // Remove other exports to avoid unused warnings 
\ No newline at end of file
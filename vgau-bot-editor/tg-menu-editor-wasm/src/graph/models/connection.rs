@@ -1,30 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+use super::NodeId;
+
 /// Represents a connection between two nodes in the graph
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Connection {
-    pub(crate) from_node: usize,
-    pub(crate) to_node: usize,
+    pub(crate) from_node: NodeId,
+    pub(crate) to_node: NodeId,
     pub(crate) from_port: String,
     pub(crate) to_port: String,
+    /// Position among the connections leaving the same output port, children
+    /// are exported in this order. Older projects have 0 everywhere and keep
+    /// the order the connections were made in.
+    #[serde(default)]
+    pub(crate) order: usize,
 }
 
 impl Connection {
     /// Create a new connection between two nodes
-    pub fn new(from_node: usize, from_port: String, to_node: usize, to_port: String) -> Self {
+    pub fn new(from_node: NodeId, from_port: String, to_node: NodeId, to_port: String) -> Self {
         Self {
             from_node,
             to_node,
             from_port,
             to_port,
+            order: 0,
         }
     }
     
     /// Get the source node id
-    pub fn from_node(&self) -> usize {
+    pub fn from_node(&self) -> NodeId {
         self.from_node
     }
     
     /// Get the target node id
-    pub fn to_node(&self) -> usize {
+    pub fn to_node(&self) -> NodeId {
         self.to_node
     }
     
@@ -37,4 +47,17 @@ impl Connection {
     pub fn to_port(&self) -> &str {
         &self.to_port
     }
+    
+    /// Get the position among the siblings
+    pub fn order(&self) -> usize {
+        self.order
+    }
+    
+    /// Whether both connections join the same ports, regardless of their order
+    pub fn same_link(&self, other: &Connection) -> bool {
+        self.from_node == other.from_node
+            && self.to_node == other.to_node
+            && self.from_port == other.from_port
+            && self.to_port == other.to_port
+    }
 } 
\ No newline at end of file
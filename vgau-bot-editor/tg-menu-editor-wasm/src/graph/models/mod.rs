@@ -1,6 +1,7 @@
 // Remove unused egui import
 
 mod node;
+mod node_id;
 mod port;
 mod connection;
 mod parameter;
@@ -9,4 +10,5 @@ pub use node::Node;
 pub use port::{Port, PortType};
 pub use connection::Connection;
 pub use parameter::{Parameter, ParameterType, ParameterValue};
-pub use node::NodeType; 
\ No newline at end of file
+pub use node::NodeType;
+pub use node_id::NodeId; 
\ No newline at end of file
@@ -1,20 +1,21 @@
 use eframe::egui;
-use super::{Port, Parameter};
+use serde::{Deserialize, Serialize};
+use super::{NodeId, Port, Parameter};
+use crate::i18n::Language;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct Node {
-    pub(crate) id: usize,
+    pub(crate) id: NodeId,
     pub(crate) title: String,
     pub(crate) position: egui::Pos2,
     pub(crate) size: egui::Vec2,
-    pub(crate) color: egui::Color32,
     pub(crate) node_type: NodeType,
     pub(crate) inputs: Vec<Port>,
     pub(crate) outputs: Vec<Port>,
     pub(crate) params: Vec<Parameter>,
 }
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum NodeType {
     #[default]
     MenuItem,
@@ -23,6 +24,10 @@ pub enum NodeType {
     Input,
     Output,
     Document,
+    Image,
+    Link,
+    TextContent,
+    Contacts,
 }
 
 impl Node {
@@ -37,20 +42,12 @@ impl Node {
     }
     
     /// Create a new node with the given id, title, position, and node type
-    pub fn new(id: usize, title: String, position: egui::Pos2, node_type: NodeType) -> Self {
+    pub fn new(id: NodeId, title: String, position: egui::Pos2, node_type: NodeType) -> Self {
         Self {
             id,
             title,
             position,
             size: egui::vec2(180.0, 100.0),
-            color: match node_type {
-                NodeType::MenuItem => egui::Color32::from_rgb(100, 150, 200),
-                NodeType::FaqItem => egui::Color32::from_rgb(200, 150, 100),
-                NodeType::Process => egui::Color32::from_rgb(150, 200, 100),
-                NodeType::Input => egui::Color32::from_rgb(100, 200, 150),
-                NodeType::Output => egui::Color32::from_rgb(200, 100, 150),
-                NodeType::Document => egui::Color32::from_rgb(100, 100, 200),
-            },
             node_type,
             inputs: Vec::new(),
             outputs: Vec::new(),
@@ -74,10 +71,35 @@ impl Node {
     }
     
     /// Get the node id
-    pub fn id(&self) -> usize {
+    pub fn id(&self) -> NodeId {
         self.id
     }
     
+    /// Get the node position in graph coordinates
+    pub fn position(&self) -> egui::Pos2 {
+        self.position
+    }
+    
+    /// Get the node size in graph coordinates
+    pub fn size(&self) -> egui::Vec2 {
+        self.size
+    }
+    
+    /// Get the input ports of the node
+    pub fn inputs(&self) -> &[Port] {
+        &self.inputs
+    }
+    
+    /// Get the output ports of the node
+    pub fn outputs(&self) -> &[Port] {
+        &self.outputs
+    }
+    
+    /// Get the area the node covers in graph coordinates
+    pub fn rect(&self) -> egui::Rect {
+        egui::Rect::from_min_size(self.position, self.size)
+    }
+    
     /// Find a parameter by its ID
     pub fn find_param(&self, id: &str) -> Option<&Parameter> {
         self.params.iter().find(|p| p.id() == id)
@@ -92,8 +114,8 @@ impl Node {
     pub fn add_data_parameter(&mut self, data: String) {
         self.add_parameter(
             "data".to_string(),
-            "Custom Data".to_string(), 
-            super::ParameterType::Text,
+            "param.data".to_string(), 
+            super::ParameterType::Json,
             super::ParameterValue::Text(data)
         );
     }
@@ -108,6 +130,31 @@ impl NodeType {
             NodeType::Input => "Input",
             NodeType::Output => "Output",
             NodeType::Document => "document",
+            NodeType::Image => "Image",
+            NodeType::Link => "Link",
+            NodeType::TextContent => "TextContent",
+            NodeType::Contacts => "Contacts",
         }
     }
+    
+    /// Name of the node type shown to users
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            NodeType::MenuItem => "node_type.menu_item",
+            NodeType::FaqItem => "node_type.faq_item",
+            NodeType::Process => "node_type.process",
+            NodeType::Input => "node_type.input",
+            NodeType::Output => "node_type.output",
+            NodeType::Document => "node_type.document",
+            NodeType::Image => "node_type.image",
+            NodeType::Link => "node_type.link",
+            NodeType::TextContent => "node_type.text_content",
+            NodeType::Contacts => "node_type.contacts",
+        })
+    }
+    
+    /// Whether nodes of this type hang off a menu item's content output
+    pub fn is_content(&self) -> bool {
+        matches!(self, NodeType::Image | NodeType::Link | NodeType::TextContent | NodeType::Contacts)
+    }
 } 
\ No newline at end of file
@@ -1,6 +1,10 @@
 // Parameter types and values for nodes
+use eframe::egui;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+use crate::i18n::Language;
+
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Parameter {
     pub(crate) id: String,
     pub(crate) label: String,
@@ -8,20 +12,33 @@ pub struct Parameter {
     pub(crate) value: ParameterValue,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub enum ParameterType {
     Text,
     Number,
     Boolean,
     Select,
+    /// Web or Telegram link
+    Url,
+    /// Long text such as a Telegram message
+    Multiline,
+    /// List of short strings, e.g. FAQ tags
+    StringList,
+    /// Free-form JSON object
+    Json,
+    /// Calendar date in `YYYY-MM-DD` form
+    Date,
+    Color,
 }
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
 pub enum ParameterValue {
     Text(String),
     Number(f64),
     Boolean(bool),
     Select(String, Vec<String>),
+    List(Vec<String>),
+    Color(egui::Color32),
 }
 
 impl Parameter {
@@ -47,6 +64,16 @@ impl Parameter {
             ParameterValue::Number(num) => num.to_string(),
             ParameterValue::Boolean(b) => b.to_string(),
             ParameterValue::Select(selected, _) => selected.clone(),
+            ParameterValue::List(items) => items.join(", "),
+            ParameterValue::Color(color) => format_color(*color),
+        }
+    }
+    
+    /// Get the value as a list of strings, text values are split by commas
+    pub fn get_list(&self) -> Vec<String> {
+        match &self.value {
+            ParameterValue::List(items) => items.clone(),
+            _ => split_list(&self.get_text()),
         }
     }
     
@@ -65,12 +92,19 @@ impl Parameter {
                 }
             },
             ParameterValue::Select(ref mut selected, _) => *selected = text,
+            ParameterValue::List(ref mut items) => *items = split_list(&text),
+            ParameterValue::Color(ref mut color) => {
+                if let Ok(parsed) = parse_color(&text) {
+                    *color = parsed;
+                }
+            },
         }
     }
     
-    /// Get the label of the parameter
-    pub fn label(&self) -> &str {
-        &self.label
+    /// Get the label of the parameter in `language`. Labels are message keys,
+    /// projects saved before that keep the text itself and show it as is.
+    pub fn label(&self, language: Language) -> &str {
+        language.get(&self.label).unwrap_or(&self.label)
     }
     
     /// Get the type of the parameter
@@ -88,34 +122,139 @@ impl Parameter {
         &self.id
     }
     
-    /// Set parameter value as text
-    pub fn set_text_value(&mut self, value: String) {
+    /// Set parameter value from text, checking it against the parameter type.
+    ///
+    /// Invalid text is rejected with an error and the old value is kept.
+    pub fn set_text_value(&mut self, value: String) -> Result<(), String> {
+        check_text(&self.param_type, &value)
+            .map_err(|e| format!("{}: {}", self.id, e))?;
+        
         match &mut self.value {
             ParameterValue::Text(text) => *text = value,
-            ParameterValue::Number(_) => {
-                if let Ok(num) = value.parse::<f64>() {
-                    self.value = ParameterValue::Number(num);
-                } else {
-                    // If it's not a valid number, treat as text
-                    self.value = ParameterValue::Text(value);
-                }
+            ParameterValue::Number(num) => {
+                *num = value.trim().parse::<f64>()
+                    .map_err(|_| format!("{}: \"{}\" is not a number", self.id, value))?;
             },
-            ParameterValue::Boolean(_) => {
-                if let Ok(b) = value.parse::<bool>() {
-                    self.value = ParameterValue::Boolean(b);
-                } else {
-                    // If it's not a valid boolean, treat as text
-                    self.value = ParameterValue::Text(value);
-                }
+            ParameterValue::Boolean(b) => {
+                *b = value.trim().parse::<bool>()
+                    .map_err(|_| format!("{}: \"{}\" is not true or false", self.id, value))?;
             },
             ParameterValue::Select(selected, options) => {
-                if options.contains(&value) {
-                    *selected = value;
-                } else {
-                    // If not in options, just update the text
-                    self.value = ParameterValue::Text(value);
+                if !options.contains(&value) {
+                    return Err(format!("{}: \"{}\" is not one of {}", self.id, value, options.join(", ")));
                 }
+                *selected = value;
             },
+            ParameterValue::List(items) => *items = split_list(&value),
+            ParameterValue::Color(color) => {
+                *color = parse_color(&value).map_err(|e| format!("{}: {}", self.id, e))?;
+            },
+        }
+        
+        Ok(())
+    }
+    
+    /// Check the current value against the parameter type
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.value {
+            ParameterValue::Text(text) => check_text(&self.param_type, text),
+            _ => Ok(()),
         }
     }
-} 
\ No newline at end of file
+}
+
+/// Check that text is acceptable for a parameter of the given type, empty text always is
+fn check_text(param_type: &ParameterType, text: &str) -> Result<(), String> {
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(());
+    }
+    
+    match param_type {
+        ParameterType::Url => check_url(text),
+        ParameterType::Json => serde_json::from_str::<serde_json::Value>(text)
+            .map(|_| ())
+            .map_err(|e| format!("invalid JSON: {}", e)),
+        ParameterType::Date => check_date(text),
+        ParameterType::Color => parse_color(text).map(|_| ()),
+        _ => Ok(()),
+    }
+}
+
+/// Accept links the bot can open: web pages, Telegram deep links and e-mail
+fn check_url(text: &str) -> Result<(), String> {
+    let rest = ["https://", "http://", "tg://", "mailto:"].iter()
+        .find_map(|scheme| text.strip_prefix(scheme));
+    
+    match rest {
+        None => Err(format!("\"{}\" must start with https://, http://, tg:// or mailto:", text)),
+        Some("") => Err(format!("\"{}\" has no address after the scheme", text)),
+        Some(_) if text.chars().any(char::is_whitespace) => Err(format!("\"{}\" must not contain spaces", text)),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Check a `YYYY-MM-DD` date, including the number of days in the month
+fn check_date(text: &str) -> Result<(), String> {
+    let invalid = || format!("\"{}\" is not a date in YYYY-MM-DD form", text);
+    
+    let parts: Vec<&str> = text.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return Err(invalid());
+    }
+    
+    let year: u32 = year.parse().map_err(|_| invalid())?;
+    let month: u32 = month.parse().map_err(|_| invalid())?;
+    let day: u32 = day.parse().map_err(|_| invalid())?;
+    
+    let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return Err(invalid()),
+    };
+    
+    if day == 0 || day > days_in_month {
+        return Err(invalid());
+    }
+    
+    Ok(())
+}
+
+/// Parse a `#rrggbb` or `#rrggbbaa` colour
+pub fn parse_color(text: &str) -> Result<egui::Color32, String> {
+    let invalid = || format!("\"{}\" is not a colour in #rrggbb form", text);
+    let hex = text.trim().strip_prefix('#').ok_or_else(invalid)?;
+    
+    if !(hex.len() == 6 || hex.len() == 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+    let alpha = if hex.len() == 8 { channel(6)? } else { 255 };
+    
+    Ok(egui::Color32::from_rgba_unmultiplied(channel(0)?, channel(2)?, channel(4)?, alpha))
+}
+
+/// Format a colour as `#rrggbb`, adding the alpha channel only when it is not opaque
+pub fn format_color(color: egui::Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if a == 255 {
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    } else {
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
+}
+
+/// Split comma-separated text into trimmed, non-empty items
+fn split_list(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
@@ -1,14 +1,15 @@
-use eframe::egui;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Default)]
+use crate::i18n::Language;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct Port {
     pub(crate) id: String,
     pub(crate) label: String,
     pub(crate) port_type: PortType,
-    pub(crate) color: egui::Color32,
 }
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum PortType {
     #[default]
     String,
@@ -20,18 +21,10 @@ pub enum PortType {
 impl Port {
     /// Create a new port with the given id, label, and port type
     pub fn new(id: String, label: String, port_type: PortType) -> Self {
-        let color = match port_type {
-            PortType::String => egui::Color32::from_rgb(200, 200, 100),
-            PortType::Number => egui::Color32::from_rgb(100, 200, 200),
-            PortType::Object => egui::Color32::from_rgb(150, 200, 255),
-            PortType::Action => egui::Color32::from_rgb(255, 150, 150),
-        };
-        
         Self {
             id,
             label,
             port_type,
-            color,
         }
     }
     
@@ -40,9 +33,10 @@ impl Port {
         &self.id
     }
     
-    /// Get the port label
-    pub fn label(&self) -> &str {
-        &self.label
+    /// Get the port label in `language`. Labels are message keys, projects
+    /// saved before that keep the text itself and show it as is.
+    pub fn label(&self, language: Language) -> &str {
+        language.get(&self.label).unwrap_or(&self.label)
     }
     
     /// Get the port type
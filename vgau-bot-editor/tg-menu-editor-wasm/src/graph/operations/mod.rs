@@ -1,66 +1,72 @@
 use eframe::egui;
-use crate::graph::models::{Node, NodeType, Connection, PortType, ParameterType, ParameterValue};
+use crate::graph::models::{Node, NodeId, NodeType, Connection, PortType, ParameterType, ParameterValue};
 
 /// Provides operations for managing nodes and connections in the graph
 pub struct GraphOperations;
 
 impl GraphOperations {
     /// Create a new menu item node
-    pub fn create_menu_item(id: usize, title: String, position: egui::Pos2) -> Node {
+    pub fn create_menu_item(id: NodeId, title: String, position: egui::Pos2) -> Node {
         let mut node = Node::new(id, title.clone(), position, NodeType::MenuItem);
         
         // Add standard inputs and outputs for menu item nodes
         node.add_input(
             "parent_menu".to_string(),
-            "Родительское меню".to_string(),
+            "port.parent_menu".to_string(),
             PortType::Object
         );
         
         node.add_output(
             "sub_menu".to_string(),
-            "Подменю".to_string(),
+            "port.sub_menu".to_string(),
             PortType::Object
         );
         
         node.add_output(
             "documents".to_string(),
-            "Документы".to_string(),
+            "port.documents".to_string(),
+            PortType::Object
+        );
+        
+        node.add_output(
+            "content".to_string(),
+            "port.content".to_string(),
             PortType::Object
         );
         
         // Add standard parameters
         node.add_parameter(
             "name".to_string(),
-            "Название".to_string(),
+            "param.name".to_string(),
             ParameterType::Text,
             ParameterValue::Text(title)
         );
         
         node.add_parameter(
             "callback_data".to_string(),
-            "Callback Data".to_string(),
+            "callback_data".to_string(),
             ParameterType::Text,
             ParameterValue::Text(String::new())
         );
         
         node.add_parameter(
             "description".to_string(),
-            "Описание".to_string(),
-            ParameterType::Text,
+            "param.description".to_string(),
+            ParameterType::Multiline,
             ParameterValue::Text(String::new())
         );
         
         node.add_parameter(
             "url".to_string(),
             "URL".to_string(),
-            ParameterType::Text,
+            ParameterType::Url,
             ParameterValue::Text(String::new())
         );
         
         node.add_parameter(
             "text_content".to_string(),
-            "Текстовое содержимое".to_string(),
-            ParameterType::Text,
+            "param.text_content".to_string(),
+            ParameterType::Multiline,
             ParameterValue::Text(String::new())
         );
         
@@ -68,7 +74,7 @@ impl GraphOperations {
     }
     
     /// Create a new FAQ item node
-    pub fn create_faq_item(id: usize, title: String, position: egui::Pos2) -> Node {
+    pub fn create_faq_item(id: NodeId, title: String, position: egui::Pos2) -> Node {
         let mut node = Node::new(id, title.clone(), position, NodeType::FaqItem);
         
         // FAQ items don't need ports as they are not connected in the graph
@@ -76,50 +82,50 @@ impl GraphOperations {
         // Add standard parameters for FAQ items
         node.add_parameter(
             "question".to_string(),
-            "Вопрос".to_string(),
+            "param.question".to_string(),
             ParameterType::Text,
             ParameterValue::Text(title)
         );
         
         node.add_parameter(
             "answer".to_string(),
-            "Ответ".to_string(),
-            ParameterType::Text,
+            "param.answer".to_string(),
+            ParameterType::Multiline,
             ParameterValue::Text(String::new())
         );
         
         node.add_parameter(
             "tags".to_string(),
-            "Теги (через запятую)".to_string(),
-            ParameterType::Text,
-            ParameterValue::Text(String::new())
+            "param.tags".to_string(),
+            ParameterType::StringList,
+            ParameterValue::List(Vec::new())
         );
         
         node
     }
     
     /// Create a new document node
-    pub fn create_document(id: usize, title: String, position: egui::Pos2) -> Node {
+    pub fn create_document(id: NodeId, title: String, position: egui::Pos2) -> Node {
         let mut node = Node::new(id, title.clone(), position, NodeType::Document);
         
         // Add standard inputs for document nodes
         node.add_input(
             "parent_menu".to_string(),
-            "Родительское меню".to_string(),
+            "port.parent_menu".to_string(),
             PortType::Object
         );
         
         // Add standard parameters for documents
         node.add_parameter(
             "text".to_string(),
-            "Название".to_string(),
+            "param.name".to_string(),
             ParameterType::Text,
             ParameterValue::Text(title)
         );
         
         node.add_parameter(
             "callback_data".to_string(),
-            "Callback Data".to_string(),
+            "callback_data".to_string(),
             ParameterType::Text,
             ParameterValue::Text(String::new())
         );
@@ -127,13 +133,103 @@ impl GraphOperations {
         node.add_parameter(
             "url".to_string(),
             "URL".to_string(),
+            ParameterType::Url,
+            ParameterValue::Text(String::new())
+        );
+        
+        node
+    }
+    
+    /// Create a new image node, the photo is sent when the parent menu item is opened
+    pub fn create_image(id: NodeId, title: String, position: egui::Pos2) -> Node {
+        let mut node = Self::create_content_node(id, title, position, NodeType::Image);
+        
+        node.add_parameter(
+            "url".to_string(),
+            "param.image_url".to_string(),
+            ParameterType::Url,
+            ParameterValue::Text(String::new())
+        );
+        
+        node.add_parameter(
+            "caption".to_string(),
+            "param.caption".to_string(),
+            ParameterType::Multiline,
+            ParameterValue::Text(String::new())
+        );
+        
+        node
+    }
+    
+    /// Create a new link node, shown as a URL button under the parent menu item
+    pub fn create_link(id: NodeId, title: String, position: egui::Pos2) -> Node {
+        let mut node = Self::create_content_node(id, title.clone(), position, NodeType::Link);
+        
+        node.add_parameter(
+            "text".to_string(),
+            "param.button_text".to_string(),
             ParameterType::Text,
+            ParameterValue::Text(title)
+        );
+        
+        node.add_parameter(
+            "url".to_string(),
+            "URL".to_string(),
+            ParameterType::Url,
+            ParameterValue::Text(String::new())
+        );
+        
+        node
+    }
+    
+    /// Create a new text message node
+    pub fn create_text_content(id: NodeId, title: String, position: egui::Pos2) -> Node {
+        let mut node = Self::create_content_node(id, title, position, NodeType::TextContent);
+        
+        node.add_parameter(
+            "text".to_string(),
+            "param.message_text".to_string(),
+            ParameterType::Multiline,
             ParameterValue::Text(String::new())
         );
         
         node
     }
     
+    /// Create a new contacts card node
+    pub fn create_contacts(id: NodeId, title: String, position: egui::Pos2) -> Node {
+        let mut node = Self::create_content_node(id, title, position, NodeType::Contacts);
+        
+        for (param_id, label, param_type) in [
+            ("address", "param.address", ParameterType::Multiline),
+            ("phone", "param.phone", ParameterType::Text),
+            ("email", "Email", ParameterType::Text),
+            ("website", "param.website", ParameterType::Url),
+        ] {
+            node.add_parameter(
+                param_id.to_string(),
+                label.to_string(),
+                param_type,
+                ParameterValue::Text(String::new())
+            );
+        }
+        
+        node
+    }
+    
+    /// Base for content nodes: they only have the input from the parent menu item
+    fn create_content_node(id: NodeId, title: String, position: egui::Pos2, node_type: NodeType) -> Node {
+        let mut node = Node::new(id, title, position, node_type);
+        
+        node.add_input(
+            "parent_menu".to_string(),
+            "port.parent_menu".to_string(),
+            PortType::Object
+        );
+        
+        node
+    }
+    
     /// Check if document creation is supported
     pub fn supports_document_creation() -> bool {
         true
@@ -166,9 +262,9 @@ impl GraphOperations {
     
     /// Create a connection between two nodes
     pub fn create_connection(
-        from_node: usize,
+        from_node: NodeId,
         from_port: String,
-        to_node: usize,
+        to_node: NodeId,
         to_port: String
     ) -> Connection {
         Connection::new(from_node, from_port, to_node, to_port)
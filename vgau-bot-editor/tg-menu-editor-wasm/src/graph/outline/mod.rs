@@ -0,0 +1,273 @@
+use std::collections::HashSet;
+
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::NodeId;
+
+/// What a row of the outline stands for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutlineKind {
+    Menu,
+    Document,
+    Faq,
+}
+
+impl OutlineKind {
+    pub fn icon(&self) -> &'static str {
+        match self {
+            OutlineKind::Menu => "📁",
+            OutlineKind::Document => "📄",
+            OutlineKind::Faq => "❓",
+        }
+    }
+}
+
+/// A visible row of the outline
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutlineRow {
+    pub node_id: NodeId,
+    pub kind: OutlineKind,
+    /// Menu item the row is listed under, `None` at the top level and in the FAQ list
+    pub parent: Option<NodeId>,
+    pub depth: usize,
+    /// Place among the rows of the same kind under the same parent
+    pub index: usize,
+    pub has_children: bool,
+    pub expanded: bool,
+}
+
+impl OutlineRow {
+    /// A document under several menu items has a row under each of them,
+    /// the parent tells those rows apart
+    pub fn key(&self) -> (Option<NodeId>, NodeId) {
+        (self.parent, self.node_id)
+    }
+}
+
+/// Where a dragged row goes relative to the row it is dropped on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPlace {
+    Before,
+    /// Last below a menu item
+    Inside,
+    After,
+}
+
+/// Keys moving the cursor through the rows
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutlineStep {
+    Up,
+    Down,
+    /// Collapse the row, or go to its parent when it is collapsed already
+    Left,
+    /// Expand the row, or go to its first child when it is expanded already
+    Right,
+    First,
+    Last,
+}
+
+/// Keyboard moves of the node at the cursor
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutlineMove {
+    Up,
+    Down,
+    /// Out of its menu item, right after it
+    Outdent,
+    /// Into the menu item above it, as its last child
+    Indent,
+}
+
+/// Tree view of the menu: top-level menu items with their submenus and
+/// documents, then the FAQ list.
+///
+/// Rows are built from the graph every time, so the outline never goes out
+/// of date. Only which menu items are collapsed is kept here.
+#[derive(Default)]
+pub struct Outline {
+    collapsed: HashSet<NodeId>,
+}
+
+impl Outline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn is_expanded(&self, node_id: NodeId) -> bool {
+        !self.collapsed.contains(&node_id)
+    }
+    
+    pub fn set_expanded(&mut self, node_id: NodeId, expanded: bool) {
+        if expanded {
+            self.collapsed.remove(&node_id);
+        } else {
+            self.collapsed.insert(node_id);
+        }
+    }
+    
+    /// Visible rows, the menu tree first and the FAQ list after it
+    pub fn rows(&self, graph: &SimpleNodeGraph) -> Vec<OutlineRow> {
+        let mut rows = Vec::new();
+        let mut path = HashSet::new();
+        for (index, root_id) in graph.get_root_menu_nodes().into_iter().enumerate() {
+            self.push_menu(graph, &mut rows, &mut path, root_id, None, index);
+        }
+        rows.extend(graph.get_faq_nodes().into_iter().enumerate().map(|(index, node_id)| OutlineRow {
+            node_id,
+            kind: OutlineKind::Faq,
+            parent: None,
+            depth: 0,
+            index,
+            has_children: false,
+            expanded: false,
+        }));
+        rows
+    }
+    
+    /// Add the row of a menu item and, when it is expanded, the rows below it.
+    /// `path` holds the menu items above it.
+    fn push_menu(&self, graph: &SimpleNodeGraph, rows: &mut Vec<OutlineRow>, path: &mut HashSet<NodeId>, node_id: NodeId, parent: Option<NodeId>, index: usize) {
+        let depth = path.len();
+        // A menu item below itself is listed once, without its children
+        let looped = !path.insert(node_id);
+        let children = if looped { Vec::new() } else { graph.get_child_menu_nodes(node_id) };
+        let documents = if looped { Vec::new() } else { graph.get_documents_for_node(node_id) };
+        let has_children = !children.is_empty() || !documents.is_empty();
+        let expanded = has_children && self.is_expanded(node_id);
+        rows.push(OutlineRow {
+            node_id,
+            kind: OutlineKind::Menu,
+            parent,
+            depth,
+            index,
+            has_children,
+            expanded,
+        });
+        
+        if expanded {
+            for (index, child_id) in children.into_iter().enumerate() {
+                self.push_menu(graph, rows, path, child_id, Some(node_id), index);
+            }
+            rows.extend(documents.into_iter().enumerate().map(|(index, document_id)| OutlineRow {
+                node_id: document_id,
+                kind: OutlineKind::Document,
+                parent: Some(node_id),
+                depth: depth + 1,
+                index,
+                has_children: false,
+                expanded: false,
+            }));
+        }
+        if !looped {
+            path.remove(&node_id);
+        }
+    }
+    
+    /// Expand every menu item above a node so that its rows are visible
+    pub fn reveal(&mut self, graph: &SimpleNodeGraph, node_id: NodeId) {
+        let mut visited = HashSet::new();
+        let mut stack = vec![node_id];
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            for conn in graph.connections() {
+                if conn.to_node() == id && conn.to_port() == "parent_menu" {
+                    self.collapsed.remove(&conn.from_node());
+                    stack.push(conn.from_node());
+                }
+            }
+        }
+    }
+    
+    /// Row the cursor is on after a key, expanding or collapsing the row it
+    /// is on for left and right
+    pub fn step(&mut self, rows: &[OutlineRow], cursor: usize, step: OutlineStep) -> usize {
+        let Some(row) = rows.get(cursor) else {
+            return 0;
+        };
+        match step {
+            OutlineStep::Up => cursor.saturating_sub(1),
+            OutlineStep::Down => (cursor + 1).min(rows.len() - 1),
+            OutlineStep::First => 0,
+            OutlineStep::Last => rows.len() - 1,
+            OutlineStep::Left if row.expanded => {
+                self.set_expanded(row.node_id, false);
+                cursor
+            },
+            OutlineStep::Left => rows[..cursor].iter()
+                .rposition(|other| Some(other.node_id) == row.parent && other.depth + 1 == row.depth)
+                .unwrap_or(cursor),
+            OutlineStep::Right if row.has_children && !row.expanded => {
+                self.set_expanded(row.node_id, true);
+                cursor
+            },
+            OutlineStep::Right if row.expanded => cursor + 1,
+            OutlineStep::Right => cursor,
+        }
+    }
+    
+    /// Move the node of a row with the keyboard. Returns whether the graph changed.
+    pub fn move_row(&mut self, graph: &mut SimpleNodeGraph, rows: &[OutlineRow], cursor: usize, step: OutlineMove) -> bool {
+        let Some(row) = rows.get(cursor) else {
+            return false;
+        };
+        match step {
+            OutlineMove::Up => row.index > 0 && graph.move_in_tree(row.node_id, row.parent, row.parent, row.index - 1),
+            OutlineMove::Down => graph.move_in_tree(row.node_id, row.parent, row.parent, row.index + 1),
+            OutlineMove::Outdent => {
+                let Some(parent_row) = rows[..cursor].iter().rev().find(|other| Some(other.node_id) == row.parent) else {
+                    return false;
+                };
+                // Documents come after the submenus, so they go last
+                let index = match row.kind {
+                    OutlineKind::Menu => parent_row.index + 1,
+                    _ => usize::MAX,
+                };
+                graph.move_in_tree(row.node_id, row.parent, parent_row.parent, index)
+            },
+            OutlineMove::Indent => {
+                let Some(new_parent) = rows[..cursor].iter().rev()
+                    .find(|other| other.kind == OutlineKind::Menu && other.parent == row.parent && other.depth == row.depth)
+                else {
+                    return false;
+                };
+                let new_parent = new_parent.node_id;
+                let moved = row.kind == OutlineKind::Menu
+                    && graph.move_in_tree(row.node_id, row.parent, Some(new_parent), usize::MAX);
+                if moved {
+                    self.set_expanded(new_parent, true);
+                }
+                moved
+            },
+        }
+    }
+    
+    /// Drop a dragged row on another one. Returns whether the graph changed.
+    pub fn drop_row(&mut self, graph: &mut SimpleNodeGraph, dragged: &OutlineRow, target: &OutlineRow, place: DropPlace) -> bool {
+        if dragged.key() == target.key() {
+            return false;
+        }
+        if (dragged.kind == OutlineKind::Faq) != (target.kind == OutlineKind::Faq) {
+            return false;
+        }
+        
+        if place == DropPlace::Inside && target.kind == OutlineKind::Menu {
+            let moved = graph.move_in_tree(dragged.node_id, dragged.parent, Some(target.node_id), usize::MAX);
+            if moved {
+                self.set_expanded(target.node_id, true);
+            }
+            return moved;
+        }
+        
+        let index = if dragged.kind == target.kind {
+            let index = target.index + usize::from(place != DropPlace::Before);
+            // Rows after the dragged one move up once it leaves its place
+            if dragged.parent == target.parent && dragged.index < index { index - 1 } else { index }
+        } else if dragged.kind == OutlineKind::Document {
+            // Documents are listed after the submenus
+            0
+        } else {
+            usize::MAX
+        };
+        graph.move_in_tree(dragged.node_id, dragged.parent, target.parent, index)
+    }
+}
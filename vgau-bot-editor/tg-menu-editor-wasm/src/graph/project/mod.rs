@@ -0,0 +1,100 @@
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+use crate::bot_config::BotMenuConfig;
+use crate::graph::models::{Node, Connection};
+
+/// File extension used for native editor projects
+pub const PROJECT_FILE_EXTENSION: &str = "vgaumenu";
+
+/// Marker stored in every project file to tell it apart from bot_data.json exports
+pub const PROJECT_FORMAT_ID: &str = "vgaumenu";
+
+/// Current version of the project file format
+pub const PROJECT_FORMAT_VERSION: u32 = 1;
+
+/// Metadata describing the edited workflow
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct WorkflowMetadata {
+    pub created_at: String,
+    pub modified_at: String,
+    pub author: String,
+    pub description: String,
+    pub tags: Vec<String>,
+}
+
+/// Full state of the node graph as it is stored in a project file
+#[derive(Serialize, Deserialize, Clone)]
+pub struct GraphState {
+    pub nodes: Vec<Node>,
+    pub connections: Vec<Connection>,
+    pub drag_offset: egui::Vec2,
+    pub zoom: f32,
+}
+
+/// Native project file that preserves the whole editor graph.
+///
+/// Unlike the `bot_data.json` export, nothing is dropped here: node positions,
+/// colours, unconnected nodes and empty parameters are all written as is.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProjectFile {
+    pub format: String,
+    pub version: u32,
+    pub workflow_id: String,
+    pub metadata: WorkflowMetadata,
+    /// Title written into the exported bot_data.json
+    #[serde(default)]
+    pub menu_title: String,
+    /// Bot data imported last time, kept as the common base for later merges
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub import_base: Option<BotMenuConfig>,
+    pub graph: GraphState,
+}
+
+impl ProjectFile {
+    /// Create a project file for the current version of the format
+    pub fn new(workflow_id: String, metadata: WorkflowMetadata, graph: GraphState) -> Self {
+        Self {
+            format: PROJECT_FORMAT_ID.to_string(),
+            version: PROJECT_FORMAT_VERSION,
+            workflow_id,
+            metadata,
+            menu_title: String::new(),
+            import_base: None,
+            graph,
+        }
+    }
+    
+    /// Serialize the project to pretty-printed JSON
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Error serializing project: {}", e))
+    }
+    
+    /// Parse a project from JSON, checking the format marker and version
+    pub fn from_json(json_data: &str) -> Result<Self, String> {
+        let project: ProjectFile = serde_json::from_str(json_data)
+            .map_err(|e| format!("Error parsing project: {}", e))?;
+        
+        if project.format != PROJECT_FORMAT_ID {
+            return Err(format!("Unknown project format: {}", project.format));
+        }
+        
+        if project.version > PROJECT_FORMAT_VERSION {
+            return Err(format!(
+                "Project version {} is newer than supported version {}",
+                project.version, PROJECT_FORMAT_VERSION
+            ));
+        }
+        
+        Ok(project)
+    }
+    
+    /// Quick check whether the text looks like a project file rather than a bot export
+    pub fn is_project_json(json_data: &str) -> bool {
+        serde_json::from_str::<serde_json::Value>(json_data)
+            .ok()
+            .and_then(|value| value.get("format").and_then(|f| f.as_str()).map(|f| f == PROJECT_FORMAT_ID))
+            .unwrap_or(false)
+    }
+}
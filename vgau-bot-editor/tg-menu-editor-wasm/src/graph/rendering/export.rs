@@ -0,0 +1,477 @@
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use ab_glyph::{Font, ScaleFont};
+use eframe::egui;
+
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Connection, Node, NodeId};
+use crate::graph::rendering::GraphRenderer;
+use crate::i18n::Language;
+use crate::settings::ConnectionStyle;
+use crate::theme::{Palette, Theme};
+
+/// Free space around the exported nodes
+const MARGIN: f32 = 32.0;
+
+/// Port labels are drawn this far above and below a node
+const LABEL_SPACE: f32 = 22.0;
+
+/// Longest side of a PNG, larger pictures are refused instead of running out of memory
+pub const MAX_PNG_SIDE: u32 = 16384;
+
+/// Control point distance of a cubic curve approximating a quarter circle
+const KAPPA: f32 = 0.552_284_8;
+
+/// File format of an exported picture
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Svg,
+    Png,
+}
+
+impl ImageFormat {
+    pub const ALL: [ImageFormat; 2] = [ImageFormat::Svg, ImageFormat::Png];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "SVG",
+            ImageFormat::Png => "PNG",
+        }
+    }
+    
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "svg",
+            ImageFormat::Png => "png",
+        }
+    }
+    
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            ImageFormat::Svg => "image/svg+xml",
+            ImageFormat::Png => "image/png",
+        }
+    }
+}
+
+/// Which nodes go into the picture
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportScope {
+    /// Every node of the graph
+    All,
+    /// The selected nodes and the connections between them
+    Selection,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum PathCommand {
+    MoveTo(egui::Pos2),
+    LineTo(egui::Pos2),
+    CubicTo(egui::Pos2, egui::Pos2, egui::Pos2),
+    Close,
+}
+
+#[derive(Clone, Debug)]
+enum Shape {
+    Path {
+        commands: Vec<PathCommand>,
+        fill: Option<egui::Color32>,
+        stroke: Option<egui::Stroke>,
+    },
+    Circle {
+        center: egui::Pos2,
+        radius: f32,
+        fill: egui::Color32,
+    },
+    /// A line of text centred on `center`
+    Text {
+        center: egui::Pos2,
+        text: String,
+        size: f32,
+        color: egui::Color32,
+    },
+}
+
+/// The graph drawn the way the canvas shows it at 100% zoom, without the
+/// grid and the selection, ready to be written as SVG or PNG.
+///
+/// Nothing here needs a window or a GPU, so pictures can be made in tests
+/// and in the web build alike.
+pub struct GraphImage {
+    size: egui::Vec2,
+    background: egui::Color32,
+    shapes: Vec<Shape>,
+}
+
+impl GraphImage {
+    /// Lay out the nodes in `scope` with the colours of `theme`
+    pub fn new(graph: &SimpleNodeGraph, scope: ExportScope, theme: Theme) -> Result<Self, String> {
+        let included: HashSet<NodeId> = match scope {
+            ExportScope::All => graph.nodes().iter().map(|node| node.id()).collect(),
+            ExportScope::Selection => graph.selected_nodes().into_iter().collect(),
+        };
+        let nodes: Vec<&Node> = graph.nodes().iter().filter(|node| included.contains(&node.id())).collect();
+        if nodes.is_empty() {
+            return Err(match scope {
+                ExportScope::All => "The graph has no nodes to export".to_string(),
+                ExportScope::Selection => "No nodes are selected".to_string(),
+            });
+        }
+        
+        let palette = theme.palette();
+        let curves: Vec<([egui::Pos2; 4], egui::Color32)> = graph.connections().iter()
+            .filter(|conn| included.contains(&conn.from_node()) && included.contains(&conn.to_node()))
+            .filter_map(|conn| Self::curve(conn, graph.nodes(), graph.connection_style(), palette))
+            .collect();
+        
+        // Port labels stick out of the nodes, curves may bulge past them
+        let bounds = nodes.iter()
+            .map(|node| egui::Rect::from_min_size(node.position, node.size).expand2(egui::vec2(0.0, LABEL_SPACE)))
+            .chain(curves.iter().map(|(points, _)| egui::Rect::from_points(points)))
+            .reduce(|a, b| a.union(b))
+            .unwrap_or(egui::Rect::NOTHING)
+            .expand(MARGIN);
+        let offset = -bounds.min.to_vec2();
+        
+        let mut shapes = Vec::new();
+        for (points, color) in curves {
+            let [from, from_cp, to_cp, to] = points.map(|point| point + offset);
+            shapes.push(Shape::Path {
+                commands: vec![PathCommand::MoveTo(from), PathCommand::CubicTo(from_cp, to_cp, to)],
+                fill: None,
+                stroke: Some(egui::Stroke::new(palette.connection_width, color)),
+            });
+        }
+        for node in nodes {
+            Self::add_node(&mut shapes, node, offset, palette, graph.language());
+        }
+        
+        Ok(Self {
+            size: bounds.size(),
+            background: palette.canvas,
+            shapes,
+        })
+    }
+    
+    /// Size of the picture at scale 1, in pixels
+    pub fn size(&self) -> egui::Vec2 {
+        self.size
+    }
+    
+    /// Curve of a connection and its colour. Straight connections are
+    /// curves with the control points on the ends.
+    fn curve(connection: &Connection, nodes: &[Node], style: ConnectionStyle, palette: &Palette) -> Option<([egui::Pos2; 4], egui::Color32)> {
+        let (from, to) = GraphRenderer::connection_endpoints(connection, nodes, 1.0)?;
+        let points = match style {
+            ConnectionStyle::Bezier => GraphRenderer::bezier_points(from, to, 1.0),
+            ConnectionStyle::Straight => [from, from, to, to],
+        };
+        let color = nodes.iter()
+            .find(|node| node.id() == connection.from_node())
+            .and_then(|node| node.outputs.iter().find(|port| port.id() == connection.from_port()))
+            .map(|port| palette.port_color(port.port_type()))
+            .unwrap_or(palette.text);
+        Some((points, color))
+    }
+    
+    /// The shapes `GraphRenderer::draw_node` paints for an unselected node
+    fn add_node(shapes: &mut Vec<Shape>, node: &Node, offset: egui::Vec2, palette: &Palette, language: Language) {
+        let rect = egui::Rect::from_min_size(node.position, node.size).translate(offset);
+        let fill = palette.node_color(&node.node_type);
+        shapes.push(Shape::Path {
+            commands: rounded_rect(rect, egui::Rounding::same(8.0)),
+            fill: Some(fill),
+            stroke: Some(egui::Stroke::new(2.0, palette.node_frame)),
+        });
+        
+        let title_rect = egui::Rect::from_min_size(rect.min, egui::vec2(rect.width(), 24.0));
+        let title_shade = egui::Color32::from_rgba_premultiplied(0, 0, 0, 100);
+        shapes.push(Shape::Path {
+            commands: rounded_rect(title_rect, egui::Rounding { nw: 8.0, ne: 8.0, sw: 0.0, se: 0.0 }),
+            fill: Some(title_shade),
+            stroke: None,
+        });
+        shapes.push(Shape::Text {
+            center: title_rect.center(),
+            text: node.get_title().to_string(),
+            size: 14.0,
+            color: Palette::text_on(fill.gamma_multiply(1.0 - title_shade.a() as f32 / 255.0)),
+        });
+        
+        for (ports, is_input) in [(&node.inputs, true), (&node.outputs, false)] {
+            for port in ports {
+                let Some(center) = GraphRenderer::get_port_position(node, port.id(), is_input) else {
+                    continue;
+                };
+                let center = center + offset;
+                shapes.push(Shape::Circle {
+                    center,
+                    radius: 6.0,
+                    fill: palette.port_color(port.port_type()),
+                });
+                shapes.push(Shape::Text {
+                    center: center + egui::vec2(0.0, if is_input { -15.0 } else { 15.0 }),
+                    text: port.label(language).to_string(),
+                    size: 10.0,
+                    color: palette.text,
+                });
+            }
+        }
+    }
+    
+    /// The picture as an SVG document, text is kept as text
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w:.0}" height="{h:.0}" viewBox="0 0 {w:.0} {h:.0}" font-family="Ubuntu, 'Segoe UI', sans-serif">"#,
+            w = self.size.x.ceil(),
+            h = self.size.y.ceil(),
+        );
+        let _ = writeln!(svg, r#"  <rect width="100%" height="100%"{}/>"#, svg_paint("fill", self.background));
+        
+        for shape in &self.shapes {
+            match shape {
+                Shape::Path { commands, fill, stroke } => {
+                    let mut d = String::new();
+                    for command in commands {
+                        match command {
+                            PathCommand::MoveTo(p) => { let _ = write!(d, "M{:.1} {:.1}", p.x, p.y); },
+                            PathCommand::LineTo(p) => { let _ = write!(d, "L{:.1} {:.1}", p.x, p.y); },
+                            PathCommand::CubicTo(a, b, p) => {
+                                let _ = write!(d, "C{:.1} {:.1} {:.1} {:.1} {:.1} {:.1}", a.x, a.y, b.x, b.y, p.x, p.y);
+                            },
+                            PathCommand::Close => d.push('Z'),
+                        }
+                    }
+                    let fill = fill.map_or_else(|| r#" fill="none""#.to_string(), |fill| svg_paint("fill", fill));
+                    let stroke = stroke.map_or_else(String::new, |stroke| {
+                        format!(r#"{} stroke-width="{:.1}""#, svg_paint("stroke", stroke.color), stroke.width)
+                    });
+                    let _ = writeln!(svg, r#"  <path d="{}"{}{}/>"#, d, fill, stroke);
+                },
+                Shape::Circle { center, radius, fill } => {
+                    let _ = writeln!(
+                        svg,
+                        r#"  <circle cx="{:.1}" cy="{:.1}" r="{:.1}"{}/>"#,
+                        center.x, center.y, radius, svg_paint("fill", *fill),
+                    );
+                },
+                Shape::Text { center, text, size, color } => {
+                    let _ = writeln!(
+                        svg,
+                        r#"  <text x="{:.1}" y="{:.1}" font-size="{:.0}" text-anchor="middle" dominant-baseline="central"{}>{}</text>"#,
+                        center.x, center.y, size, svg_paint("fill", *color), escape_xml(text),
+                    );
+                },
+            }
+        }
+        
+        svg.push_str("</svg>\n");
+        svg
+    }
+    
+    /// The picture rasterized at `scale` pixels per graph unit and encoded as PNG.
+    /// Text is drawn with the fonts bundled with egui.
+    pub fn to_png(&self, scale: f32) -> Result<Vec<u8>, String> {
+        let width = (self.size.x * scale).ceil() as u32;
+        let height = (self.size.y * scale).ceil() as u32;
+        if width.max(height) > MAX_PNG_SIDE {
+            return Err(format!(
+                "The picture would be {}×{} pixels, sides are limited to {}",
+                width, height, MAX_PNG_SIDE,
+            ));
+        }
+        let mut pixmap = tiny_skia::Pixmap::new(width, height)
+            .ok_or_else(|| format!("Cannot create a {}×{} picture", width, height))?;
+        pixmap.fill(skia_color(self.background));
+        
+        let definitions = egui::FontDefinitions::default();
+        let fonts: Vec<ab_glyph::FontRef> = definitions.families.get(&egui::FontFamily::Proportional)
+            .into_iter()
+            .flatten()
+            .filter_map(|name| definitions.font_data.get(name))
+            .filter_map(|data| ab_glyph::FontRef::try_from_slice_and_index(&data.font, data.index).ok())
+            .collect();
+        
+        let transform = tiny_skia::Transform::from_scale(scale, scale);
+        let paint = |color: egui::Color32| {
+            let mut paint = tiny_skia::Paint::default();
+            paint.set_color(skia_color(color));
+            paint.anti_alias = true;
+            paint
+        };
+        
+        for shape in &self.shapes {
+            match shape {
+                Shape::Path { commands, fill, stroke } => {
+                    let Some(path) = skia_path(commands) else {
+                        continue;
+                    };
+                    if let Some(fill) = fill {
+                        pixmap.fill_path(&path, &paint(*fill), tiny_skia::FillRule::Winding, transform, None);
+                    }
+                    if let Some(stroke) = stroke {
+                        let width = tiny_skia::Stroke {
+                            width: stroke.width,
+                            ..Default::default()
+                        };
+                        pixmap.stroke_path(&path, &paint(stroke.color), &width, transform, None);
+                    }
+                },
+                Shape::Circle { center, radius, fill } => {
+                    if let Some(path) = tiny_skia::PathBuilder::from_circle(center.x, center.y, *radius) {
+                        pixmap.fill_path(&path, &paint(*fill), tiny_skia::FillRule::Winding, transform, None);
+                    }
+                },
+                Shape::Text { center, text, size, color } => {
+                    if let Some(path) = text_path(&fonts, text, *size, *center) {
+                        pixmap.fill_path(&path, &paint(*color), tiny_skia::FillRule::Winding, transform, None);
+                    }
+                },
+            }
+        }
+        
+        pixmap.encode_png().map_err(|e| format!("Error encoding PNG: {}", e))
+    }
+}
+
+/// Outline of a rectangle with rounded corners
+fn rounded_rect(rect: egui::Rect, rounding: egui::Rounding) -> Vec<PathCommand> {
+    use PathCommand::*;
+    
+    let egui::Rounding { nw, ne, sw, se } = rounding;
+    let (min, max) = (rect.min, rect.max);
+    // Control points sit this far from the corner
+    let k = |radius: f32| radius * (1.0 - KAPPA);
+    vec![
+        MoveTo(egui::pos2(min.x + nw, min.y)),
+        LineTo(egui::pos2(max.x - ne, min.y)),
+        CubicTo(egui::pos2(max.x - k(ne), min.y), egui::pos2(max.x, min.y + k(ne)), egui::pos2(max.x, min.y + ne)),
+        LineTo(egui::pos2(max.x, max.y - se)),
+        CubicTo(egui::pos2(max.x, max.y - k(se)), egui::pos2(max.x - k(se), max.y), egui::pos2(max.x - se, max.y)),
+        LineTo(egui::pos2(min.x + sw, max.y)),
+        CubicTo(egui::pos2(min.x + k(sw), max.y), egui::pos2(min.x, max.y - k(sw)), egui::pos2(min.x, max.y - sw)),
+        LineTo(egui::pos2(min.x, min.y + nw)),
+        CubicTo(egui::pos2(min.x, min.y + k(nw)), egui::pos2(min.x + k(nw), min.y), egui::pos2(min.x + nw, min.y)),
+        Close,
+    ]
+}
+
+/// ` fill="#rrggbb"` or ` stroke="#rrggbb"`, with the opacity when the colour is translucent
+fn svg_paint(attribute: &str, color: egui::Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    if a == 255 {
+        format!(r##" {}="#{:02x}{:02x}{:02x}""##, attribute, r, g, b)
+    } else {
+        format!(r##" {}="#{:02x}{:02x}{:02x}" {}-opacity="{:.3}""##, attribute, r, g, b, attribute, a as f32 / 255.0)
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn skia_color(color: egui::Color32) -> tiny_skia::Color {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    tiny_skia::Color::from_rgba8(r, g, b, a)
+}
+
+fn skia_path(commands: &[PathCommand]) -> Option<tiny_skia::Path> {
+    let mut builder = tiny_skia::PathBuilder::new();
+    for command in commands {
+        match *command {
+            PathCommand::MoveTo(p) => builder.move_to(p.x, p.y),
+            PathCommand::LineTo(p) => builder.line_to(p.x, p.y),
+            PathCommand::CubicTo(a, b, p) => builder.cubic_to(a.x, a.y, b.x, b.y, p.x, p.y),
+            PathCommand::Close => builder.close(),
+        }
+    }
+    builder.finish()
+}
+
+/// Glyph outlines of a line of text centred on `center`. Each character comes
+/// from the first font that has it, as in egui.
+fn text_path(fonts: &[ab_glyph::FontRef], text: &str, size: f32, center: egui::Pos2) -> Option<tiny_skia::Path> {
+    // egui sizes fonts by their height rather than by the em square
+    let scaled: Vec<_> = fonts.iter()
+        .map(|font| {
+            let units_per_em = font.units_per_em().unwrap_or(font.height_unscaled());
+            font.as_scaled(size * font.height_unscaled() / units_per_em)
+        })
+        .collect();
+    let main = scaled.first()?;
+    
+    let glyphs: Vec<(usize, ab_glyph::GlyphId)> = text.chars()
+        .map(|c| {
+            let index = scaled.iter().position(|font| font.glyph_id(c).0 != 0).unwrap_or(0);
+            (index, scaled[index].glyph_id(c))
+        })
+        .collect();
+    let mut width = 0.0;
+    let mut previous: Option<(usize, ab_glyph::GlyphId)> = None;
+    for &(index, id) in &glyphs {
+        if let Some((_, previous_id)) = previous.filter(|(previous_index, _)| *previous_index == index) {
+            width += scaled[index].kern(previous_id, id);
+        }
+        width += scaled[index].h_advance(id);
+        previous = Some((index, id));
+    }
+    
+    let baseline = center.y + (main.ascent() + main.descent()) / 2.0;
+    let mut x = center.x - width / 2.0;
+    let mut builder = tiny_skia::PathBuilder::new();
+    previous = None;
+    for (index, id) in glyphs {
+        let font = &scaled[index];
+        if let Some((_, previous_id)) = previous.filter(|(previous_index, _)| *previous_index == index) {
+            x += font.kern(previous_id, id);
+        }
+        if let Some(outline) = font.font().outline(id) {
+            let factor = font.scale_factor();
+            // Font units point up, the picture's y axis points down
+            let point = |p: ab_glyph::Point| (x + p.x * factor.horizontal, baseline - p.y * factor.vertical);
+            let mut last = None;
+            for curve in outline.curves {
+                let (start, end) = match curve {
+                    ab_glyph::OutlineCurve::Line(start, end) => (start, end),
+                    ab_glyph::OutlineCurve::Quad(start, _, end) => (start, end),
+                    ab_glyph::OutlineCurve::Cubic(start, _, _, end) => (start, end),
+                };
+                if last != Some(start) {
+                    let (sx, sy) = point(start);
+                    builder.move_to(sx, sy);
+                }
+                match curve {
+                    ab_glyph::OutlineCurve::Line(_, end) => {
+                        let (ex, ey) = point(end);
+                        builder.line_to(ex, ey);
+                    },
+                    ab_glyph::OutlineCurve::Quad(_, control, end) => {
+                        let ((cx, cy), (ex, ey)) = (point(control), point(end));
+                        builder.quad_to(cx, cy, ex, ey);
+                    },
+                    ab_glyph::OutlineCurve::Cubic(_, first, second, end) => {
+                        let ((ax, ay), (bx, by), (ex, ey)) = (point(first), point(second), point(end));
+                        builder.cubic_to(ax, ay, bx, by, ex, ey);
+                    },
+                }
+                last = Some(end);
+            }
+        }
+        x += font.h_advance(id);
+        previous = Some((index, id));
+    }
+    builder.finish()
+}
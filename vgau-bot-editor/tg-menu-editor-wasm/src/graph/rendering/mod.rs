@@ -1,10 +1,46 @@
+pub mod export;
+
 use eframe::egui;
-use crate::graph::models::{Node, Connection};
+use crate::graph::alignment::Guide;
+use crate::graph::models::{Node, Connection, Parameter, ParameterType, ParameterValue};
+use crate::graph::validation::{Diagnostic, Severity};
+use crate::i18n::Language;
+use crate::settings::ConnectionStyle;
+use crate::theme::{Palette, Theme};
 
 /// Handles rendering of nodes, connections, and other UI elements in the graph
 pub struct GraphRenderer;
 
 impl GraphRenderer {
+    /// Distance between minor grid lines in graph coordinates, nodes snap to it
+    pub const GRID_SPACING: f32 = 25.0;
+    
+    /// Draw a connection between two points in the given style
+    pub fn draw_link(
+        ui: &mut egui::Ui,
+        from: egui::Pos2,
+        to: egui::Pos2,
+        color: egui::Color32,
+        style: ConnectionStyle,
+    ) {
+        match style {
+            ConnectionStyle::Bezier => Self::draw_bezier_connection(ui, from, to, color),
+            ConnectionStyle::Straight => Self::draw_straight_connection(ui, from, to, color),
+        }
+    }
+    
+    /// Draw a connection between two points with a straight line
+    pub fn draw_straight_connection(
+        ui: &mut egui::Ui,
+        from: egui::Pos2,
+        to: egui::Pos2,
+        color: egui::Color32
+    ) {
+        let zoom = ui.memory(|mem| mem.data.get_temp::<f32>(egui::Id::new("graph_zoom")).unwrap_or(1.0));
+        let width = Theme::current(ui.ctx()).palette().connection_width;
+        ui.painter().line_segment([from, to], egui::Stroke::new(width * zoom, color));
+    }
+    
     /// Draw a connection between two points with a bezier curve
     pub fn draw_bezier_connection(
         ui: &mut egui::Ui,
@@ -14,7 +50,65 @@ impl GraphRenderer {
     ) {
         // Get the current zoom level from the graph
         let zoom = ui.memory(|mem| mem.data.get_temp::<f32>(egui::Id::new("graph_zoom")).unwrap_or(1.0));
+        let width = Theme::current(ui.ctx()).palette().connection_width;
         
+        // Draw the bezier curve with scaled stroke width
+        ui.painter().add(egui::Shape::CubicBezier(
+            egui::epaint::CubicBezierShape::from_points_stroke(
+                Self::bezier_points(from, to, zoom),
+                false,
+                egui::Color32::TRANSPARENT,
+                egui::Stroke::new(width * zoom, color),
+            )
+        ));
+    }
+    
+    /// Draw a wide band under a connection, marking it as hovered or selected
+    pub fn draw_link_highlight(
+        ui: &mut egui::Ui,
+        from: egui::Pos2,
+        to: egui::Pos2,
+        color: egui::Color32,
+        style: ConnectionStyle,
+    ) {
+        let zoom = ui.memory(|mem| mem.data.get_temp::<f32>(egui::Id::new("graph_zoom")).unwrap_or(1.0));
+        let width = Theme::current(ui.ctx()).palette().connection_width + 6.0;
+        let stroke = egui::Stroke::new(width * zoom, color);
+        match style {
+            ConnectionStyle::Bezier => {
+                ui.painter().add(egui::Shape::CubicBezier(
+                    egui::epaint::CubicBezierShape::from_points_stroke(
+                        Self::bezier_points(from, to, zoom),
+                        false,
+                        egui::Color32::TRANSPARENT,
+                        stroke,
+                    )
+                ));
+            },
+            ConnectionStyle::Straight => {
+                ui.painter().line_segment([from, to], stroke);
+            },
+        }
+    }
+    
+    /// Screen distance from `pos` to a connection drawn between two points
+    pub fn distance_to_link(pos: egui::Pos2, from: egui::Pos2, to: egui::Pos2, style: ConnectionStyle, zoom: f32) -> f32 {
+        let points = match style {
+            ConnectionStyle::Bezier => egui::epaint::CubicBezierShape::from_points_stroke(
+                Self::bezier_points(from, to, zoom),
+                false,
+                egui::Color32::TRANSPARENT,
+                egui::Stroke::NONE,
+            ).flatten(Some(0.5)),
+            ConnectionStyle::Straight => vec![from, to],
+        };
+        points.windows(2)
+            .map(|segment| distance_to_segment(pos, segment[0], segment[1]))
+            .fold(f32::INFINITY, f32::min)
+    }
+    
+    /// Start, control points and end of the curve a connection is drawn with
+    pub(crate) fn bezier_points(from: egui::Pos2, to: egui::Pos2, zoom: f32) -> [egui::Pos2; 4] {
         // Determine if this is a vertical connection (top to bottom)
         let is_vertical = (to.y - from.y).abs() > (to.x - from.x).abs();
         
@@ -43,24 +137,33 @@ impl GraphRenderer {
             egui::pos2(to.x - control_distance, to.y)
         };
         
-        // Draw the bezier curve with scaled stroke width
-        ui.painter().add(egui::Shape::CubicBezier(
-            egui::epaint::CubicBezierShape::from_points_stroke(
-                [from, from_cp, to_cp, to],
-                false,
-                egui::Color32::TRANSPARENT,
-                egui::Stroke::new(2.0 * zoom, color),
-            )
-        ));
+        [from, from_cp, to_cp, to]
+    }
+    
+    /// Draw alignment guides of dragged nodes, given in graph coordinates
+    pub fn draw_guides(ui: &mut egui::Ui, guides: &[Guide], zoom: f32) {
+        let stroke = egui::Stroke::new(1.0, Theme::current(ui.ctx()).palette().guide);
+        for guide in guides {
+            let (from, to) = if guide.vertical {
+                (egui::pos2(guide.position, guide.from), egui::pos2(guide.position, guide.to))
+            } else {
+                (egui::pos2(guide.from, guide.position), egui::pos2(guide.to, guide.position))
+            };
+            ui.painter().line_segment(
+                [egui::pos2(from.x * zoom, from.y * zoom), egui::pos2(to.x * zoom, to.y * zoom)],
+                stroke,
+            );
+        }
     }
     
     /// Draw a grid in the background
     pub fn draw_grid(ui: &mut egui::Ui, rect: egui::Rect, zoom: f32) {
-        let grid_color_major = egui::Color32::from_gray(60);
-        let grid_color_minor = egui::Color32::from_gray(40);
+        let palette = Theme::current(ui.ctx()).palette();
+        let grid_color_major = palette.grid_major;
+        let grid_color_minor = palette.grid_minor;
         
-        let grid_spacing_major = 100.0 * zoom;
-        let grid_spacing_minor = 25.0 * zoom;
+        let grid_spacing_major = Self::GRID_SPACING * 4.0 * zoom;
+        let grid_spacing_minor = Self::GRID_SPACING * zoom;
         
         // Minor grid lines
         let mut x = rect.min.x - (rect.min.x % grid_spacing_minor);
@@ -138,24 +241,25 @@ impl GraphRenderer {
         let node_rect = egui::Rect::from_min_size(scaled_position, scaled_size);
         
         // Decide colors based on node type and active state
+        let palette = Theme::current(ui.ctx()).palette();
+        let language = Language::current(ui.ctx());
+        let node_color = palette.node_color(&node.node_type);
         let bg_color = if is_active {
             // Brighter version of the node color
-            let mut color = node.color;
-            color = egui::Color32::from_rgba_premultiplied(
-                color.r().saturating_add(40),
-                color.g().saturating_add(40),
-                color.b().saturating_add(40),
-                color.a(),
-            );
-            color
+            egui::Color32::from_rgba_premultiplied(
+                node_color.r().saturating_add(40),
+                node_color.g().saturating_add(40),
+                node_color.b().saturating_add(40),
+                node_color.a(),
+            )
         } else {
-            node.color
+            node_color
         };
         
         let frame_color = if is_active {
-            egui::Color32::WHITE
+            palette.selection
         } else {
-            egui::Color32::from_gray(180)
+            palette.node_frame
         };
         
         // Draw node background
@@ -179,6 +283,7 @@ impl GraphRenderer {
             egui::vec2(node_rect.width(), title_height),
         );
         
+        let title_shade = egui::Color32::from_rgba_premultiplied(0, 0, 0, 100);
         ui.painter().add(egui::Shape::rect_filled(
             title_rect,
             egui::Rounding {
@@ -187,25 +292,23 @@ impl GraphRenderer {
                 sw: 0.0,
                 se: 0.0,
             },
-            egui::Color32::from_rgba_premultiplied(
-                0, 0, 0, 100
-            ),
+            title_shade,
         ));
         
-        // Node title text
+        // Node title text, in whichever of black and white reads better on the shaded title
         let title_pos = title_rect.center();
         ui.painter().text(
             title_pos,
             egui::Align2::CENTER_CENTER,
             node.get_title(),
             egui::FontId::proportional(14.0 * zoom), // Scale font size with zoom
-            egui::Color32::WHITE,
+            Palette::text_on(bg_color.gamma_multiply(1.0 - title_shade.a() as f32 / 255.0)),
         );
         
         // Draw input ports
         let port_radius = 6.0 * zoom;
         for (i, port) in node.inputs.iter().enumerate() {
-            if node.inputs.len() > 0 {
+            if !node.inputs.is_empty() {
                 let port_spacing = node_rect.width() / (node.inputs.len() as f32 + 1.0);
                 let port_x = node_rect.min.x + port_spacing * (i as f32 + 1.0);
                 let port_y = node_rect.min.y;
@@ -214,16 +317,16 @@ impl GraphRenderer {
                 ui.painter().add(egui::Shape::circle_filled(
                     egui::pos2(port_x, port_y),
                     port_radius,
-                    port.color,
+                    palette.port_color(&port.port_type),
                 ));
                 
                 // Draw port label
                 ui.painter().text(
                     egui::pos2(port_x, port_y - 15.0 * zoom),
                     egui::Align2::CENTER_CENTER,
-                    &port.label,
+                    port.label(language),
                     egui::FontId::proportional(10.0 * zoom), // Scale font size with zoom
-                    egui::Color32::WHITE,
+                    palette.text,
                 );
                 
                 // Add interaction for port (for future use)
@@ -239,7 +342,7 @@ impl GraphRenderer {
         
         // Draw output ports
         for (i, port) in node.outputs.iter().enumerate() {
-            if node.outputs.len() > 0 {
+            if !node.outputs.is_empty() {
                 let port_spacing = node_rect.width() / (node.outputs.len() as f32 + 1.0);
                 let port_x = node_rect.min.x + port_spacing * (i as f32 + 1.0);
                 let port_y = node_rect.max.y;
@@ -248,16 +351,16 @@ impl GraphRenderer {
                 ui.painter().add(egui::Shape::circle_filled(
                     egui::pos2(port_x, port_y),
                     port_radius,
-                    port.color,
+                    palette.port_color(&port.port_type),
                 ));
                 
                 // Draw port label
                 ui.painter().text(
                     egui::pos2(port_x, port_y + 15.0 * zoom),
                     egui::Align2::CENTER_CENTER,
-                    &port.label,
+                    port.label(language),
                     egui::FontId::proportional(10.0 * zoom), // Scale font size with zoom
-                    egui::Color32::WHITE,
+                    palette.text,
                 );
                 
                 // Add interaction for port (for future use)
@@ -276,6 +379,65 @@ impl GraphRenderer {
         *response = Some(ui.interact(node_rect, ui.id().with(node.id()), node_sense));
     }
     
+    /// Draw a badge in the node corner for the most serious of its diagnostics
+    pub fn draw_node_badge(ui: &mut egui::Ui, node: &Node, diagnostics: &[&Diagnostic]) {
+        let Some(severity) = diagnostics.iter().map(|d| d.severity).min() else {
+            return;
+        };
+        
+        let zoom = ui.memory(|mem| mem.data.get_temp::<f32>(egui::Id::new("graph_zoom")).unwrap_or(1.0));
+        let center = egui::pos2(
+            (node.position.x + node.size.x) * zoom,
+            node.position.y * zoom
+        );
+        let radius = 8.0 * zoom;
+        
+        let palette = Theme::current(ui.ctx()).palette();
+        let (color, icon) = match severity {
+            Severity::Error => (palette.error, "!"),
+            Severity::Warning => (palette.warning, "!"),
+            Severity::Info => (palette.info, "i"),
+        };
+        
+        ui.painter().circle(center, radius, color, egui::Stroke::new(1.0 * zoom, palette.canvas));
+        ui.painter().text(
+            center,
+            egui::Align2::CENTER_CENTER,
+            icon,
+            egui::FontId::proportional(11.0 * zoom),
+            Palette::text_on(color),
+        );
+        
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        let badge_rect = egui::Rect::from_center_size(center, egui::vec2(radius * 2.0, radius * 2.0));
+        ui.interact(badge_rect, ui.id().with(("badge", node.id())), egui::Sense::hover())
+            .on_hover_text(messages.join("\n"));
+    }
+    
+    /// Draw the minimap: every node as a rectangle of its colour and the
+    /// visible part of the graph as a frame, all in minimap coordinates
+    pub fn draw_minimap(
+        ui: &mut egui::Ui,
+        rect: egui::Rect,
+        nodes: &[(egui::Rect, egui::Color32)],
+        visible: egui::Rect
+    ) {
+        let palette = Theme::current(ui.ctx()).palette();
+        let painter = ui.painter_at(rect);
+        painter.rect(
+            rect,
+            4.0,
+            palette.canvas.gamma_multiply(0.86),
+            egui::Stroke::new(1.0, palette.border),
+        );
+        
+        for (node_rect, color) in nodes {
+            painter.rect_filled(*node_rect, 1.0, *color);
+        }
+        
+        painter.rect_stroke(visible, 2.0, egui::Stroke::new(1.5, palette.selection));
+    }
+    
     /// Calculate the position of a port on a node
     pub fn get_port_position(
         node: &Node,
@@ -305,51 +467,145 @@ impl GraphRenderer {
         Some(egui::pos2(port_x, port_y))
     }
     
-    /// Draw a connection between two nodes
+    /// Screen positions of the ports a connection joins
+    pub fn connection_endpoints(connection: &Connection, nodes: &[Node], zoom: f32) -> Option<(egui::Pos2, egui::Pos2)> {
+        let from_node = nodes.iter().find(|n| n.id() == connection.from_node())?;
+        let to_node = nodes.iter().find(|n| n.id() == connection.to_node())?;
+        let from_pos = Self::get_port_position(from_node, connection.from_port(), false)?;
+        let to_pos = Self::get_port_position(to_node, connection.to_port(), true)?;
+        
+        // Scale positions by zoom
+        Some((
+            egui::pos2(from_pos.x * zoom, from_pos.y * zoom),
+            egui::pos2(to_pos.x * zoom, to_pos.y * zoom),
+        ))
+    }
+    
+    /// Draw a connection between two nodes, over a band of `highlight` if given
     pub fn draw_connection(
         ui: &mut egui::Ui,
         connection: &Connection,
         nodes: &[Node],
+        style: ConnectionStyle,
+        highlight: Option<egui::Color32>,
     ) {
         // Get the current zoom level from the graph
         let zoom = ui.memory(|mem| mem.data.get_temp::<f32>(egui::Id::new("graph_zoom")).unwrap_or(1.0));
         
-        // Find the nodes
-        let from_node = nodes.iter().find(|n| n.id() == connection.from_node());
-        let to_node = nodes.iter().find(|n| n.id() == connection.to_node());
+        let Some((from_pos, to_pos)) = Self::connection_endpoints(connection, nodes, zoom) else {
+            return;
+        };
         
-        if let (Some(from_node), Some(to_node)) = (from_node, to_node) {
-            // Calculate port positions
-            let from_pos = Self::get_port_position(from_node, connection.from_port(), false);
-            let to_pos = Self::get_port_position(to_node, connection.to_port(), true);
-            
-            if let (Some(from_pos), Some(to_pos)) = (from_pos, to_pos) {
-                // Scale positions by zoom
-                let from_pos = egui::pos2(
-                    from_pos.x * zoom,
-                    from_pos.y * zoom
-                );
-                let to_pos = egui::pos2(
-                    to_pos.x * zoom,
-                    to_pos.y * zoom
-                );
+        // Determine color based on port types
+        let palette = Theme::current(ui.ctx()).palette();
+        let color = nodes.iter()
+            .find(|n| n.id() == connection.from_node())
+            .and_then(|node| node.outputs.iter().find(|p| p.id() == connection.from_port()))
+            .map(|port| palette.port_color(port.port_type()))
+            .unwrap_or(palette.text);
+        
+        if let Some(highlight) = highlight {
+            Self::draw_link_highlight(ui, from_pos, to_pos, highlight, style);
+        }
+        // Draw the connection with scaled stroke width
+        Self::draw_link(ui, from_pos, to_pos, color, style);
+    }
+    
+    /// Draw the editor widget matching the parameter type, returns true if the value changed
+    pub fn draw_parameter_widget(ui: &mut egui::Ui, param: &mut Parameter) -> bool {
+        let widget_id = ui.id().with(("parameter", param.id.clone()));
+        let language = Language::current(ui.ctx());
+        
+        match (&param.param_type, &mut param.value) {
+            (_, ParameterValue::Number(num)) => ui.add(egui::DragValue::new(num).speed(0.1)).changed(),
+            (_, ParameterValue::Boolean(value)) => ui.checkbox(value, "").changed(),
+            (_, ParameterValue::Select(selected, options)) => {
+                let mut changed = false;
+                egui::ComboBox::from_id_source(widget_id)
+                    .selected_text(selected.clone())
+                    .show_ui(ui, |ui| {
+                        for option in options.iter() {
+                            if ui.selectable_label(selected == option, option.clone()).clicked() {
+                                *selected = option.clone();
+                                changed = true;
+                            }
+                        }
+                    });
+                changed
+            },
+            (_, ParameterValue::List(items)) => Self::draw_string_list(ui, items),
+            (_, ParameterValue::Color(color)) => ui.color_edit_button_srgba(color).changed(),
+            (ParameterType::Multiline, ParameterValue::Text(text)) => {
+                ui.add(egui::TextEdit::multiline(text)
+                    .desired_rows(4)
+                    .desired_width(f32::INFINITY)).changed()
+            },
+            (ParameterType::Json, ParameterValue::Text(text)) => {
+                let mut changed = ui.add(egui::TextEdit::multiline(text)
+                    .code_editor()
+                    .desired_rows(6)
+                    .desired_width(f32::INFINITY)).changed();
                 
-                // Determine color based on port types
-                let from_port = from_node.outputs.iter().find(|p| p.id() == connection.from_port());
-                let color = if let Some(port) = from_port {
-                    match port.port_type() {
-                        crate::graph::models::PortType::String => egui::Color32::from_rgb(200, 200, 100),
-                        crate::graph::models::PortType::Number => egui::Color32::from_rgb(100, 200, 200),
-                        crate::graph::models::PortType::Object => egui::Color32::from_rgb(150, 200, 255),
-                        crate::graph::models::PortType::Action => egui::Color32::from_rgb(255, 150, 150),
+                // Pretty-print valid JSON on request, invalid JSON is left for the user to fix
+                let parsed = serde_json::from_str::<serde_json::Value>(text).ok();
+                if ui.add_enabled(parsed.is_some(), egui::Button::new(language.tr("param.format_json"))).clicked() {
+                    if let Some(pretty) = parsed.and_then(|value| serde_json::to_string_pretty(&value).ok()) {
+                        *text = pretty;
+                        changed = true;
                     }
-                } else {
-                    egui::Color32::WHITE
-                };
-                
-                // Draw bezier connection with scaled stroke width
-                Self::draw_bezier_connection(ui, from_pos, to_pos, color);
+                }
+                changed
+            },
+            (ParameterType::Url, ParameterValue::Text(text)) => {
+                ui.add(egui::TextEdit::singleline(text).hint_text("https://")).changed()
+            },
+            (ParameterType::Date, ParameterValue::Text(text)) => {
+                ui.add(egui::TextEdit::singleline(text).hint_text(language.tr("param.date_hint")).desired_width(100.0)).changed()
+            },
+            (ParameterType::Color, ParameterValue::Text(text)) => {
+                ui.add(egui::TextEdit::singleline(text).hint_text("#rrggbb").desired_width(100.0)).changed()
+            },
+            (_, ParameterValue::Text(text)) => ui.text_edit_singleline(text).changed(),
+        }
+    }
+    
+    /// One row per list item with a remove button, plus a button to add an item
+    fn draw_string_list(ui: &mut egui::Ui, items: &mut Vec<String>) -> bool {
+        let mut changed = false;
+        let mut to_remove = None;
+        
+        ui.vertical(|ui| {
+            for (index, item) in items.iter_mut().enumerate() {
+                ui.horizontal(|ui| {
+                    changed |= ui.text_edit_singleline(item).changed();
+                    if ui.small_button("✖").clicked() {
+                        to_remove = Some(index);
+                    }
+                });
+            }
+            
+            if ui.small_button(Language::current(ui.ctx()).tr("param.add_item")).clicked() {
+                items.push(String::new());
+                changed = true;
             }
+        });
+        
+        if let Some(index) = to_remove {
+            items.remove(index);
+            changed = true;
         }
+        
+        changed
     }
-} 
\ No newline at end of file
+}
+
+/// Distance from `pos` to the closest point between `a` and `b`
+fn distance_to_segment(pos: egui::Pos2, a: egui::Pos2, b: egui::Pos2) -> f32 {
+    let along = b - a;
+    let t = if along.length_sq() > 0.0 {
+        ((pos - a).dot(along) / along.length_sq()).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    pos.distance(a + along * t)
+}
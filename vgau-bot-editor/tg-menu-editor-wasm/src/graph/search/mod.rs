@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Node, NodeId, NodeType};
+
+/// Parameters searched besides the title, with the message key of the label
+/// shown for a match
+const SEARCHED_PARAMS: &[(&str, &str)] = &[
+    ("callback_data", "search.field.callback_data"),
+    ("url", "search.field.url"),
+    ("description", "search.field.description"),
+    ("answer", "search.field.answer"),
+    ("tags", "search.field.tags"),
+];
+
+/// Message key of the label shown for a title match
+const TITLE_FIELD: &str = "search.field.title";
+
+/// Longest piece of the matched text shown with a result, in characters
+const SNIPPET_LENGTH: usize = 80;
+
+/// A node found by [`GraphSearch`]
+#[derive(Clone, Debug)]
+pub struct SearchMatch {
+    pub node_id: NodeId,
+    /// Text on the node's button or its FAQ question
+    pub title: String,
+    /// Message key of the label of the field that matched best
+    pub field: &'static str,
+    /// Value of that field
+    pub text: String,
+    pub score: i32,
+    /// Titles from the top of the menu down to the node's parent
+    pub path: Vec<String>,
+}
+
+impl SearchMatch {
+    /// Path to the node for display, e.g. "Абитуриенту › Информация о приёме".
+    /// Long paths keep the first and the last two steps.
+    pub fn breadcrumb(&self) -> String {
+        if self.path.len() > 3 {
+            let tail = &self.path[self.path.len() - 2..];
+            format!("{} › … › {}", self.path[0], tail.join(" › "))
+        } else {
+            self.path.join(" › ")
+        }
+    }
+    
+    /// The matched text cut to a length that fits on one line
+    pub fn snippet(&self) -> String {
+        let text = self.text.replace('\n', " ");
+        if text.chars().count() > SNIPPET_LENGTH {
+            format!("{}…", text.chars().take(SNIPPET_LENGTH).collect::<String>())
+        } else {
+            text
+        }
+    }
+}
+
+/// Score how well `query` matches `text`, ignoring case.
+///
+/// Every character of the query has to appear in the text in the same order.
+/// Substrings score highest, then matches that run on or start words, so
+/// "прав пр" finds "Правила приёма" before "Справка о переводе".
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    let query: Vec<char> = query.trim().chars().flat_map(char::to_lowercase).collect();
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() || text.is_empty() {
+        return None;
+    }
+    
+    // Whole query found as is
+    if let Some(start) = text.windows(query.len()).position(|window| window == query.as_slice()) {
+        let word_bonus = if start == 0 || !text[start - 1].is_alphanumeric() { 100 } else { 0 };
+        return Some(1000 + word_bonus - start.min(100) as i32);
+    }
+    
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous: Option<usize> = None;
+    for c in &query {
+        let found = text[position..].iter().position(|t| t == c)? + position;
+        score += 10;
+        if previous.is_some_and(|p| p + 1 == found) {
+            score += 15;
+        }
+        if found == 0 || !text[found - 1].is_alphanumeric() {
+            score += 10;
+        }
+        previous = Some(found);
+        position = found + 1;
+    }
+    
+    // Prefer compact matches in short texts
+    Some(score - (text.len() / 10) as i32)
+}
+
+/// Finds nodes by their title and the parameters users remember them by
+pub struct GraphSearch;
+
+impl GraphSearch {
+    /// Nodes matching `query`, best first. Each node is listed once, with the
+    /// field that matched best.
+    pub fn search(graph: &SimpleNodeGraph, query: &str, limit: usize) -> Vec<SearchMatch> {
+        let mut matches: Vec<SearchMatch> = graph.nodes().iter()
+            .filter_map(|node| {
+                let title = Self::title(node);
+                let mut fields = vec![(TITLE_FIELD, title.clone())];
+                fields.extend(SEARCHED_PARAMS.iter().filter_map(|(id, label)| {
+                    let text = node.find_param(id).map(|p| p.get_text()).unwrap_or_default();
+                    (!text.is_empty()).then_some((*label, text))
+                }));
+                
+                let (field, text, score) = fields.into_iter()
+                    .filter_map(|(field, text)| {
+                        let score = fuzzy_score(query, &text)?;
+                        // Titles are what users think of first
+                        let bonus = if field == TITLE_FIELD { 50 } else { 0 };
+                        Some((field, text, score + bonus))
+                    })
+                    .max_by_key(|(_, _, score)| *score)?;
+                
+                Some(SearchMatch {
+                    node_id: node.id(),
+                    title,
+                    field,
+                    text,
+                    score,
+                    path: Vec::new(),
+                })
+            })
+            .collect();
+        
+        // Stable sort keeps graph order among equal scores
+        matches.sort_by_key(|m| -m.score);
+        matches.truncate(limit);
+        for m in &mut matches {
+            m.path = Self::path(graph, m.node_id);
+        }
+        matches
+    }
+    
+    /// Titles of the menu items above a node, starting from the main menu.
+    /// FAQ items are listed under "FAQ".
+    pub fn path(graph: &SimpleNodeGraph, node_id: NodeId) -> Vec<String> {
+        if graph.get_node_data(node_id).is_some_and(|node| node.node_type == NodeType::FaqItem) {
+            return vec!["FAQ".to_string()];
+        }
+        
+        let mut path = Vec::new();
+        let mut visited = HashSet::from([node_id]);
+        let mut current = node_id;
+        // Documents and content hang below a menu item the same way submenus do
+        while let Some(parent_id) = graph.connections().iter()
+            .find(|conn| conn.to_node() == current && conn.to_port() == "parent_menu")
+            .map(|conn| conn.from_node())
+        {
+            if !visited.insert(parent_id) {
+                break;
+            }
+            let Some(parent) = graph.get_node_data(parent_id) else {
+                break;
+            };
+            path.push(Self::title(parent));
+            current = parent_id;
+        }
+        
+        path.reverse();
+        path
+    }
+    
+    fn title(node: &Node) -> String {
+        ["name", "question"].iter()
+            .filter_map(|id| node.find_param(id))
+            .map(|p| p.get_text())
+            .find(|text| !text.is_empty())
+            .unwrap_or_else(|| node.get_title().to_string())
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Node, NodeId, NodeType};
+use crate::i18n::Language;
+
+pub mod runtime;
+pub mod telegram;
+
+/// How serious a diagnostic is
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Severity {
+    /// The graph cannot be exported as is
+    Error,
+    /// Something will be dropped or duplicated on export
+    Warning,
+    /// Worth knowing, but exported as expected
+    Info,
+}
+
+impl Severity {
+    /// Label shown in the diagnostics panel
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            Severity::Error => "validation.severity.error",
+            Severity::Warning => "validation.severity.warning",
+            Severity::Info => "validation.severity.info",
+        })
+    }
+}
+
+/// A problem found in the graph, attached to the node it concerns.
+/// The message is worded in the language of the graph.
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub node_id: NodeId,
+    pub message: String,
+}
+
+/// Checks the graph for everything the exporter would silently drop or get wrong
+pub struct GraphValidator;
+
+impl GraphValidator {
+    /// Run every check, errors first
+    pub fn validate(graph: &SimpleNodeGraph) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        
+        Self::check_titles(graph, &mut diagnostics);
+        Self::check_duplicate_callback_data(graph, &mut diagnostics);
+        Self::check_submenu_cycles(graph, &mut diagnostics);
+        Self::check_parents(graph, &mut diagnostics);
+        Self::check_dropped_content(graph, &mut diagnostics);
+        telegram::check(graph, &mut diagnostics);
+        
+        diagnostics.sort_by_key(|d| d.severity);
+        diagnostics
+    }
+    
+    /// Whether any diagnostic blocks the export
+    pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+        diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+    
+    /// Menu items, documents and questions need a visible text
+    fn check_titles(graph: &SimpleNodeGraph, diagnostics: &mut Vec<Diagnostic>) {
+        for node in graph.nodes() {
+            let (text, message) = match node.node_type {
+                NodeType::MenuItem => (Self::param_or_title(node, "name"), "validation.empty_menu_item"),
+                NodeType::Document => (Self::param_or_title(node, "text"), "validation.empty_document"),
+                NodeType::FaqItem => (Self::param_or_title(node, "question"), "validation.empty_question"),
+                _ => continue,
+            };
+            
+            if text.trim().is_empty() {
+                Self::push(diagnostics, Severity::Error, node.id(), graph.language().tr(message).to_string());
+            }
+        }
+    }
+    
+    /// The bot finds menu items and documents by callback_data, so it must be unique
+    fn check_duplicate_callback_data(graph: &SimpleNodeGraph, diagnostics: &mut Vec<Diagnostic>) {
+        let mut owners: HashMap<String, Vec<NodeId>> = HashMap::new();
+        
+        for node in graph.nodes() {
+            if !matches!(node.node_type, NodeType::MenuItem | NodeType::Document) {
+                continue;
+            }
+            let callback_data = Self::param_text(node, "callback_data");
+            if !callback_data.is_empty() {
+                owners.entry(callback_data).or_default().push(node.id());
+            }
+        }
+        
+        for (callback_data, node_ids) in owners {
+            if node_ids.len() < 2 {
+                continue;
+            }
+            for node_id in node_ids.iter() {
+                Self::push(diagnostics, Severity::Error, *node_id, graph.language().tr_args(
+                    "validation.duplicate_callback_data",
+                    &[("callback_data", &callback_data), ("count", &node_ids.len())],
+                ));
+            }
+        }
+    }
+    
+    /// A sub_menu cycle would make the exporter recurse forever
+    fn check_submenu_cycles(graph: &SimpleNodeGraph, diagnostics: &mut Vec<Diagnostic>) {
+        let mut finished = HashSet::new();
+        
+        for node in graph.nodes() {
+            if node.node_type == NodeType::MenuItem && !finished.contains(&node.id()) {
+                let mut path = Vec::new();
+                Self::find_cycles(graph, node.id(), &mut path, &mut finished, diagnostics);
+            }
+        }
+    }
+    
+    fn find_cycles(
+        graph: &SimpleNodeGraph,
+        node_id: NodeId,
+        path: &mut Vec<NodeId>,
+        finished: &mut HashSet<NodeId>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        if let Some(start) = path.iter().position(|id| *id == node_id) {
+            let names: Vec<String> = path[start..].iter()
+                .chain(std::iter::once(&node_id))
+                .map(|id| Self::display_name(graph, *id))
+                .collect();
+            let message = graph.language().tr_args("validation.submenu_cycle", &[("path", &names.join(" → "))]);
+            Self::push(diagnostics, Severity::Error, node_id, message);
+            return;
+        }
+        if finished.contains(&node_id) {
+            return;
+        }
+        
+        path.push(node_id);
+        for child_id in graph.get_child_menu_nodes(node_id) {
+            Self::find_cycles(graph, child_id, path, finished, diagnostics);
+        }
+        path.pop();
+        
+        finished.insert(node_id);
+    }
+    
+    /// Orphans are not exported, items with several parents are exported several times
+    fn check_parents(graph: &SimpleNodeGraph, diagnostics: &mut Vec<Diagnostic>) {
+        let language = graph.language();
+        for node in graph.nodes() {
+            let parents = graph.connections().iter()
+                .filter(|conn| conn.to_node() == node.id() && conn.to_port() == "parent_menu")
+                .count();
+            
+            match (&node.node_type, parents) {
+                (NodeType::MenuItem, 0) => Self::push(diagnostics, Severity::Info, node.id(),
+                    language.tr("validation.orphan_menu_item").to_string()),
+                (NodeType::Document, 0) => Self::push(diagnostics, Severity::Warning, node.id(),
+                    language.tr("validation.orphan_document").to_string()),
+                (node_type, 0) if node_type.is_content() => Self::push(diagnostics, Severity::Warning, node.id(),
+                    language.tr("validation.orphan_content").to_string()),
+                (NodeType::MenuItem | NodeType::Document, count) if count > 1 => Self::push(diagnostics, Severity::Warning, node.id(),
+                    language.tr_args("validation.several_parents", &[("count", &count)])),
+                _ => {},
+            }
+        }
+    }
+    
+    /// Nodes the exporter skips because a required field is empty
+    fn check_dropped_content(graph: &SimpleNodeGraph, diagnostics: &mut Vec<Diagnostic>) {
+        for node in graph.nodes() {
+            let message = match node.node_type {
+                NodeType::Document if Self::param_text(node, "url").is_empty() => "validation.document_without_url",
+                NodeType::FaqItem if Self::param_text(node, "answer").is_empty() => "validation.question_without_answer",
+                NodeType::Image if Self::param_text(node, "url").is_empty() => "validation.image_without_url",
+                NodeType::Link if Self::param_text(node, "url").is_empty() => "validation.link_without_url",
+                NodeType::TextContent if Self::param_text(node, "text").is_empty() => "validation.empty_text",
+                NodeType::Contacts if node.get_params().iter().all(|p| p.get_text().is_empty()) => "validation.empty_contacts",
+                _ => continue,
+            };
+            
+            Self::push(diagnostics, Severity::Warning, node.id(), graph.language().tr(message).to_string());
+        }
+    }
+    
+    fn push(diagnostics: &mut Vec<Diagnostic>, severity: Severity, node_id: NodeId, message: String) {
+        diagnostics.push(Diagnostic { severity, node_id, message });
+    }
+    
+    fn display_name(graph: &SimpleNodeGraph, node_id: NodeId) -> String {
+        graph.get_node_data(node_id)
+            .map(|node| Self::param_or_title(node, "name"))
+            .unwrap_or_default()
+    }
+    
+    fn param_text(node: &Node, id: &str) -> String {
+        node.find_param(id).map(|p| p.get_text()).unwrap_or_default()
+    }
+    
+    fn param_or_title(node: &Node, id: &str) -> String {
+        let text = Self::param_text(node, id);
+        if text.is_empty() { node.get_title().to_string() } else { text }
+    }
+}
@@ -0,0 +1,348 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::callback_data::{CallbackDataGenerator, DOCUMENTS_PER_PAGE};
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Node, NodeId, NodeType};
+use crate::i18n::Language;
+use super::{Diagnostic, GraphValidator, Severity};
+
+/// What the bot shows when a menu item is opened
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Screen {
+    Submenu,
+    Documents,
+    Url,
+    TextContent,
+    Description,
+    Data,
+}
+
+impl Screen {
+    fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            Screen::Submenu => "runtime.screen.submenu",
+            Screen::Documents => "runtime.screen.documents",
+            Screen::Url => "runtime.screen.url",
+            Screen::TextContent => "runtime.screen.text_content",
+            Screen::Description => "runtime.screen.description",
+            Screen::Data => "runtime.screen.data",
+        })
+    }
+}
+
+/// How the bot treats the items of one menu level
+#[derive(Clone, Debug, PartialEq)]
+pub struct LevelRules {
+    /// What opening an item shows, the first one the item has wins
+    pub screens: Vec<Screen>,
+    /// Items with a URL and no submenu or documents become link buttons
+    pub url_buttons: bool,
+}
+
+/// Navigation rules of a bot runtime reading bot_data.json
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuntimeProfile {
+    /// Message key of the name
+    pub name: &'static str,
+    /// Rules of each menu level, starting with the main menu. Deeper items get no button.
+    pub levels: Vec<LevelRules>,
+    /// Apply the rules of the last level to every deeper one
+    pub repeat_last_level: bool,
+    pub documents_per_page: usize,
+    /// Whether the buttons switching document pages work
+    pub document_pages: bool,
+    /// Whether images, links, messages and contacts are shown
+    pub content_nodes: bool,
+    /// Callbacks the bot handles itself before looking up menu items
+    pub reserved_prefixes: Vec<&'static str>,
+}
+
+impl RuntimeProfile {
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(self.name)
+    }
+    
+    /// The Python bot in `bot/`.
+    ///
+    /// Main menu items open a submenu, a link or data. Second level items also
+    /// open documents, text and descriptions. Third level items are buttons
+    /// that only work as links, deeper items are never shown. Page buttons of
+    /// the document list split the callback on `_` at the wrong index, so only
+    /// the first page can be seen.
+    pub fn python_bot() -> Self {
+        Self {
+            name: "runtime.profile.python_bot",
+            levels: vec![
+                LevelRules {
+                    screens: vec![Screen::Submenu, Screen::Url, Screen::Data],
+                    url_buttons: false,
+                },
+                LevelRules {
+                    screens: vec![
+                        Screen::Documents,
+                        Screen::Submenu,
+                        Screen::Url,
+                        Screen::TextContent,
+                        Screen::Description,
+                        Screen::Data,
+                    ],
+                    url_buttons: true,
+                },
+                LevelRules {
+                    screens: Vec::new(),
+                    url_buttons: true,
+                },
+            ],
+            repeat_last_level: false,
+            documents_per_page: DOCUMENTS_PER_PAGE,
+            document_pages: false,
+            content_nodes: false,
+            reserved_prefixes: vec!["faq_", "back_to_", "doc_page_", "pagination_info"],
+        }
+    }
+    
+    /// A runtime that shows everything the editor exports, at any depth
+    pub fn unrestricted() -> Self {
+        Self {
+            name: "runtime.profile.unrestricted",
+            levels: vec![LevelRules {
+                screens: vec![
+                    Screen::Submenu,
+                    Screen::Documents,
+                    Screen::Url,
+                    Screen::TextContent,
+                    Screen::Description,
+                    Screen::Data,
+                ],
+                url_buttons: false,
+            }],
+            repeat_last_level: true,
+            documents_per_page: DOCUMENTS_PER_PAGE,
+            document_pages: true,
+            content_nodes: true,
+            reserved_prefixes: Vec::new(),
+        }
+    }
+    
+    fn level(&self, depth: usize) -> Option<&LevelRules> {
+        self.levels.get(depth - 1).or_else(|| {
+            if self.repeat_last_level { self.levels.last() } else { None }
+        })
+    }
+}
+
+/// Result of checking a graph against a runtime profile
+#[derive(Clone, Debug, Default)]
+pub struct RuntimeReport {
+    /// Unreachable and shadowed nodes
+    pub diagnostics: Vec<Diagnostic>,
+    /// Clicks from /start to every reachable node. Content counts as
+    /// reached with its menu item, FAQ is opened with /faq and not included.
+    pub depths: HashMap<NodeId, usize>,
+}
+
+impl RuntimeReport {
+    /// Largest click depth of the graph
+    pub fn max_depth(&self) -> usize {
+        self.depths.values().copied().max().unwrap_or(0)
+    }
+}
+
+/// Walks the graph in export order the way the bot navigates the exported data
+pub struct RuntimeAnalyzer<'a> {
+    graph: &'a SimpleNodeGraph,
+    profile: &'a RuntimeProfile,
+    callbacks: HashMap<NodeId, String>,
+    visited: HashSet<NodeId>,
+    /// Menu items opened through a callback, in the order the bot looks them up
+    opened: Vec<(usize, NodeId)>,
+    report: RuntimeReport,
+}
+
+impl<'a> RuntimeAnalyzer<'a> {
+    /// Find nodes users cannot reach under `profile` and the click depth of the others
+    pub fn analyze(graph: &'a SimpleNodeGraph, profile: &'a RuntimeProfile) -> RuntimeReport {
+        let mut analyzer = Self {
+            graph,
+            profile,
+            callbacks: CallbackDataGenerator::assign(graph),
+            visited: HashSet::new(),
+            opened: Vec::new(),
+            report: RuntimeReport::default(),
+        };
+        
+        for root_id in graph.get_root_menu_nodes() {
+            analyzer.visit(root_id, 1, None);
+        }
+        analyzer.check_shadowing();
+        
+        analyzer.report.diagnostics.sort_by_key(|d| d.severity);
+        analyzer.report
+    }
+    
+    /// Visit a menu item whose button sits on level `depth`, or that cannot be
+    /// reached for the given reason
+    fn visit(&mut self, node_id: NodeId, depth: usize, blocked: Option<String>) {
+        // Cycles are reported by the validator
+        if !self.visited.insert(node_id) {
+            return;
+        }
+        let Some(node) = self.graph.get_node_data(node_id) else {
+            return;
+        };
+        
+        let children = self.graph.get_child_menu_nodes(node_id);
+        let documents = self.exported_documents(node_id);
+        let content = self.graph.get_content_for_node(node_id);
+        
+        let profile = self.profile;
+        let language = self.graph.language();
+        let rules = match (blocked, profile.level(depth)) {
+            (None, Some(rules)) => rules,
+            (blocked, _) => {
+                let reason = blocked.unwrap_or_else(|| language.tr_args(
+                    "runtime.levels_shown",
+                    &[("profile", &profile.label(language)), ("levels", &profile.levels.len())],
+                ));
+                self.warn(node_id, language.tr_args("runtime.unreachable_item", &[("reason", &reason)]));
+                for child_id in children {
+                    self.visit(child_id, depth + 1, Some(reason.clone()));
+                }
+                for doc_id in documents {
+                    self.warn(doc_id, language.tr_args("runtime.unreachable_document", &[("reason", &reason)]));
+                }
+                for content_id in content {
+                    self.warn(content_id, language.tr_args("runtime.unreachable_content", &[("reason", &reason)]));
+                }
+                return;
+            },
+        };
+        
+        self.report.depths.insert(node_id, depth);
+        let has_url = Self::has_param(node, "url");
+        
+        let screen = if rules.url_buttons && has_url && children.is_empty() && documents.is_empty() {
+            // Opens the link straight from the button
+            Some(Screen::Url)
+        } else {
+            let screen = rules.screens.iter().copied().find(|screen| self.shows(node, *screen, &children, &documents));
+            match screen {
+                Some(_) => self.opened.push((depth, node_id)),
+                None => self.warn(node_id, language.tr_args(
+                    "runtime.under_construction",
+                    &[("depth", &depth), ("screens", &Self::labels(language, &rules.screens))],
+                )),
+            }
+            screen
+        };
+        
+        let title = GraphValidator::param_or_title(node, "name");
+        let other = |what: &'static str| match screen {
+            Some(screen) => language.tr_args(
+                "runtime.shows_instead",
+                &[("title", &title), ("screen", &screen.label(language)), ("instead", &language.tr(what))],
+            ),
+            None => language.tr_args("runtime.does_not_open", &[("title", &title)]),
+        };
+        
+        let children_blocked = (screen != Some(Screen::Submenu)).then(|| other("runtime.instead.submenu"));
+        for child_id in children {
+            self.visit(child_id, depth + 1, children_blocked.clone());
+        }
+        
+        for (index, doc_id) in documents.into_iter().enumerate() {
+            let page = index / self.profile.documents_per_page.max(1);
+            if screen != Some(Screen::Documents) {
+                self.warn(doc_id, language.tr_args("runtime.unreachable_document", &[("reason", &other("runtime.instead.documents"))]));
+            } else if page > 0 && !self.profile.document_pages {
+                self.warn(doc_id, language.tr_args("runtime.unreachable_page", &[("page", &(page + 1))]));
+            } else {
+                self.report.depths.insert(doc_id, depth + 1 + page);
+            }
+        }
+        
+        for content_id in content {
+            if !self.profile.content_nodes {
+                self.warn(content_id, language.tr_args("runtime.no_content_nodes", &[("profile", &self.profile.label(language))]));
+            } else if screen.is_none() {
+                self.warn(content_id, language.tr_args("runtime.unreachable_content", &[("reason", &other("runtime.instead.content"))]));
+            } else {
+                self.report.depths.insert(content_id, depth);
+            }
+        }
+    }
+    
+    /// The bot answers a callback with the first item that has it, and handles
+    /// reserved prefixes before looking anything up
+    fn check_shadowing(&mut self) {
+        let mut opened = std::mem::take(&mut self.opened);
+        // Main menu items are checked first, then each deeper level
+        opened.sort_by_key(|(depth, _)| *depth);
+        
+        let language = self.graph.language();
+        let mut owners: HashMap<String, NodeId> = HashMap::new();
+        for (_, node_id) in opened {
+            let Some(callback_data) = self.callbacks.get(&node_id).cloned() else {
+                continue;
+            };
+            
+            if let Some(prefix) = self.profile.reserved_prefixes.iter().find(|p| callback_data.starts_with(*p)) {
+                self.warn(node_id, language.tr_args(
+                    "runtime.reserved_prefix",
+                    &[("callback_data", &callback_data), ("prefix", prefix)],
+                ));
+                continue;
+            }
+            
+            match owners.get(&callback_data) {
+                Some(owner) => {
+                    let owner_title = self.graph.get_node_data(*owner)
+                        .map(|node| GraphValidator::param_or_title(node, "name"))
+                        .unwrap_or_default();
+                    self.warn(node_id, language.tr_args(
+                        "runtime.shadowed",
+                        &[("callback_data", &callback_data), ("owner", &owner_title)],
+                    ));
+                },
+                None => {
+                    owners.insert(callback_data, node_id);
+                },
+            }
+        }
+    }
+    
+    /// Whether the item has what `screen` shows
+    fn shows(&self, node: &Node, screen: Screen, children: &[NodeId], documents: &[NodeId]) -> bool {
+        match screen {
+            Screen::Submenu => !children.is_empty(),
+            Screen::Documents => !documents.is_empty(),
+            Screen::Url => Self::has_param(node, "url"),
+            Screen::TextContent => Self::has_param(node, "text_content"),
+            Screen::Description => Self::has_param(node, "description"),
+            // The exporter drops data that is not valid JSON
+            Screen::Data => serde_json::from_str::<serde_json::Value>(&GraphValidator::param_text(node, "data")).is_ok(),
+        }
+    }
+    
+    /// Documents the exporter keeps, those without a URL are dropped
+    fn exported_documents(&self, node_id: NodeId) -> Vec<NodeId> {
+        self.graph.get_documents_for_node(node_id).into_iter()
+            .filter(|id| self.graph.get_node_data(*id).is_some_and(|doc| doc.node_type == NodeType::Document && Self::has_param(doc, "url")))
+            .collect()
+    }
+    
+    fn has_param(node: &Node, id: &str) -> bool {
+        !GraphValidator::param_text(node, id).is_empty()
+    }
+    
+    fn labels(language: Language, screens: &[Screen]) -> String {
+        if screens.is_empty() {
+            return language.tr("runtime.screens.links_only").to_string();
+        }
+        screens.iter().map(|s| s.label(language)).collect::<Vec<_>>().join(", ")
+    }
+    
+    fn warn(&mut self, node_id: NodeId, message: String) {
+        GraphValidator::push(&mut self.report.diagnostics, Severity::Warning, node_id, message);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::callback_data::{self, DOCUMENTS_PER_PAGE};
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Node, NodeType};
+use crate::i18n::Language;
+use super::{Diagnostic, GraphValidator, Severity};
+
+/// callback_data of an inline button, in bytes
+pub const CALLBACK_DATA_MAX_BYTES: usize = 64;
+/// Text of a message, in UTF-16 code units after entity parsing
+pub const MESSAGE_MAX_LENGTH: usize = 4096;
+/// Caption of a photo, in UTF-16 code units
+pub const CAPTION_MAX_LENGTH: usize = 1024;
+/// Buttons in one inline keyboard
+pub const KEYBOARD_MAX_BUTTONS: usize = 100;
+
+/// Length of a text the way Telegram counts it
+pub fn text_length(text: &str) -> usize {
+    text.encode_utf16().count()
+}
+
+/// Checks what the bot sends against the Telegram Bot API limits.
+///
+/// Telegram rejects the whole request when a limit is exceeded, so every
+/// finding is an error and reports the measured size. Pinned callback_data
+/// the bot handles itself is an error too, the item could never be opened.
+pub(super) fn check(graph: &SimpleNodeGraph, diagnostics: &mut Vec<Diagnostic>) {
+    let language = graph.language();
+    for node in graph.nodes() {
+        match node.node_type {
+            NodeType::MenuItem => {
+                // Documents without a URL are not exported and get no page
+                let documents = graph.get_documents_for_node(node.id()).into_iter()
+                    .filter(|id| graph.get_node_data(*id).is_some_and(|doc| !GraphValidator::param_text(doc, "url").is_empty()))
+                    .count();
+                check_callback_data(language, node, documents, diagnostics);
+                
+                // The bot sends the item text followed by its description or text content
+                let name = GraphValidator::param_or_title(node, "name");
+                for (param, label) in [("description", "telegram.description"), ("text_content", "telegram.text_content")] {
+                    let text = GraphValidator::param_text(node, param);
+                    if !text.is_empty() {
+                        check_message(language, node, label, &format!("{}\n\n{}", name, text), diagnostics);
+                    }
+                }
+                
+                // Child items and link buttons, plus the back button
+                let links = graph.get_content_for_node(node.id()).into_iter()
+                    .filter(|id| graph.get_node_data(*id).is_some_and(|n| n.node_type == NodeType::Link))
+                    .count();
+                let buttons = graph.get_child_menu_nodes(node.id()).len() + links + 1;
+                check_keyboard(language, node, "telegram.submenu", buttons, diagnostics);
+            },
+            NodeType::Document => check_callback_data(language, node, 0, diagnostics),
+            NodeType::FaqItem => {
+                let text = format!(
+                    "❓ {}\n\n{}",
+                    GraphValidator::param_or_title(node, "question"),
+                    GraphValidator::param_text(node, "answer")
+                );
+                check_message(language, node, "telegram.faq_answer", &text, diagnostics);
+            },
+            NodeType::TextContent => {
+                check_message(language, node, "telegram.text_message", &GraphValidator::param_text(node, "text"), diagnostics);
+            },
+            NodeType::Image => {
+                let length = text_length(&GraphValidator::param_text(node, "caption"));
+                if length > CAPTION_MAX_LENGTH {
+                    GraphValidator::push(diagnostics, Severity::Error, node.id(), language.tr_args(
+                        "telegram.caption_too_long",
+                        &[("length", &length), ("max", &CAPTION_MAX_LENGTH)],
+                    ));
+                }
+            },
+            _ => {},
+        }
+    }
+    
+    // Keyboards that are not attached to a single menu item
+    let roots = graph.get_root_menu_nodes();
+    if let Some(first) = roots.first().and_then(|id| graph.get_node_data(*id)) {
+        check_keyboard(language, first, "telegram.main_menu", roots.len(), diagnostics);
+    }
+    
+    let faq = graph.get_faq_nodes();
+    if let Some(first) = faq.first().and_then(|id| graph.get_node_data(*id)) {
+        // Every question plus the button back to the main menu
+        check_keyboard(language, first, "telegram.faq_list", faq.len() + 1, diagnostics);
+    }
+}
+
+/// Pinned callback_data of an item with `documents`. Items with documents
+/// are measured with the page wrapper of the last page, generated values
+/// always leave room for it.
+fn check_callback_data(language: Language, node: &Node, documents: usize, diagnostics: &mut Vec<Diagnostic>) {
+    let pinned = GraphValidator::param_text(node, "callback_data");
+    if pinned.is_empty() {
+        return;
+    }
+    
+    if callback_data::is_reserved(&pinned) {
+        GraphValidator::push(diagnostics, Severity::Error, node.id(), language.tr_args(
+            "telegram.callback_data_reserved",
+            &[("callback_data", &pinned)],
+        ));
+    }
+    
+    let (key, bytes) = match documents.div_ceil(DOCUMENTS_PER_PAGE) {
+        0 => ("telegram.callback_data_too_long", pinned.len()),
+        pages => ("telegram.page_callback_data_too_long", callback_data::document_page(&pinned, pages).len()),
+    };
+    if bytes > CALLBACK_DATA_MAX_BYTES {
+        GraphValidator::push(diagnostics, Severity::Error, node.id(), language.tr_args(
+            key,
+            &[("bytes", &bytes), ("max", &CALLBACK_DATA_MAX_BYTES)],
+        ));
+    }
+}
+
+/// `label` is the message key naming what is sent
+fn check_message(language: Language, node: &Node, label: &'static str, text: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let length = text_length(text);
+    if length > MESSAGE_MAX_LENGTH {
+        GraphValidator::push(diagnostics, Severity::Error, node.id(), language.tr_args(
+            "telegram.message_too_long",
+            &[("label", &language.tr(label)), ("length", &length), ("max", &MESSAGE_MAX_LENGTH)],
+        ));
+    }
+}
+
+/// `label` is the message key naming the keyboard
+fn check_keyboard(language: Language, node: &Node, label: &'static str, buttons: usize, diagnostics: &mut Vec<Diagnostic>) {
+    if buttons > KEYBOARD_MAX_BUTTONS {
+        GraphValidator::push(diagnostics, Severity::Error, node.id(), language.tr_args(
+            "telegram.too_many_buttons",
+            &[("label", &language.tr(label)), ("buttons", &buttons), ("max", &KEYBOARD_MAX_BUTTONS)],
+        ));
+    }
+}
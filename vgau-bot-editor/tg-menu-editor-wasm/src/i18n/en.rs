@@ -0,0 +1,479 @@
+//! English texts
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.file", "File"),
+    ("menu.file.new", "New"),
+    ("menu.file.open", "Open project..."),
+    ("menu.file.save", "Save project..."),
+    ("menu.file.export", "Export"),
+    ("menu.file.import", "Import"),
+    ("menu.file.save_image", "Save as image"),
+    ("menu.file.image_all", "Whole graph ({format})"),
+    ("menu.file.image_selection", "Selected nodes ({format})"),
+    ("menu.file.import_bot_data", "Import VGAU Telegram bot data"),
+    ("menu.edit", "Edit"),
+    ("menu.edit.undo", "Undo"),
+    ("menu.edit.undo_shortcut", "Undo (Ctrl+Z)"),
+    ("menu.edit.redo", "Redo"),
+    ("menu.edit.redo_shortcut", "Redo (Ctrl+Y)"),
+    ("menu.edit.find", "Find... (Ctrl+F)"),
+    ("menu.edit.copy_node", "Copy node"),
+    ("menu.edit.paste_node", "Paste node"),
+    ("menu.edit.delete_node", "Delete selected node"),
+    ("menu.export", "Export"),
+    ("menu.export.json", "Generate JSON"),
+    ("menu.export.clipboard", "Copy to clipboard"),
+    ("menu.view", "View"),
+    ("menu.view.editor", "Editor"),
+    ("menu.view.export", "Export/Import"),
+    ("menu.view.settings", "Settings"),
+    ("menu.view.diagnostics", "Check graph"),
+    ("menu.view.inspector", "Properties"),
+    ("menu.view.outline", "Outline"),
+    ("menu.view.reset_zoom", "Reset zoom"),
+    ("menu.view.fit_all", "Center graph (Home)"),
+    ("menu.view.fit_selection", "Show selected (F)"),
+    ("menu.view.minimap", "Minimap"),
+    ("menu.nodes", "Nodes"),
+    ("menu.nodes.layout_selected", "Arrange selected"),
+    ("menu.nodes.align", "Alignment"),
+    ("menu.help", "Help"),
+    ("menu.help.about", "About the editor"),
+    ("menu.help.docs", "Documentation"),
+    
+    // Status bar messages
+    ("status.project_created", "New project created"),
+    ("status.bot_data_imported", "Telegram bot data imported"),
+    ("status.import_error", "Import error: {error}"),
+    ("status.undone", "Last action undone"),
+    ("status.redone", "Action redone"),
+    ("status.no_node_to_delete", "No node selected to delete"),
+    ("status.zoom_reset", "Zoom reset"),
+    ("status.graph_centered", "Graph centered"),
+    ("status.graph_empty", "The graph is empty"),
+    ("status.no_nodes_selected", "No nodes selected"),
+    ("status.settings_reset", "Settings reset"),
+    ("status.cache_cleared", "Window and panel positions reset"),
+    ("status.zoom_out_unavailable", "Zooming out is not implemented yet"),
+    ("status.zoom_reset_unavailable", "Resetting the zoom is not implemented yet"),
+    ("status.zoom_in_unavailable", "Zooming in is not implemented yet"),
+    ("status.copy_unavailable", "Copying nodes is not implemented yet"),
+    ("status.paste_unavailable", "Pasting nodes is not implemented yet"),
+    ("status.delete_unavailable", "Deleting nodes is not implemented yet"),
+    ("status.clipboard_unavailable", "Copying to the clipboard is not implemented yet"),
+    ("status.docs_unavailable", "Opening the documentation is not implemented yet"),
+    
+    // Editor tab
+    ("editor.zoom", "Zoom:"),
+    ("editor.copy", "📋 Copy"),
+    ("editor.paste", "📋 Paste"),
+    ("editor.delete", "🗑️ Delete"),
+    
+    // Export tab
+    ("export.title", "Data export / import"),
+    ("export.metadata", "Project metadata"),
+    ("export.author", "Author:"),
+    ("export.description", "Description:"),
+    ("export.tags", "Tags:"),
+    ("export.created", "Created:"),
+    ("export.modified", "Modified:"),
+    ("export.data", "Exported data:"),
+    ("export.refresh", "Refresh export"),
+    ("export.import_title", "Data import"),
+    ("export.import_hint", "Paste JSON data to import..."),
+    ("export.errors_found", "Errors found: {count}"),
+    ("export.import_mode", "Import mode:"),
+    ("export.import", "Import"),
+    ("export.clear", "Clear"),
+    ("import_mode.replace", "Replace the graph"),
+    ("import_mode.append", "Add to the graph"),
+    ("import_mode.merge", "Merge by callback_data"),
+    
+    // Settings tab
+    ("settings.title", "Settings"),
+    ("settings.appearance", "Appearance"),
+    ("settings.language", "Language:"),
+    ("settings.theme", "Theme:"),
+    ("settings.font_size", "Font size:"),
+    ("settings.graph", "Graph"),
+    ("settings.show_grid", "Show grid"),
+    ("settings.snap_to_grid", "Snap to grid"),
+    ("settings.snap_to_grid_hint", "Hold Alt while dragging to move nodes without snapping"),
+    ("settings.connection_style", "Connection style:"),
+    ("settings.history_depth", "Undo steps:"),
+    ("settings.advanced", "Advanced"),
+    ("settings.reset", "Reset all settings"),
+    ("settings.clear_cache", "Clear cache"),
+    ("theme.dark", "Dark"),
+    ("theme.light", "Light"),
+    ("theme.high_contrast", "High contrast"),
+    ("connection_style.bezier", "Bézier curves"),
+    ("connection_style.straight", "Straight lines"),
+    
+    // Help tab
+    ("help.about", "About"),
+    ("help.version", "VGAU Bot Editor v1.0"),
+    ("help.description", "Menu and FAQ structure editor for the Telegram bot of the Upper Volga State Agrarian University"),
+    ("help.features", "Features"),
+    ("help.feature.nodes", "• Intuitive node-based editor with interactive elements"),
+    ("help.feature.drag_and_drop", "• Drag-and-drop interface for creating and connecting components"),
+    ("help.feature.menu", "• Visual editing of the Telegram bot menu structure"),
+    ("help.feature.faq", "• Questions and answers (FAQ) managed as nodes"),
+    ("help.feature.json", "• Data export and import in JSON format"),
+    ("help.feature.menus", "• Context menus and a toolbar for quick access to functions"),
+    ("help.feature.zoom", "• Zooming and panning of the workspace"),
+    ("help.feature.parameters", "• Detailed bot settings through node properties"),
+    ("help.feature.history", "• Change history with undo and redo"),
+    ("help.shortcuts", "Keyboard shortcuts"),
+    ("help.shortcut.undo", "• Ctrl+Z — Undo the last action"),
+    ("help.shortcut.redo", "• Ctrl+Y or Ctrl+Shift+Z — Redo the undone action"),
+    ("help.shortcut.delete", "• Delete — Delete the selected node"),
+    ("help.shortcut.zoom", "• Ctrl+mouse wheel — Zoom"),
+    ("help.shortcut.move", "• Drag with the left button — Move nodes"),
+    ("help.shortcut.pan", "• Drag with the middle button/Alt — Pan the workspace"),
+    ("help.close", "Close"),
+    
+    // Canvas context menus
+    ("canvas.add_menu_item", "Add menu item"),
+    ("canvas.add_faq", "Add FAQ"),
+    ("canvas.add_image", "Add image"),
+    ("canvas.add_link", "Add link"),
+    ("canvas.add_text", "Add text"),
+    ("canvas.add_contacts", "Add contacts"),
+    ("canvas.pin_all_callback_data", "Pin all callback_data"),
+    ("canvas.auto_layout", "Arrange nodes"),
+    ("canvas.paste", "Paste"),
+    ("canvas.delete_selected", "Delete selected ({count})"),
+    ("canvas.duplicate_selected", "Duplicate selected ({count})"),
+    ("canvas.align", "Align"),
+    ("canvas.distribute_vertically", "Distribute vertically"),
+    ("canvas.delete_node", "Delete node"),
+    ("canvas.copy", "Copy"),
+    ("canvas.copy_hint", "Copy the item with its submenus and documents"),
+    ("canvas.paste_as_child", "Paste as subitem"),
+    ("canvas.edit_parameters", "Edit parameters"),
+    ("canvas.pin_callback_data", "Pin callback_data"),
+    ("canvas.pin_callback_data_hint", "Keep the generated value so it no longer follows the title"),
+    ("canvas.regenerate_callback_data", "Generate callback_data"),
+    ("canvas.regenerate_callback_data_hint", "Replace callback_data with a value made from the current title"),
+    ("canvas.delete_connection", "Delete connection"),
+    ("canvas.retarget_connection", "Reconnect to"),
+    ("canvas.move_up", "Move up"),
+    ("canvas.move_down", "Move down"),
+    ("align.left", "Left edges"),
+    ("align.center", "Centers"),
+    ("align.top", "Top edges"),
+    
+    // Outline panel
+    ("outline.title", "Outline"),
+    ("outline.menu", "Menu"),
+    ("outline.faq", "FAQ"),
+    ("outline.empty", "No items yet"),
+    ("outline.close", "Close"),
+    ("outline.expand", "Expand"),
+    ("outline.collapse", "Collapse"),
+    ("outline.drag", "Drag onto another item to move it"),
+    ("outline.rename", "Rename (F2)"),
+    ("outline.keys", "↑↓ select, ←→ collapse and expand, F2 rename, Enter show on canvas, Alt+↑↓ order, Alt+←→ level"),
+    
+    // Titles of nodes added from the menus
+    ("node.new_menu_item", "New menu item"),
+    ("node.new_faq", "New FAQ"),
+    ("node.image", "Image"),
+    ("node.link", "Link"),
+    ("node.text", "Text"),
+    ("node.contacts", "Contacts"),
+    
+    // Graph validation
+    ("validation.severity.error", "Error"),
+    ("validation.severity.warning", "Warning"),
+    ("validation.severity.info", "Info"),
+    ("validation.empty_menu_item", "Menu item without a name"),
+    ("validation.empty_document", "Document without a name"),
+    ("validation.empty_question", "FAQ item without a question"),
+    ("validation.duplicate_callback_data", "callback_data \"{callback_data}\" is used by {count} nodes"),
+    ("validation.submenu_cycle", "Submenu cycle: {path}"),
+    ("validation.orphan_menu_item", "The item has no parent and will be exported to the main menu"),
+    ("validation.orphan_document", "The document is not connected to a menu item and will not be exported"),
+    ("validation.orphan_content", "The content is not connected to a menu item and will not be exported"),
+    ("validation.several_parents", "The node has {count} parents and will be exported {count} times"),
+    ("validation.document_without_url", "A document without a URL will not be exported"),
+    ("validation.question_without_answer", "An FAQ item without an answer will not be exported"),
+    ("validation.image_without_url", "An image without a URL will not be exported"),
+    ("validation.link_without_url", "A link without a URL will not be exported"),
+    ("validation.empty_text", "An empty text message will not be exported"),
+    ("validation.empty_contacts", "Empty contacts will not be exported"),
+    
+    // Telegram Bot API limits
+    ("telegram.description", "Description"),
+    ("telegram.text_content", "Text content"),
+    ("telegram.submenu", "Submenu"),
+    ("telegram.faq_answer", "FAQ answer"),
+    ("telegram.text_message", "Message"),
+    ("telegram.main_menu", "Main menu"),
+    ("telegram.faq_list", "FAQ list"),
+    ("telegram.caption_too_long", "The image caption takes {length} characters (at most {max})"),
+    ("telegram.callback_data_too_long", "callback_data takes {bytes} bytes (at most {max})"),
+    ("telegram.page_callback_data_too_long", "callback_data of the document page buttons takes {bytes} bytes (at most {max})"),
+    ("telegram.callback_data_reserved", "callback_data \"{callback_data}\" is handled by the bot itself, the item will not open"),
+    ("telegram.message_too_long", "{label}: the message takes {length} characters (at most {max})"),
+    ("telegram.too_many_buttons", "{label}: the keyboard has {buttons} buttons (at most {max})"),
+    
+    // Bot runtime reachability
+    ("runtime.screen.submenu", "a submenu"),
+    ("runtime.screen.documents", "documents"),
+    ("runtime.screen.url", "a link"),
+    ("runtime.screen.text_content", "text"),
+    ("runtime.screen.description", "a description"),
+    ("runtime.screen.data", "data"),
+    ("runtime.screens.links_only", "links"),
+    ("runtime.instead.submenu", "the submenu"),
+    ("runtime.instead.documents", "the documents"),
+    ("runtime.instead.content", "the content"),
+    ("runtime.profile.python_bot", "Python bot"),
+    ("runtime.profile.unrestricted", "Unrestricted"),
+    ("runtime.levels_shown", "\"{profile}\" shows only {levels} menu levels"),
+    ("runtime.unreachable_item", "The item cannot be reached: {reason}"),
+    ("runtime.unreachable_document", "The document cannot be reached: {reason}"),
+    ("runtime.unreachable_content", "The content cannot be reached: {reason}"),
+    ("runtime.unreachable_page", "The document on page {page} cannot be reached: switching pages does not work"),
+    ("runtime.under_construction", "The bot will answer \"Раздел в разработке\" (under construction): items on level {depth} only open {screens}"),
+    ("runtime.shows_instead", "\"{title}\" shows {screen} instead of {instead}"),
+    ("runtime.does_not_open", "\"{title}\" does not open"),
+    ("runtime.no_content_nodes", "\"{profile}\" does not show images, links, messages and contacts"),
+    ("runtime.reserved_prefix", "callback_data \"{callback_data}\" starts with \"{prefix}\" and is handled by the bot itself"),
+    ("runtime.shadowed", "The item is shadowed: for callback_data \"{callback_data}\" the bot opens \"{owner}\""),
+    
+    // Search palette
+    ("search.field.title", "Name"),
+    ("search.field.callback_data", "callback_data"),
+    ("search.field.url", "URL"),
+    ("search.field.description", "Description"),
+    ("search.field.answer", "Answer"),
+    ("search.field.tags", "Tags"),
+    ("search.title", "Find"),
+    ("search.hint", "Name, callback_data, URL, description, FAQ answer…"),
+    ("search.empty_query", "Type something to search for"),
+    ("search.nothing_found", "Nothing found"),
+    
+    // Node types, ports and parameters
+    ("node_type.menu_item", "Menu item"),
+    ("node_type.faq_item", "FAQ item"),
+    ("node_type.process", "Process"),
+    ("node_type.input", "Input"),
+    ("node_type.output", "Output"),
+    ("node_type.document", "Document"),
+    ("node_type.image", "Image"),
+    ("node_type.link", "Link"),
+    ("node_type.text_content", "Message"),
+    ("node_type.contacts", "Contacts"),
+    ("port.parent_menu", "Parent menu"),
+    ("port.sub_menu", "Submenu"),
+    ("port.documents", "Documents"),
+    ("port.content", "Content"),
+    ("param.name", "Name"),
+    ("param.description", "Description"),
+    ("param.text_content", "Text content"),
+    ("param.question", "Question"),
+    ("param.answer", "Answer"),
+    ("param.tags", "Tags"),
+    ("param.image_url", "Image URL"),
+    ("param.caption", "Caption"),
+    ("param.button_text", "Button text"),
+    ("param.message_text", "Message text"),
+    ("param.address", "Address"),
+    ("param.phone", "Phone"),
+    ("param.website", "Website"),
+    ("param.data", "JSON data"),
+    ("param.format_json", "Format"),
+    ("param.date_hint", "YYYY-MM-DD"),
+    ("param.add_item", "➕ Add"),
+    
+    // Inspector panel
+    ("inspector.title", "Properties"),
+    ("inspector.close", "Close"),
+    ("inspector.nothing_selected", "Select a node on the canvas"),
+    ("inspector.ports", "Ports"),
+    ("inspector.connections", "Connections"),
+    ("inspector.selected_nodes", "Nodes selected: {count}"),
+    ("inspector.mixed_types", "Nodes can be edited together when they are of one type"),
+    ("inspector.selected_of_type", "Selected: {count} × {node_type}"),
+    ("inspector.group_hint", "A value set here goes to all selected nodes"),
+    ("inspector.mixed_values", "The selected nodes have different values"),
+    ("inspector.no_connections", "No connections"),
+    ("inspector.go_to_node", "Go to the node"),
+    ("inspector.order", "Order"),
+    ("inspector.drag_to_reorder", "Drag to change the order"),
+    ("inspector.move_up", "Move up"),
+    ("inspector.move_down", "Move down"),
+    
+    // Status bar
+    ("status.unsaved_changes", "Unsaved changes"),
+    ("status.ready", "Ready"),
+    ("status.undo", "⟲ Undo"),
+    ("status.undo_shortcut", "⟲ Undo (Ctrl+Z)"),
+    ("status.redo", "⟳ Redo"),
+    ("status.redo_shortcut", "⟳ Redo (Ctrl+Y)"),
+    
+    // Diagnostics panel
+    ("diagnostics.title", "Graph check"),
+    ("diagnostics.counts", "Errors: {errors}  Warnings: {warnings}"),
+    ("diagnostics.info", "Info ({count})"),
+    ("diagnostics.no_profile", "No profile"),
+    ("diagnostics.profile_hint", "Check which nodes the bot can show to users"),
+    ("diagnostics.max_depth", "Max depth: {depth}"),
+    ("diagnostics.max_depth_hint", "Clicks from /start to the deepest node"),
+    ("diagnostics.close", "Close"),
+    ("diagnostics.dock_right", "Dock to the right"),
+    ("diagnostics.dock_bottom", "Dock to the bottom"),
+    ("diagnostics.no_problems", "No problems found"),
+    ("diagnostics.depth", " ({depth} clicks)"),
+    ("diagnostics.go_to_node", "Go to the node"),
+    
+    // Window title and the nodes of a new project
+    ("app.title", "VGAU bot menu editor"),
+    ("node.sample.main_menu", "Main menu"),
+    ("node.sample.submenu", "Submenu: About the university"),
+    ("node.sample.faq", "Frequently asked question"),
+    
+    // Results of file and edit commands
+    ("status.export_blocked", "Cannot export: the graph has {count} errors"),
+    ("status.exported", "Data exported"),
+    ("status.export_error", "Export error: {error}"),
+    ("status.export_failed", "The data could not be exported"),
+    ("status.project_saved", "Project saved"),
+    ("status.project_save_error", "The project could not be saved: {error}"),
+    ("status.project_in_export_tab", "The project is in the export tab"),
+    ("status.project_opened", "Project opened"),
+    ("status.project_open_error", "The project could not be opened: {error}"),
+    ("status.paste_project", "Paste the project into the import field"),
+    ("status.image_saved", "{format} image saved"),
+    ("status.image_downloaded", "{format} image downloaded"),
+    ("status.image_error", "The image could not be exported: {error}"),
+    ("status.imported", "Data imported"),
+    ("status.import_problems", "Import error: {count} problems found"),
+    ("status.merged", "Data merged"),
+    ("status.merge_error", "Merge error: {error}"),
+    ("status.merge_cancelled", "Merge cancelled"),
+    ("status.paste_error", "Paste error: {error}"),
+    ("status.nothing_to_undo", "Nothing to undo"),
+    ("status.nothing_to_redo", "Nothing to redo"),
+    
+    // Merge dialog and project files
+    ("merge.title", "Merge data"),
+    ("merge.updates", "Updates without conflicts: {count}"),
+    ("merge.new_items", "New items: {count}"),
+    ("merge.conflicts", "Conflicts: {count}"),
+    ("merge.field_changed", "Field \"{field}\" was changed on both sides"),
+    ("merge.local", "Local: {value}"),
+    ("merge.incoming", "In the file: {value}"),
+    ("merge.keep_local", "Keep local"),
+    ("merge.take_incoming", "Take from the file"),
+    ("merge.removed_upstream", "The node was removed in the imported file"),
+    ("merge.keep_node", "Keep the node"),
+    ("merge.remove_node", "Remove the node"),
+    ("merge.apply", "Apply"),
+    ("merge.cancel", "Cancel"),
+    ("project.file_filter", "VGAU menu project"),
+    ("project.default_description", "New VGAU bot menu"),
+    
+    // Legacy workflow editor, WorkflowEditorApp in lib.rs
+    ("workflow.title", "Telegram bot menu editor"),
+    ("workflow.add_element", "➕ Add element"),
+    ("workflow.help", "❓ Help"),
+    ("workflow.selected", "Selected: {count}"),
+    ("workflow.delete", "🗑 Delete"),
+    ("workflow.import_json", "📥 Import JSON"),
+    ("workflow.export_json", "📤 Export JSON"),
+    ("workflow.language", "Interface language"),
+    ("workflow.nodes", "Nodes: {count}"),
+    ("workflow.connections", "Connections: {count}"),
+    ("workflow.zoom", "Zoom: {percent}%"),
+    ("workflow.selection_hint", "Ctrl+click: multi-select   Shift+click: range   Del: delete"),
+    ("workflow.menu.title", "Add element"),
+    ("workflow.menu.show_help", "❓ Show help"),
+    ("workflow.menu.structure", "Menu structure"),
+    ("workflow.menu.main_menu", "🔝 Main menu"),
+    ("workflow.menu.main_menu_hint", "Starting point of the whole bot menu"),
+    ("workflow.menu.template", "Template"),
+    ("workflow.menu.submenu", "📂 Submenu"),
+    ("workflow.menu.submenu_hint", "A group of related buttons"),
+    ("workflow.menu.button", "🔘 Button"),
+    ("workflow.menu.button_hint", "An element to interact with"),
+    ("workflow.menu.content", "Content"),
+    ("workflow.menu.text", "📝 Text"),
+    ("workflow.menu.text_hint", "A text message from the bot"),
+    ("workflow.menu.image", "🖼️ Image"),
+    ("workflow.menu.image_hint", "An image sent by the bot"),
+    ("workflow.menu.document", "📄 Document"),
+    ("workflow.menu.document_hint", "A document to download"),
+    ("workflow.menu.link", "🔗 Link"),
+    ("workflow.menu.link_hint", "A link to a web page"),
+    ("workflow.menu.special", "Special elements"),
+    ("workflow.menu.faq", "❓ FAQ"),
+    ("workflow.menu.faq_hint", "A frequently asked question with its answer"),
+    ("workflow.menu.contacts", "📞 Contacts"),
+    ("workflow.menu.contacts_hint", "Contact information"),
+    ("workflow.help.title", "Editor help"),
+    ("workflow.help.heading", "How to use the menu editor:"),
+    ("workflow.help.step1", "1. Start by adding a 'Main menu' element"),
+    ("workflow.help.step2", "2. Add submenus and buttons and connect them to each other"),
+    ("workflow.help.step3", "3. Add content (text, images and more) and connect it to buttons"),
+    ("workflow.help.elements", "Bot menu elements"),
+    ("workflow.help.main_menu", "🔝 Main menu - the starting point of the whole bot"),
+    ("workflow.help.submenu", "📂 Submenu - groups related buttons"),
+    ("workflow.help.button", "🔘 Button - an element the user interacts with"),
+    ("workflow.help.content", "Content for buttons"),
+    ("workflow.help.text", "📝 Text - a text message from the bot"),
+    ("workflow.help.image", "🖼️ Image - a picture sent by the bot"),
+    ("workflow.help.document", "📄 Document - a file to download"),
+    ("workflow.help.link", "🔗 Link - opens a web page"),
+    ("workflow.help.faq", "❓ FAQ - a frequently asked question with its answer"),
+    ("workflow.help.contacts", "📞 Contacts - contact information"),
+    ("workflow.help.shortcuts", "Keyboard shortcuts"),
+    ("workflow.help.shortcut.main_menu", "Shift+M - add a main menu"),
+    ("workflow.help.shortcut.submenu", "Shift+S - add a submenu"),
+    ("workflow.help.shortcut.button", "Shift+B - add a button"),
+    ("workflow.help.shortcut.text", "Shift+T - add text"),
+    ("workflow.help.shortcut.menu", "Ctrl+Space - open the add element menu"),
+    ("workflow.help.close", "Close help"),
+    ("workflow.description.main_menu", "Main bot menu - the starting point for all buttons"),
+    ("workflow.description.submenu", "A submenu holds a set of related buttons"),
+    ("workflow.description.button", "A button the user interacts with the bot through"),
+    ("workflow.description.text", "A text message sent by the bot"),
+    ("workflow.description.image", "An image sent by the bot"),
+    ("workflow.description.document", "A document or file to download"),
+    ("workflow.description.link", "A link to a web page"),
+    ("workflow.description.faq", "A frequently asked question with its answer"),
+    ("workflow.description.contacts", "Contact information"),
+    ("workflow.slot.items", "Items"),
+    ("workflow.slot.action", "Action"),
+    ("workflow.slot.question", "Question"),
+    ("workflow.slot.answer", "Answer"),
+    ("workflow.slot.output", "Output"),
+    ("workflow.slot.parent", "Parent"),
+    ("workflow.slot.input", "Input"),
+    ("workflow.node.main_menu", "Main menu"),
+    ("workflow.node.submenu", "Submenu"),
+    ("workflow.node.button", "Button"),
+    ("workflow.node.text", "Text"),
+    ("workflow.node.image", "Image"),
+    ("workflow.node.document", "Document"),
+    ("workflow.node.link", "Link"),
+    ("workflow.node.faq", "FAQ"),
+    ("workflow.node.contacts", "Contacts"),
+    ("workflow.node.condition", "Condition"),
+    ("workflow.node.action", "Action"),
+    ("workflow.node.custom", "Custom"),
+    ("workflow.node.text_content", "Text content..."),
+    ("workflow.node.question", "Frequently asked question?"),
+    ("workflow.node.answer", "Answer to the question..."),
+    ("workflow.node.address", "Organization address"),
+    ("workflow.template.main_menu", "Main bot menu"),
+    ("workflow.template.menu_title", "Bot menu"),
+    ("workflow.template.submenu", "Menu section"),
+    ("workflow.template.button", "Button"),
+    ("workflow.template.text", "Text message"),
+    ("workflow.template.text_content", "Sample text message from the bot"),
+];
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+mod en;
+mod ru;
+
+/// Texts of one language as (key, text) pairs
+pub type Catalog = &'static [(&'static str, &'static str)];
+
+/// Language of the interface.
+///
+/// Interface texts are looked up by keys such as `menu.file.open`. Russian is
+/// the language the editor is written in: a text missing from another catalog
+/// is shown in Russian, a key missing everywhere is shown as is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    Russian,
+    English,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Russian, Language::English];
+    
+    /// Name of the language in the language itself, so it can be found
+    /// whatever language is active
+    pub fn label(&self) -> &'static str {
+        match self {
+            Language::Russian => "Русский",
+            Language::English => "English",
+        }
+    }
+    
+    pub fn catalog(&self) -> Catalog {
+        match self {
+            Language::Russian => ru::MESSAGES,
+            Language::English => en::MESSAGES,
+        }
+    }
+    
+    /// Text for `key` in this language
+    pub fn tr(&self, key: &'static str) -> &'static str {
+        translate(self.catalog(), key)
+    }
+    
+    /// Text for `key` with every `{name}` replaced by the value given for `name`
+    pub fn tr_args(&self, key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+        args.iter().fold(self.tr(key).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{}}}", name), &value.to_string())
+        })
+    }
+    
+    /// Text for a key that is not known at compile time, `None` when no catalog has it
+    pub fn get(&self, key: &str) -> Option<&'static str> {
+        lookup(self.catalog(), key).or_else(|| lookup(ru::MESSAGES, key))
+    }
+    
+    /// Keys of the Russian catalog this language has no text for
+    pub fn missing_keys(&self) -> Vec<&'static str> {
+        ru::MESSAGES.iter()
+            .map(|(key, _)| *key)
+            .filter(|key| lookup(self.catalog(), key).is_none())
+            .collect()
+    }
+    
+    /// Make this the language of everything drawn in `ctx`
+    pub fn apply(&self, ctx: &egui::Context) {
+        ctx.memory_mut(|mem| mem.data.insert_temp(egui::Id::new("editor_language"), *self));
+    }
+    
+    /// Language applied to `ctx` last, Russian before any was applied
+    pub fn current(ctx: &egui::Context) -> Self {
+        ctx.memory(|mem| mem.data.get_temp::<Self>(egui::Id::new("editor_language"))).unwrap_or_default()
+    }
+}
+
+/// Text for `key` in `catalog`, falling back to Russian and then to the key itself
+pub fn translate(catalog: Catalog, key: &'static str) -> &'static str {
+    lookup(catalog, key)
+        .or_else(|| lookup(ru::MESSAGES, key))
+        .unwrap_or(key)
+}
+
+fn lookup(catalog: Catalog, key: &str) -> Option<&'static str> {
+    catalog.iter().find(|(k, _)| *k == key).map(|(_, text)| *text)
+}
@@ -0,0 +1,479 @@
+//! Russian texts, the reference catalog every other language is checked against
+
+pub(super) const MESSAGES: &[(&str, &str)] = &[
+    // Menu bar
+    ("menu.file", "Файл"),
+    ("menu.file.new", "Новый"),
+    ("menu.file.open", "Открыть проект..."),
+    ("menu.file.save", "Сохранить проект..."),
+    ("menu.file.export", "Экспорт"),
+    ("menu.file.import", "Импорт"),
+    ("menu.file.save_image", "Сохранить как изображение"),
+    ("menu.file.image_all", "Весь граф ({format})"),
+    ("menu.file.image_selection", "Выделенные узлы ({format})"),
+    ("menu.file.import_bot_data", "Импорт данных Telegram-бота ВГАУ"),
+    ("menu.edit", "Правка"),
+    ("menu.edit.undo", "Отменить"),
+    ("menu.edit.undo_shortcut", "Отменить (Ctrl+Z)"),
+    ("menu.edit.redo", "Вернуть"),
+    ("menu.edit.redo_shortcut", "Вернуть (Ctrl+Y)"),
+    ("menu.edit.find", "Найти... (Ctrl+F)"),
+    ("menu.edit.copy_node", "Копировать узел"),
+    ("menu.edit.paste_node", "Вставить узел"),
+    ("menu.edit.delete_node", "Удалить выбранный узел"),
+    ("menu.export", "Экспорт"),
+    ("menu.export.json", "Сформировать JSON"),
+    ("menu.export.clipboard", "Скопировать в буфер обмена"),
+    ("menu.view", "Вид"),
+    ("menu.view.editor", "Редактор"),
+    ("menu.view.export", "Экспорт/Импорт"),
+    ("menu.view.settings", "Настройки"),
+    ("menu.view.diagnostics", "Проверка графа"),
+    ("menu.view.inspector", "Свойства"),
+    ("menu.view.outline", "Структура"),
+    ("menu.view.reset_zoom", "Сбросить масштаб"),
+    ("menu.view.fit_all", "Центрировать граф (Home)"),
+    ("menu.view.fit_selection", "Показать выбранные (F)"),
+    ("menu.view.minimap", "Мини-карта"),
+    ("menu.nodes", "Узлы"),
+    ("menu.nodes.layout_selected", "Организовать выбранные"),
+    ("menu.nodes.align", "Выравнивание"),
+    ("menu.help", "Помощь"),
+    ("menu.help.about", "О редакторе"),
+    ("menu.help.docs", "Документация"),
+    
+    // Status bar messages
+    ("status.project_created", "Создан новый проект"),
+    ("status.bot_data_imported", "Данные Telegram-бота успешно импортированы"),
+    ("status.import_error", "Ошибка импорта: {error}"),
+    ("status.undone", "Отменено последнее действие"),
+    ("status.redone", "Действие возвращено"),
+    ("status.no_node_to_delete", "Не выбран узел для удаления"),
+    ("status.zoom_reset", "Масштаб сброшен"),
+    ("status.graph_centered", "Граф центрирован"),
+    ("status.graph_empty", "Граф пуст"),
+    ("status.no_nodes_selected", "Не выбраны узлы"),
+    ("status.settings_reset", "Настройки сброшены"),
+    ("status.cache_cleared", "Положение окон и панелей сброшено"),
+    ("status.zoom_out_unavailable", "Уменьшение масштаба пока не реализовано"),
+    ("status.zoom_reset_unavailable", "Сброс масштаба пока не реализован"),
+    ("status.zoom_in_unavailable", "Увеличение масштаба пока не реализовано"),
+    ("status.copy_unavailable", "Копирование узла пока не реализовано"),
+    ("status.paste_unavailable", "Вставка узла пока не реализована"),
+    ("status.delete_unavailable", "Удаление узла пока не реализовано"),
+    ("status.clipboard_unavailable", "Копирование в буфер обмена пока не реализовано"),
+    ("status.docs_unavailable", "Открытие документации пока не реализовано"),
+    
+    // Editor tab
+    ("editor.zoom", "Масштаб:"),
+    ("editor.copy", "📋 Копировать"),
+    ("editor.paste", "📋 Вставить"),
+    ("editor.delete", "🗑️ Удалить"),
+    
+    // Export tab
+    ("export.title", "Экспорт / Импорт данных"),
+    ("export.metadata", "Метаданные проекта"),
+    ("export.author", "Автор:"),
+    ("export.description", "Описание:"),
+    ("export.tags", "Теги:"),
+    ("export.created", "Создан:"),
+    ("export.modified", "Изменен:"),
+    ("export.data", "Экспортированные данные:"),
+    ("export.refresh", "Обновить экспорт"),
+    ("export.import_title", "Импорт данных"),
+    ("export.import_hint", "Вставьте JSON данные для импорта..."),
+    ("export.errors_found", "Найдено ошибок: {count}"),
+    ("export.import_mode", "Режим импорта:"),
+    ("export.import", "Импортировать"),
+    ("export.clear", "Очистить"),
+    ("import_mode.replace", "Заменить граф"),
+    ("import_mode.append", "Добавить к графу"),
+    ("import_mode.merge", "Объединить по callback_data"),
+    
+    // Settings tab
+    ("settings.title", "Настройки"),
+    ("settings.appearance", "Внешний вид"),
+    ("settings.language", "Язык:"),
+    ("settings.theme", "Тема:"),
+    ("settings.font_size", "Размер шрифта:"),
+    ("settings.graph", "Граф"),
+    ("settings.show_grid", "Показывать сетку"),
+    ("settings.snap_to_grid", "Привязка к сетке"),
+    ("settings.snap_to_grid_hint", "Удерживайте Alt при перетаскивании, чтобы двигать узлы без привязки"),
+    ("settings.connection_style", "Стиль соединений:"),
+    ("settings.history_depth", "Шагов отмены:"),
+    ("settings.advanced", "Дополнительно"),
+    ("settings.reset", "Сбросить все настройки"),
+    ("settings.clear_cache", "Очистить кэш"),
+    ("theme.dark", "Темная"),
+    ("theme.light", "Светлая"),
+    ("theme.high_contrast", "Контрастная"),
+    ("connection_style.bezier", "Кривые Безье"),
+    ("connection_style.straight", "Прямые линии"),
+    
+    // Help tab
+    ("help.about", "О программе"),
+    ("help.version", "ВГАУ Бот Редактор v1.0"),
+    ("help.description", "Редактор структуры меню и FAQ для телеграм-бота Верхневолжского государственного аграрного университета"),
+    ("help.features", "Возможности"),
+    ("help.feature.nodes", "• Интуитивный редактор на основе узлов с интерактивными элементами"),
+    ("help.feature.drag_and_drop", "• Drag-and-drop интерфейс для создания и соединения компонентов"),
+    ("help.feature.menu", "• Визуальное редактирование структуры меню телеграм-бота"),
+    ("help.feature.faq", "• Управление вопросами и ответами (FAQ) через узловые компоненты"),
+    ("help.feature.json", "• Экспорт и импорт данных в формате JSON"),
+    ("help.feature.menus", "• Контекстные меню и панель инструментов для быстрого доступа к функциям"),
+    ("help.feature.zoom", "• Масштабирование и панорамирование рабочей области"),
+    ("help.feature.parameters", "• Подробная настройка параметров бота через свойства узлов"),
+    ("help.feature.history", "• История изменений с функциями отмены и повтора действий"),
+    ("help.shortcuts", "Горячие клавиши"),
+    ("help.shortcut.undo", "• Ctrl+Z — Отменить последнее действие"),
+    ("help.shortcut.redo", "• Ctrl+Y или Ctrl+Shift+Z — Повторить отмененное действие"),
+    ("help.shortcut.delete", "• Delete — Удалить выбранный узел"),
+    ("help.shortcut.zoom", "• Ctrl+колесо мыши — Изменить масштаб"),
+    ("help.shortcut.move", "• Перетаскивание с зажатым ЛКМ — Перемещение узлов"),
+    ("help.shortcut.pan", "• Перетаскивание с зажатым СКМ/Alt — Панорамирование рабочей области"),
+    ("help.close", "Закрыть"),
+    
+    // Canvas context menus
+    ("canvas.add_menu_item", "Добавить пункт меню"),
+    ("canvas.add_faq", "Добавить FAQ"),
+    ("canvas.add_image", "Добавить изображение"),
+    ("canvas.add_link", "Добавить ссылку"),
+    ("canvas.add_text", "Добавить текст"),
+    ("canvas.add_contacts", "Добавить контакты"),
+    ("canvas.pin_all_callback_data", "Закрепить все callback_data"),
+    ("canvas.auto_layout", "Организовать узлы"),
+    ("canvas.paste", "Вставить"),
+    ("canvas.delete_selected", "Удалить выбранные ({count})"),
+    ("canvas.duplicate_selected", "Дублировать выбранные ({count})"),
+    ("canvas.align", "Выровнять"),
+    ("canvas.distribute_vertically", "Распределить по вертикали"),
+    ("canvas.delete_node", "Удалить узел"),
+    ("canvas.copy", "Копировать"),
+    ("canvas.copy_hint", "Скопировать пункт вместе с подменю и документами"),
+    ("canvas.paste_as_child", "Вставить как подпункт"),
+    ("canvas.edit_parameters", "Редактировать параметры"),
+    ("canvas.pin_callback_data", "Закрепить callback_data"),
+    ("canvas.pin_callback_data_hint", "Сохранить сгенерированное значение, чтобы оно не менялось вместе с названием"),
+    ("canvas.regenerate_callback_data", "Сгенерировать callback_data"),
+    ("canvas.regenerate_callback_data_hint", "Заменить callback_data значением из текущего названия"),
+    ("canvas.delete_connection", "Удалить связь"),
+    ("canvas.retarget_connection", "Переподключить к"),
+    ("canvas.move_up", "Переместить выше"),
+    ("canvas.move_down", "Переместить ниже"),
+    ("align.left", "По левому краю"),
+    ("align.center", "По центру"),
+    ("align.top", "По верхнему краю"),
+    
+    // Outline panel
+    ("outline.title", "Структура"),
+    ("outline.menu", "Меню"),
+    ("outline.faq", "FAQ"),
+    ("outline.empty", "Пунктов пока нет"),
+    ("outline.close", "Закрыть"),
+    ("outline.expand", "Развернуть"),
+    ("outline.collapse", "Свернуть"),
+    ("outline.drag", "Перетащите на другой пункт, чтобы переместить"),
+    ("outline.rename", "Переименовать (F2)"),
+    ("outline.keys", "↑↓ — выбор, ←→ — свернуть и развернуть, F2 — переименовать, Enter — показать на холсте, Alt+↑↓ — порядок, Alt+←→ — уровень"),
+    
+    // Titles of nodes added from the menus
+    ("node.new_menu_item", "Новый пункт меню"),
+    ("node.new_faq", "Новый FAQ"),
+    ("node.image", "Изображение"),
+    ("node.link", "Ссылка"),
+    ("node.text", "Текст"),
+    ("node.contacts", "Контакты"),
+    
+    // Graph validation
+    ("validation.severity.error", "Ошибка"),
+    ("validation.severity.warning", "Предупреждение"),
+    ("validation.severity.info", "Информация"),
+    ("validation.empty_menu_item", "Пустое название пункта меню"),
+    ("validation.empty_document", "Пустое название документа"),
+    ("validation.empty_question", "Пустой вопрос FAQ"),
+    ("validation.duplicate_callback_data", "callback_data «{callback_data}» используется в {count} узлах"),
+    ("validation.submenu_cycle", "Цикл в подменю: {path}"),
+    ("validation.orphan_menu_item", "Пункт не подключён к родителю и будет экспортирован в главное меню"),
+    ("validation.orphan_document", "Документ не подключён ни к одному пункту меню и не попадёт в экспорт"),
+    ("validation.orphan_content", "Содержимое не подключено ни к одному пункту меню и не попадёт в экспорт"),
+    ("validation.several_parents", "Узел подключён к {count} родителям и будет экспортирован {count} раз"),
+    ("validation.document_without_url", "Документ без URL не попадёт в экспорт"),
+    ("validation.question_without_answer", "Вопрос FAQ без ответа не попадёт в экспорт"),
+    ("validation.image_without_url", "Изображение без URL не попадёт в экспорт"),
+    ("validation.link_without_url", "Ссылка без URL не попадёт в экспорт"),
+    ("validation.empty_text", "Пустое текстовое сообщение не попадёт в экспорт"),
+    ("validation.empty_contacts", "Пустые контакты не попадут в экспорт"),
+    
+    // Telegram Bot API limits
+    ("telegram.description", "Описание"),
+    ("telegram.text_content", "Текстовое содержимое"),
+    ("telegram.submenu", "Подменю"),
+    ("telegram.faq_answer", "Ответ FAQ"),
+    ("telegram.text_message", "Сообщение"),
+    ("telegram.main_menu", "Главное меню"),
+    ("telegram.faq_list", "Список FAQ"),
+    ("telegram.caption_too_long", "Подпись к изображению занимает {length} символов (максимум {max})"),
+    ("telegram.callback_data_too_long", "callback_data занимает {bytes} байт (максимум {max})"),
+    ("telegram.page_callback_data_too_long", "callback_data кнопок страниц документов занимает {bytes} байт (максимум {max})"),
+    ("telegram.callback_data_reserved", "callback_data «{callback_data}» обрабатывается самим ботом, пункт не откроется"),
+    ("telegram.message_too_long", "{label}: сообщение занимает {length} символов (максимум {max})"),
+    ("telegram.too_many_buttons", "{label}: клавиатура содержит {buttons} кнопок (максимум {max})"),
+    
+    // Bot runtime reachability
+    ("runtime.screen.submenu", "подменю"),
+    ("runtime.screen.documents", "документы"),
+    ("runtime.screen.url", "ссылку"),
+    ("runtime.screen.text_content", "текст"),
+    ("runtime.screen.description", "описание"),
+    ("runtime.screen.data", "данные"),
+    ("runtime.screens.links_only", "ссылки"),
+    ("runtime.instead.submenu", "подменю"),
+    ("runtime.instead.documents", "документов"),
+    ("runtime.instead.content", "содержимого"),
+    ("runtime.profile.python_bot", "Python-бот"),
+    ("runtime.profile.unrestricted", "Без ограничений"),
+    ("runtime.levels_shown", "«{profile}» показывает только {levels} уровня меню"),
+    ("runtime.unreachable_item", "Пункт недостижим: {reason}"),
+    ("runtime.unreachable_document", "Документ недостижим: {reason}"),
+    ("runtime.unreachable_content", "Содержимое недостижимо: {reason}"),
+    ("runtime.unreachable_page", "Документ на странице {page} недостижим: переключение страниц не работает"),
+    ("runtime.under_construction", "Бот ответит «Раздел в разработке»: на уровне {depth} пункты открывают только {screens}"),
+    ("runtime.shows_instead", "«{title}» показывает {screen} вместо {instead}"),
+    ("runtime.does_not_open", "«{title}» не открывается"),
+    ("runtime.no_content_nodes", "«{profile}» не показывает изображения, ссылки, сообщения и контакты"),
+    ("runtime.reserved_prefix", "callback_data «{callback_data}» начинается с «{prefix}» и обрабатывается самим ботом"),
+    ("runtime.shadowed", "Пункт перекрыт: по callback_data «{callback_data}» бот откроет «{owner}»"),
+    
+    // Search palette
+    ("search.field.title", "Название"),
+    ("search.field.callback_data", "callback_data"),
+    ("search.field.url", "URL"),
+    ("search.field.description", "Описание"),
+    ("search.field.answer", "Ответ"),
+    ("search.field.tags", "Теги"),
+    ("search.title", "Поиск"),
+    ("search.hint", "Название, callback_data, URL, описание, ответ FAQ…"),
+    ("search.empty_query", "Введите текст для поиска"),
+    ("search.nothing_found", "Ничего не найдено"),
+    
+    // Node types, ports and parameters
+    ("node_type.menu_item", "Пункт меню"),
+    ("node_type.faq_item", "Вопрос FAQ"),
+    ("node_type.process", "Процесс"),
+    ("node_type.input", "Ввод"),
+    ("node_type.output", "Вывод"),
+    ("node_type.document", "Документ"),
+    ("node_type.image", "Изображение"),
+    ("node_type.link", "Ссылка"),
+    ("node_type.text_content", "Сообщение"),
+    ("node_type.contacts", "Контакты"),
+    ("port.parent_menu", "Родительское меню"),
+    ("port.sub_menu", "Подменю"),
+    ("port.documents", "Документы"),
+    ("port.content", "Содержимое"),
+    ("param.name", "Название"),
+    ("param.description", "Описание"),
+    ("param.text_content", "Текстовое содержимое"),
+    ("param.question", "Вопрос"),
+    ("param.answer", "Ответ"),
+    ("param.tags", "Теги"),
+    ("param.image_url", "URL изображения"),
+    ("param.caption", "Подпись"),
+    ("param.button_text", "Текст кнопки"),
+    ("param.message_text", "Текст сообщения"),
+    ("param.address", "Адрес"),
+    ("param.phone", "Телефон"),
+    ("param.website", "Сайт"),
+    ("param.data", "Данные JSON"),
+    ("param.format_json", "Форматировать"),
+    ("param.date_hint", "ГГГГ-ММ-ДД"),
+    ("param.add_item", "➕ Добавить"),
+    
+    // Inspector panel
+    ("inspector.title", "Свойства"),
+    ("inspector.close", "Закрыть"),
+    ("inspector.nothing_selected", "Выберите узел на холсте"),
+    ("inspector.ports", "Порты"),
+    ("inspector.connections", "Связи"),
+    ("inspector.selected_nodes", "Выбрано узлов: {count}"),
+    ("inspector.mixed_types", "Групповое редактирование доступно для узлов одного типа"),
+    ("inspector.selected_of_type", "Выбрано: {count} × {node_type}"),
+    ("inspector.group_hint", "Значение поля задаётся всем выбранным узлам"),
+    ("inspector.mixed_values", "У выбранных узлов разные значения"),
+    ("inspector.no_connections", "Нет связей"),
+    ("inspector.go_to_node", "Перейти к узлу"),
+    ("inspector.order", "Порядок"),
+    ("inspector.drag_to_reorder", "Перетащите, чтобы изменить порядок"),
+    ("inspector.move_up", "Выше"),
+    ("inspector.move_down", "Ниже"),
+    
+    // Status bar
+    ("status.unsaved_changes", "Несохраненные изменения"),
+    ("status.ready", "Готов к работе"),
+    ("status.undo", "⟲ Отменить"),
+    ("status.undo_shortcut", "⟲ Отменить (Ctrl+Z)"),
+    ("status.redo", "⟳ Вернуть"),
+    ("status.redo_shortcut", "⟳ Вернуть (Ctrl+Y)"),
+    
+    // Diagnostics panel
+    ("diagnostics.title", "Проверка графа"),
+    ("diagnostics.counts", "Ошибок: {errors}  Предупреждений: {warnings}"),
+    ("diagnostics.info", "Информация ({count})"),
+    ("diagnostics.no_profile", "Без профиля"),
+    ("diagnostics.profile_hint", "Проверить, какие узлы бот сможет показать пользователю"),
+    ("diagnostics.max_depth", "Макс. глубина: {depth}"),
+    ("diagnostics.max_depth_hint", "Кликов от /start до самого глубокого узла"),
+    ("diagnostics.close", "Закрыть"),
+    ("diagnostics.dock_right", "Закрепить справа"),
+    ("diagnostics.dock_bottom", "Закрепить снизу"),
+    ("diagnostics.no_problems", "Проблем не найдено"),
+    ("diagnostics.depth", " ({depth} кл.)"),
+    ("diagnostics.go_to_node", "Перейти к узлу"),
+    
+    // Window title and the nodes of a new project
+    ("app.title", "ВГАУ Бот Меню Редактор"),
+    ("node.sample.main_menu", "Главное меню"),
+    ("node.sample.submenu", "Подменю: О университете"),
+    ("node.sample.faq", "Часто задаваемый вопрос"),
+    
+    // Results of file and edit commands
+    ("status.export_blocked", "Экспорт невозможен: ошибок в графе — {count}"),
+    ("status.exported", "Данные успешно экспортированы"),
+    ("status.export_error", "Ошибка экспорта: {error}"),
+    ("status.export_failed", "Ошибка при экспорте данных"),
+    ("status.project_saved", "Проект сохранен"),
+    ("status.project_save_error", "Ошибка сохранения проекта: {error}"),
+    ("status.project_in_export_tab", "Проект сформирован на вкладке экспорта"),
+    ("status.project_opened", "Проект открыт"),
+    ("status.project_open_error", "Ошибка открытия проекта: {error}"),
+    ("status.paste_project", "Вставьте содержимое проекта в поле импорта"),
+    ("status.image_saved", "Изображение {format} сохранено"),
+    ("status.image_downloaded", "Изображение {format} скачано"),
+    ("status.image_error", "Ошибка экспорта изображения: {error}"),
+    ("status.imported", "Данные успешно импортированы"),
+    ("status.import_problems", "Ошибка импорта: найдено проблем: {count}"),
+    ("status.merged", "Данные успешно объединены"),
+    ("status.merge_error", "Ошибка объединения: {error}"),
+    ("status.merge_cancelled", "Объединение отменено"),
+    ("status.paste_error", "Ошибка вставки: {error}"),
+    ("status.nothing_to_undo", "Нет действий для отмены"),
+    ("status.nothing_to_redo", "Нет действий для возврата"),
+    
+    // Merge dialog and project files
+    ("merge.title", "Объединение данных"),
+    ("merge.updates", "Обновлений без конфликтов: {count}"),
+    ("merge.new_items", "Новых элементов: {count}"),
+    ("merge.conflicts", "Конфликтов: {count}"),
+    ("merge.field_changed", "Поле «{field}» изменено с обеих сторон"),
+    ("merge.local", "Локально: {value}"),
+    ("merge.incoming", "В файле: {value}"),
+    ("merge.keep_local", "Оставить локальное"),
+    ("merge.take_incoming", "Взять из файла"),
+    ("merge.removed_upstream", "Узел удален в импортируемом файле"),
+    ("merge.keep_node", "Оставить узел"),
+    ("merge.remove_node", "Удалить узел"),
+    ("merge.apply", "Применить"),
+    ("merge.cancel", "Отмена"),
+    ("project.file_filter", "Проект меню ВГАУ"),
+    ("project.default_description", "Новое меню бота ВГАУ"),
+    
+    // Legacy workflow editor, WorkflowEditorApp in lib.rs
+    ("workflow.title", "Редактор меню телеграм-бота"),
+    ("workflow.add_element", "➕ Добавить элемент"),
+    ("workflow.help", "❓ Справка"),
+    ("workflow.selected", "Выбрано: {count}"),
+    ("workflow.delete", "🗑 Удалить"),
+    ("workflow.import_json", "📥 Импорт JSON"),
+    ("workflow.export_json", "📤 Экспорт JSON"),
+    ("workflow.language", "Язык интерфейса"),
+    ("workflow.nodes", "Узлов: {count}"),
+    ("workflow.connections", "Соединений: {count}"),
+    ("workflow.zoom", "Масштаб: {percent}%"),
+    ("workflow.selection_hint", "Ctrl+клик: мульти-выбор   Shift+клик: диапазон   Del: удалить"),
+    ("workflow.menu.title", "Добавить элемент"),
+    ("workflow.menu.show_help", "❓ Показать справку"),
+    ("workflow.menu.structure", "Структура меню"),
+    ("workflow.menu.main_menu", "🔝 Главное меню"),
+    ("workflow.menu.main_menu_hint", "Начальная точка для всего меню бота"),
+    ("workflow.menu.template", "Шаблон"),
+    ("workflow.menu.submenu", "📂 Подменю"),
+    ("workflow.menu.submenu_hint", "Группа связанных кнопок"),
+    ("workflow.menu.button", "🔘 Кнопка"),
+    ("workflow.menu.button_hint", "Элемент для взаимодействия"),
+    ("workflow.menu.content", "Содержимое"),
+    ("workflow.menu.text", "📝 Текст"),
+    ("workflow.menu.text_hint", "Текстовое сообщение от бота"),
+    ("workflow.menu.image", "🖼️ Изображение"),
+    ("workflow.menu.image_hint", "Изображение, отправляемое ботом"),
+    ("workflow.menu.document", "📄 Документ"),
+    ("workflow.menu.document_hint", "Документ для скачивания"),
+    ("workflow.menu.link", "🔗 Ссылка"),
+    ("workflow.menu.link_hint", "Ссылка на веб-страницу"),
+    ("workflow.menu.special", "Специальные элементы"),
+    ("workflow.menu.faq", "❓ FAQ"),
+    ("workflow.menu.faq_hint", "Часто задаваемый вопрос с ответом"),
+    ("workflow.menu.contacts", "📞 Контакты"),
+    ("workflow.menu.contacts_hint", "Контактная информация"),
+    ("workflow.help.title", "Помощь по редактору"),
+    ("workflow.help.heading", "Как пользоваться редактором меню:"),
+    ("workflow.help.step1", "1. Начните с добавления элемента 'Главное меню'"),
+    ("workflow.help.step2", "2. Добавляйте подменю и кнопки, соединяя их между собой"),
+    ("workflow.help.step3", "3. Добавляйте содержимое (текст, изображения и др.) и связывайте с кнопками"),
+    ("workflow.help.elements", "Элементы меню бота"),
+    ("workflow.help.main_menu", "🔝 Главное меню - начальная точка всего бота"),
+    ("workflow.help.submenu", "📂 Подменю - группирует связанные кнопки"),
+    ("workflow.help.button", "🔘 Кнопка - элемент для взаимодействия пользователя с ботом"),
+    ("workflow.help.content", "Содержимое для кнопок"),
+    ("workflow.help.text", "📝 Текст - текстовое сообщение от бота"),
+    ("workflow.help.image", "🖼️ Изображение - картинка, отправляемая ботом"),
+    ("workflow.help.document", "📄 Документ - файл для скачивания"),
+    ("workflow.help.link", "🔗 Ссылка - переход на веб-страницу"),
+    ("workflow.help.faq", "❓ FAQ - часто задаваемый вопрос с ответом"),
+    ("workflow.help.contacts", "📞 Контакты - контактная информация"),
+    ("workflow.help.shortcuts", "Горячие клавиши"),
+    ("workflow.help.shortcut.main_menu", "Shift+M - добавить главное меню"),
+    ("workflow.help.shortcut.submenu", "Shift+S - добавить подменю"),
+    ("workflow.help.shortcut.button", "Shift+B - добавить кнопку"),
+    ("workflow.help.shortcut.text", "Shift+T - добавить текст"),
+    ("workflow.help.shortcut.menu", "Ctrl+Space - открыть меню добавления элементов"),
+    ("workflow.help.close", "Закрыть справку"),
+    ("workflow.description.main_menu", "Главное меню бота - начальная точка для всех кнопок"),
+    ("workflow.description.submenu", "Подменю содержит набор связанных кнопок"),
+    ("workflow.description.button", "Кнопка для взаимодействия пользователя с ботом"),
+    ("workflow.description.text", "Текстовое сообщение, отправляемое ботом"),
+    ("workflow.description.image", "Изображение, отправляемое ботом"),
+    ("workflow.description.document", "Документ или файл для скачивания"),
+    ("workflow.description.link", "Ссылка на веб-страницу"),
+    ("workflow.description.faq", "Часто задаваемый вопрос с ответом"),
+    ("workflow.description.contacts", "Контактная информация"),
+    ("workflow.slot.items", "Пункты"),
+    ("workflow.slot.action", "Действие"),
+    ("workflow.slot.question", "Вопрос"),
+    ("workflow.slot.answer", "Ответ"),
+    ("workflow.slot.output", "Выход"),
+    ("workflow.slot.parent", "Родитель"),
+    ("workflow.slot.input", "Вход"),
+    ("workflow.node.main_menu", "Главное меню"),
+    ("workflow.node.submenu", "Подменю"),
+    ("workflow.node.button", "Кнопка"),
+    ("workflow.node.text", "Текст"),
+    ("workflow.node.image", "Изображение"),
+    ("workflow.node.document", "Документ"),
+    ("workflow.node.link", "Ссылка"),
+    ("workflow.node.faq", "FAQ"),
+    ("workflow.node.contacts", "Контакты"),
+    ("workflow.node.condition", "Условие"),
+    ("workflow.node.action", "Действие"),
+    ("workflow.node.custom", "Другое"),
+    ("workflow.node.text_content", "Содержимое текста..."),
+    ("workflow.node.question", "Часто задаваемый вопрос?"),
+    ("workflow.node.answer", "Ответ на вопрос..."),
+    ("workflow.node.address", "Адрес организации"),
+    ("workflow.template.main_menu", "Главное меню бота"),
+    ("workflow.template.menu_title", "Меню бота"),
+    ("workflow.template.submenu", "Раздел меню"),
+    ("workflow.template.button", "Кнопка"),
+    ("workflow.template.text", "Текстовое сообщение"),
+    ("workflow.template.text_content", "Пример текстового сообщения от бота"),
+];
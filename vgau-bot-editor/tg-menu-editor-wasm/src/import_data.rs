@@ -1,110 +1,319 @@
 use eframe::egui;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::bot_config::{BotMenuConfig, Contacts, Document, FaqItem, ImageContent, LinkButton, MenuItem, TextMessage};
 use crate::graph::SimpleNodeGraph;
-use serde_json::Value;
+use crate::graph::models::{Node, NodeId, NodeType};
+use crate::i18n::Language;
+use crate::migrations;
+use crate::schema::{self, ValidationError};
+
+/// How imported data is combined with the graph that is already open
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum ImportMode {
+    /// Drop the current graph and build it from the imported data
+    #[default]
+    Replace,
+    /// Add the imported tree next to the existing nodes
+    Append,
+    /// Update existing nodes matched by callback_data and add the missing ones
+    Merge,
+}
+
+impl ImportMode {
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            ImportMode::Replace => "import_mode.replace",
+            ImportMode::Append => "import_mode.append",
+            ImportMode::Merge => "import_mode.merge",
+        })
+    }
+}
+
+/// What went wrong with a node during a merge
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConflictKind {
+    /// The field was edited locally and changed in the imported file
+    FieldChanged {
+        field: &'static str,
+        local: String,
+        incoming: String,
+    },
+    /// The node exists locally but was removed from the imported file.
+    ///
+    /// Only found with the data imported last time as the base of the merge,
+    /// without it a removed entry cannot be told from one added locally.
+    RemovedUpstream,
+}
+
+/// Which side wins for a single conflict
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConflictResolution {
+    KeepLocal,
+    TakeIncoming,
+}
+
+/// A single conflict that has to be resolved before a merge is applied
+#[derive(Clone, Debug)]
+pub struct MergeConflict {
+    pub key: String,
+    pub node_id: NodeId,
+    pub kind: ConflictKind,
+    pub resolution: ConflictResolution,
+}
+
+/// A field change from the imported file that does not clash with local edits
+#[derive(Clone, Debug)]
+pub struct FieldUpdate {
+    pub node_id: NodeId,
+    pub field: &'static str,
+    pub value: String,
+}
+
+/// Result of comparing imported data with the graph, shown to the user before applying
+#[derive(Clone)]
+pub struct MergePlan {
+    pub conflicts: Vec<MergeConflict>,
+    pub updates: Vec<FieldUpdate>,
+    pub new_items: usize,
+    incoming: BotMenuConfig,
+}
+
+impl MergePlan {
+    /// The imported data this plan was built for
+    pub fn incoming(&self) -> &BotMenuConfig {
+        &self.incoming
+    }
+}
+
+/// Field values of one menu item, document or FAQ, keyed by field name
+type EntryFields = BTreeMap<&'static str, String>;
+
+/// One piece of menu item content, matching a content node in the graph
+#[derive(Clone)]
+enum ContentEntry {
+    Image(ImageContent),
+    Link(LinkButton),
+    Text(TextMessage),
+    Contacts(Contacts),
+}
 
 /// Utility functions to import bot menu data from JSON
 pub struct DataImporter;
 
 impl DataImporter {
-    /// Import bot menu structure from JSON data
-    pub fn import_menu_data(graph: &mut SimpleNodeGraph, json_data: &str) -> Result<(), String> {
-        // Parse JSON
-        let data: Value = serde_json::from_str(json_data)
-            .map_err(|e| format!("Error parsing JSON: {}", e))?;
-        
-        // Clear current graph (optional)
-        // We choose not to clear here to allow importing into existing graphs
+    /// Import bot menu structure from JSON data in the given mode
+    pub fn import_menu_data(graph: &mut SimpleNodeGraph, json_data: &str, mode: ImportMode) -> Result<BotMenuConfig, String> {
+        let config = Self::parse_config(json_data)?;
+        Self::import_config_with_mode(graph, &config, mode)?;
+        Ok(config)
+    }
+    
+    /// Parse bot menu data, upgrading older schema versions to the current one
+    pub fn parse_config(json_data: &str) -> Result<BotMenuConfig, String> {
+        Self::parse_config_checked(json_data).map_err(|errors| {
+            errors.iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+    
+    /// Parse and validate bot menu data against the JSON Schema.
+    ///
+    /// Every problem is reported with its JSON pointer and, when it can be
+    /// found, the line of the source text it comes from.
+    pub fn parse_config_checked(json_data: &str) -> Result<BotMenuConfig, Vec<ValidationError>> {
+        let value: serde_json::Value = serde_json::from_str(json_data).map_err(|e| vec![ValidationError {
+            pointer: String::new(),
+            message: format!("Error parsing JSON: {}", e),
+            line: Some(e.line()),
+        }])?;
         
-        // Import title if any
-        let title = data.get("title")
-            .and_then(|t| t.as_str())
-            .unwrap_or("Telegram Bot Menu");
+        let mut errors = match migrations::migrate_to_current(value) {
+            Ok(value) => {
+                let errors = schema::validate_bot_data(&value);
+                if errors.is_empty() {
+                    // The schema is generated from the same types, so this is not expected to fail
+                    return serde_json::from_value(value).map_err(|e| vec![ValidationError {
+                        pointer: String::new(),
+                        message: format!("Error parsing JSON: {}", e),
+                        line: None,
+                    }]);
+                }
+                errors
+            },
+            Err(message) => vec![ValidationError {
+                pointer: "/schema_version".to_string(),
+                message,
+                line: None,
+            }],
+        };
         
-        // Create root menu node
-        let main_menu_position = egui::pos2(100.0, 100.0);
-        let main_menu_id = graph.add_menu_item(main_menu_position, title.to_string());
+        schema::locate_errors(json_data, &mut errors);
+        Err(errors)
+    }
+    
+    /// Import an already parsed bot menu structure in the given mode.
+    ///
+    /// `Replace` builds the new graph aside and swaps it in as one undo step,
+    /// so data that fails to import leaves the open graph as it was.
+    ///
+    /// `Merge` is applied only when nothing conflicts. It has no base, so
+    /// nodes removed from the data are kept; use [`DataImporter::plan_merge`]
+    /// with the data imported last time and [`DataImporter::apply_merge`] to
+    /// decide conflicts and find removed nodes.
+    pub fn import_config_with_mode(graph: &mut SimpleNodeGraph, config: &BotMenuConfig, mode: ImportMode) -> Result<(), String> {
+        match mode {
+            ImportMode::Replace => {
+                let mut scratch = graph.scratch();
+                Self::import_config(&mut scratch, config)?;
+                graph.replace_with(scratch);
+                Ok(())
+            },
+            ImportMode::Append => Self::import_config(graph, config),
+            ImportMode::Merge => {
+                let plan = Self::plan_merge(graph, None, config.clone());
+                if plan.conflicts.is_empty() {
+                    Self::apply_merge(graph, &plan)
+                } else {
+                    Err(format!("Merge has {} unresolved conflicts", plan.conflicts.len()))
+                }
+            },
+        }
+    }
+    
+    /// Build graph nodes from an already parsed bot menu structure.
+    ///
+    /// Every top-level menu item becomes a root node, so exporting the graph
+    /// again yields the same `main_menu` instead of wrapping it in an extra item.
+    ///
+    /// The imported nodes are arranged as a tree below whatever the graph
+    /// already has.
+    pub fn import_config(graph: &mut SimpleNodeGraph, config: &BotMenuConfig) -> Result<(), String> {
+        let existing: HashSet<NodeId> = graph.nodes().iter().map(|node| node.id()).collect();
         
         // Import main menu items
-        if let Some(menu_array) = data.get("main_menu").and_then(|m| m.as_array()) {
-            Self::import_menu_items(graph, menu_array, main_menu_id, main_menu_position)?;
-        }
+        let main_menu_position = if existing.is_empty() {
+            egui::pos2(100.0, 100.0)
+        } else {
+            Self::free_root_position(graph)
+        };
+        Self::import_menu_items(graph, &config.main_menu, None, main_menu_position)?;
         
         // Import FAQ items
-        if let Some(faq_array) = data.get("faq").and_then(|f| f.as_array()) {
-            Self::import_faq_items(graph, faq_array)?;
-        }
+        Self::import_faq_items(graph, &config.faq, main_menu_position + egui::vec2(0.0, 400.0))?;
+        
+        let imported: HashSet<NodeId> = graph.nodes().iter()
+            .map(|node| node.id())
+            .filter(|id| !existing.contains(id))
+            .collect();
+        graph.apply_layout(Some(&imported));
         
         Ok(())
     }
     
-    /// Import menu items from JSON array
+    /// Paste a fragment of bot data: one `MenuItem`, a list of them or a whole
+    /// bot_data.json, as copied with [`DataExporter::export_fragment`](crate::export_data::DataExporter::export_fragment).
+    ///
+    /// The subtree is rebuilt with new node ids as one undo step. callback_data
+    /// is cleared so pasted items get fresh unique values instead of clashing
+    /// with the originals. Returns the ids of the pasted top-level items.
+    pub fn paste_fragment(
+        graph: &mut SimpleNodeGraph,
+        text: &str,
+        parent_id: Option<NodeId>,
+        position: egui::Pos2
+    ) -> Result<Vec<NodeId>, String> {
+        let (mut items, faqs) = Self::parse_fragment(text)?;
+        Self::clear_callback_data(&mut items);
+        
+        graph.batch(|graph| {
+            let pasted = Self::import_menu_items(graph, &items, parent_id, position)?;
+            Self::import_faq_items(graph, &faqs, position + egui::vec2(0.0, 400.0))?;
+            Ok(pasted)
+        })
+    }
+    
+    fn parse_fragment(text: &str) -> Result<(Vec<MenuItem>, Vec<FaqItem>), String> {
+        let value: serde_json::Value = serde_json::from_str(text.trim())
+            .map_err(|e| format!("Error parsing fragment: {}", e))?;
+        
+        if value.get("main_menu").is_some() {
+            let config = Self::parse_config(text)?;
+            return Ok((config.main_menu, config.faq));
+        }
+        
+        let items = match value {
+            serde_json::Value::Array(_) => serde_json::from_value(value),
+            _ => serde_json::from_value(value).map(|item| vec![item]),
+        }.map_err(|e| format!("Error parsing menu item: {}", e))?;
+        
+        Ok((items, Vec::new()))
+    }
+    
+    fn clear_callback_data(items: &mut [MenuItem]) {
+        for item in items {
+            item.callback_data.clear();
+            for doc in item.documents.iter_mut().flatten() {
+                doc.callback_data = None;
+            }
+            if let Some(submenu) = &mut item.submenu {
+                Self::clear_callback_data(submenu);
+            }
+        }
+    }
+    
+    /// Import menu items, connecting them to the parent when there is one.
+    /// Returns the ids of the created items.
     fn import_menu_items(
-        graph: &mut SimpleNodeGraph, 
-        items: &[Value], 
-        parent_id: usize, 
+        graph: &mut SimpleNodeGraph,
+        items: &[MenuItem],
+        parent_id: Option<NodeId>,
         parent_pos: egui::Pos2
-    ) -> Result<(), String> {
+    ) -> Result<Vec<NodeId>, String> {
         let spacing_x = 300.0;
         let spacing_y = 120.0;
+        let mut created = Vec::new();
         
         for (i, item) in items.iter().enumerate() {
-            // Calculate position for this menu item
-            let position = egui::pos2(
-                parent_pos.x + spacing_x,
-                parent_pos.y + i as f32 * spacing_y
-            );
+            // Root items are stacked in a column, children go to the right of the parent
+            let position = match parent_id {
+                Some(_) => egui::pos2(
+                    parent_pos.x + spacing_x,
+                    parent_pos.y + i as f32 * spacing_y
+                ),
+                None => egui::pos2(
+                    parent_pos.x,
+                    parent_pos.y + i as f32 * spacing_y
+                ),
+            };
             
-            // Get menu item values
-            let text = item.get("text")
-                .and_then(|t| t.as_str())
-                .unwrap_or("Unnamed Menu Item")
-                .to_string();
-                
-            let callback_data = item.get("callback_data")
-                .and_then(|c| c.as_str())
-                .unwrap_or("")
-                .to_string();
-                
-            let description = item.get("description")
-                .and_then(|d| d.as_str())
-                .unwrap_or("")
-                .to_string();
-                
-            let url = item.get("url")
-                .and_then(|u| u.as_str())
-                .unwrap_or("")
-                .to_string();
-                
-            let text_content = item.get("text_content")
-                .and_then(|t| t.as_str())
-                .unwrap_or("")
-                .to_string();
-                
             // Create menu item node
-            let item_id = graph.add_menu_item(position, text.clone());
+            let item_id = graph.add_menu_item(position, item.text.clone());
+            created.push(item_id);
             
             // Update node parameters
             if let Some(node) = graph.get_node_mut(item_id) {
                 if let Some(param) = node.find_param_mut("callback_data") {
-                    param.set_text_value(callback_data);
+                    param.set_text_value(item.callback_data.clone())?;
                 }
                 
                 if let Some(param) = node.find_param_mut("description") {
-                    param.set_text_value(description);
+                    param.set_text_value(item.description.clone().unwrap_or_default())?;
                 }
                 
                 if let Some(param) = node.find_param_mut("url") {
-                    param.set_text_value(url);
+                    param.set_text_value(item.url.clone().unwrap_or_default())?;
                 }
                 
                 if let Some(param) = node.find_param_mut("text_content") {
-                    param.set_text_value(text_content);
+                    param.set_text_value(item.text_content.clone().unwrap_or_default())?;
                 }
                 
                 // Handle custom data
-                if let Some(data_obj) = item.get("data") {
+                if let Some(data_obj) = &item.data {
                     if let Some(param) = node.find_param_mut("data") {
-                        param.set_text_value(data_obj.to_string());
+                        param.set_text_value(data_obj.to_string())?;
                     } else {
                         // Add data parameter if it doesn't exist
                         node.add_data_parameter(data_obj.to_string());
@@ -113,17 +322,63 @@ impl DataImporter {
             }
             
             // Connect to parent
-            graph.connect_nodes(parent_id, "sub_menu", item_id, "parent_menu");
+            if let Some(parent_id) = parent_id {
+                graph.connect_nodes(parent_id, "sub_menu", item_id, "parent_menu");
+            }
             
             // Process submenu if exists
-            if let Some(submenu) = item.get("submenu").and_then(|s| s.as_array()) {
-                Self::import_menu_items(graph, submenu, item_id, position)?;
+            if let Some(submenu) = &item.submenu {
+                Self::import_menu_items(graph, submenu, Some(item_id), position)?;
             }
             
             // Process documents if exists
-            if let Some(docs) = item.get("documents").and_then(|d| d.as_array()) {
+            if let Some(docs) = &item.documents {
                 Self::import_documents(graph, docs, item_id, position)?;
             }
+            
+            // Content nodes go below the documents
+            let docs_count = item.documents.as_ref().map_or(0, |docs| docs.len());
+            let content_pos = position + egui::vec2(0.0, docs_count as f32 * 80.0);
+            Self::import_content(graph, &Self::content_of(item), item_id, content_pos)?;
+        }
+        
+        Ok(created)
+    }
+    
+    /// Import content nodes (images, links, texts, contacts) for a menu item
+    fn import_content(
+        graph: &mut SimpleNodeGraph,
+        content: &[ContentEntry],
+        parent_id: NodeId,
+        parent_pos: egui::Pos2
+    ) -> Result<(), String> {
+        let content_spacing_x = 300.0;
+        let content_spacing_y = 80.0;
+        
+        for (i, entry) in content.iter().enumerate() {
+            let position = egui::pos2(
+                parent_pos.x + content_spacing_x,
+                parent_pos.y + i as f32 * content_spacing_y
+            );
+            
+            let language = graph.language();
+            let (node_type, title) = match entry {
+                ContentEntry::Image(_) => (NodeType::Image, language.tr("node.image").to_string()),
+                ContentEntry::Link(link) => (NodeType::Link, link.text.clone()),
+                ContentEntry::Text(_) => (NodeType::TextContent, language.tr("node.text").to_string()),
+                ContentEntry::Contacts(_) => (NodeType::Contacts, language.tr("node.contacts").to_string()),
+            };
+            let node_id = graph.add_content_node(node_type, position, title)?;
+            
+            if let Some(node) = graph.get_node_mut(node_id) {
+                for (field, value) in Self::content_fields(entry) {
+                    if let Some(param) = node.find_param_mut(field) {
+                        param.set_text_value(value)?;
+                    }
+                }
+            }
+            
+            graph.connect_nodes(parent_id, "content", node_id, "parent_menu");
         }
         
         Ok(())
@@ -132,8 +387,8 @@ impl DataImporter {
     /// Import documents for a menu item
     fn import_documents(
         graph: &mut SimpleNodeGraph,
-        docs: &[Value],
-        parent_id: usize,
+        docs: &[Document],
+        parent_id: NodeId,
         parent_pos: egui::Pos2
     ) -> Result<(), String> {
         let doc_spacing_x = 300.0;
@@ -146,35 +401,19 @@ impl DataImporter {
                 parent_pos.y + i as f32 * doc_spacing_y
             );
             
-            // Get document values
-            let text = doc.get("text")
-                .and_then(|t| t.as_str())
-                .unwrap_or("Unnamed Document")
-                .to_string();
-                
-            let callback_data = doc.get("callback_data")
-                .and_then(|c| c.as_str())
-                .unwrap_or("")
-                .to_string();
-                
-            let url = doc.get("url")
-                .and_then(|u| u.as_str())
-                .unwrap_or("")
-                .to_string();
-                
             // Create document node
-            let doc_id = graph.add_document(position, text.clone())?;
+            let doc_id = graph.add_document(position, doc.text.clone())?;
             
             // Update node parameters
             if let Some(node) = graph.get_node_mut(doc_id) {
-                if !callback_data.is_empty() {
+                if let Some(callback_data) = &doc.callback_data {
                     if let Some(param) = node.find_param_mut("callback_data") {
-                        param.set_text_value(callback_data);
+                        param.set_text_value(callback_data.clone())?;
                     }
                 }
                 
                 if let Some(param) = node.find_param_mut("url") {
-                    param.set_text_value(url);
+                    param.set_text_value(doc.url.clone())?;
                 }
             }
             
@@ -185,58 +424,484 @@ impl DataImporter {
         Ok(())
     }
     
-    /// Import FAQ items
+    /// Import FAQ items in a column starting at `start`
     fn import_faq_items(
         graph: &mut SimpleNodeGraph,
-        faqs: &[Value]
+        faqs: &[FaqItem],
+        start: egui::Pos2
     ) -> Result<(), String> {
-        let faq_start_x = 100.0;
-        let faq_start_y = 500.0;
         let faq_spacing_y = 100.0;
         
         for (i, faq) in faqs.iter().enumerate() {
             // Calculate position for this FAQ item
-            let position = egui::pos2(
-                faq_start_x,
-                faq_start_y + i as f32 * faq_spacing_y
-            );
+            let position = start + egui::vec2(0.0, i as f32 * faq_spacing_y);
             
-            // Get FAQ values
-            let question = faq.get("question")
-                .and_then(|q| q.as_str())
-                .unwrap_or("Unnamed Question")
-                .to_string();
-                
-            let answer = faq.get("answer")
-                .and_then(|a| a.as_str())
-                .unwrap_or("")
-                .to_string();
-                
-            let tags = faq.get("tags")
-                .and_then(|t| t.as_array())
-                .map(|arr| {
-                    arr.iter()
-                        .filter_map(|v| v.as_str())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                })
-                .unwrap_or_default();
-                
             // Create FAQ item node
-            let faq_id = graph.add_faq_item(position, question.clone());
+            let faq_id = graph.add_faq_item(position, faq.question.clone());
             
             // Update node parameters
             if let Some(node) = graph.get_node_mut(faq_id) {
                 if let Some(param) = node.find_param_mut("answer") {
-                    param.set_text_value(answer);
+                    param.set_text_value(faq.answer.clone())?;
                 }
                 
                 if let Some(param) = node.find_param_mut("tags") {
-                    param.set_text_value(tags);
+                    param.set_text_value(faq.tags.join(", "))?;
                 }
             }
         }
         
         Ok(())
     }
-} 
\ No newline at end of file
+    
+    /// Compare imported data with the graph and collect the changes to apply.
+    ///
+    /// Nodes are matched by `callback_data` (documents without one by URL, FAQs by
+    /// question). `base` is the data imported last time: with it a field that was
+    /// only changed upstream is updated silently and only fields changed on both
+    /// sides become conflicts. Without a base every differing field is a conflict,
+    /// and nodes removed upstream are not reported since they look like local additions.
+    pub fn plan_merge(graph: &SimpleNodeGraph, base: Option<&BotMenuConfig>, incoming: BotMenuConfig) -> MergePlan {
+        let local = Self::graph_entries(graph);
+        let incoming_entries = Self::config_entries(&incoming);
+        let base_entries = base.map(Self::config_entries);
+        
+        let mut conflicts = Vec::new();
+        let mut updates = Vec::new();
+        let mut new_items = 0;
+        
+        for (key, incoming_fields) in &incoming_entries {
+            let Some((node_id, local_fields)) = local.get(key) else {
+                new_items += 1;
+                continue;
+            };
+            let base_fields = base_entries.as_ref().and_then(|entries| entries.get(key));
+            
+            for (field, incoming_value) in incoming_fields {
+                let local_value = local_fields.get(field).cloned().unwrap_or_default();
+                if &local_value == incoming_value {
+                    continue;
+                }
+                
+                let base_value = base_fields.map(|fields| fields.get(field).cloned().unwrap_or_default());
+                match base_value {
+                    // Only changed upstream: take the new value
+                    Some(base_value) if base_value == local_value => updates.push(FieldUpdate {
+                        node_id: *node_id,
+                        field,
+                        value: incoming_value.clone(),
+                    }),
+                    // Only changed locally: keep the local edit
+                    Some(base_value) if &base_value == incoming_value => {},
+                    _ => conflicts.push(MergeConflict {
+                        key: key.clone(),
+                        node_id: *node_id,
+                        kind: ConflictKind::FieldChanged {
+                            field,
+                            local: local_value,
+                            incoming: incoming_value.clone(),
+                        },
+                        resolution: ConflictResolution::KeepLocal,
+                    }),
+                }
+            }
+        }
+        
+        // Entries that were imported before, still exist locally, but are gone upstream
+        if let Some(base_entries) = &base_entries {
+            for key in base_entries.keys() {
+                if incoming_entries.contains_key(key) {
+                    continue;
+                }
+                if let Some((node_id, _)) = local.get(key) {
+                    conflicts.push(MergeConflict {
+                        key: key.clone(),
+                        node_id: *node_id,
+                        kind: ConflictKind::RemovedUpstream,
+                        resolution: ConflictResolution::KeepLocal,
+                    });
+                }
+            }
+        }
+        
+        conflicts.sort_by(|a, b| a.key.cmp(&b.key));
+        updates.sort_by_key(|u| (u.node_id, u.field));
+        
+        MergePlan {
+            conflicts,
+            updates,
+            new_items,
+            incoming,
+        }
+    }
+    
+    /// Apply a merge plan with the resolutions chosen by the user
+    pub fn apply_merge(graph: &mut SimpleNodeGraph, plan: &MergePlan) -> Result<(), String> {
+        // Remember where existing nodes are before anything is removed
+        let local = Self::graph_entries(graph);
+        
+        for update in &plan.updates {
+            Self::apply_field(graph, update.node_id, update.field, &update.value)?;
+        }
+        
+        for conflict in &plan.conflicts {
+            if conflict.resolution != ConflictResolution::TakeIncoming {
+                continue;
+            }
+            match &conflict.kind {
+                ConflictKind::FieldChanged { field, incoming, .. } => {
+                    Self::apply_field(graph, conflict.node_id, field, incoming)?;
+                },
+                ConflictKind::RemovedUpstream => {
+                    graph.delete_node(conflict.node_id);
+                },
+            }
+        }
+        
+        // Add everything that has no counterpart in the graph yet
+        Self::merge_menu_items(graph, &local, &plan.incoming.main_menu, None)?;
+        
+        let faq_to_add: Vec<FaqItem> = plan.incoming.faq.iter()
+            .filter(|faq| !local.contains_key(&Self::faq_key(faq)))
+            .cloned()
+            .collect();
+        Self::import_faq_items(graph, &faq_to_add, egui::pos2(100.0, 500.0))?;
+        
+        graph.save_state();
+        
+        Ok(())
+    }
+    
+    /// Walk the imported tree and attach missing items under their matched parent
+    fn merge_menu_items(
+        graph: &mut SimpleNodeGraph,
+        local: &HashMap<String, (NodeId, EntryFields)>,
+        items: &[MenuItem],
+        parent_id: Option<NodeId>,
+    ) -> Result<(), String> {
+        for item in items {
+            match local.get(&item.callback_data) {
+                Some((node_id, _)) => {
+                    if let Some(submenu) = &item.submenu {
+                        Self::merge_menu_items(graph, local, submenu, Some(*node_id))?;
+                    }
+                    
+                    if let Some(docs) = &item.documents {
+                        let missing_docs: Vec<Document> = docs.iter()
+                            .filter(|doc| !local.contains_key(&Self::document_key(doc)))
+                            .cloned()
+                            .collect();
+                        let position = Self::node_position(graph, *node_id);
+                        Self::import_documents(graph, &missing_docs, *node_id, position)?;
+                    }
+                    
+                    let content = Self::content_of(item);
+                    let missing_content: Vec<ContentEntry> = Self::content_keys(&item.callback_data, &content).iter()
+                        .zip(content)
+                        .filter(|(key, _)| !local.contains_key(*key))
+                        .map(|(_, entry)| entry)
+                        .collect();
+                    let position = Self::node_position(graph, *node_id);
+                    Self::import_content(graph, &missing_content, *node_id, position)?;
+                },
+                None => {
+                    let position = match parent_id {
+                        Some(parent_id) => Self::node_position(graph, parent_id),
+                        None => Self::free_root_position(graph),
+                    };
+                    Self::import_menu_items(graph, std::slice::from_ref(item), parent_id, position)?;
+                },
+            }
+        }
+        
+        Ok(())
+    }
+    
+    /// Set a merged field on the node, mapping it to the matching parameter
+    fn apply_field(graph: &mut SimpleNodeGraph, node_id: NodeId, field: &str, value: &str) -> Result<(), String> {
+        let Some(node) = graph.get_node_mut(node_id) else {
+            return Ok(());
+        };
+        
+        let param_id = match (&node.node_type, field) {
+            (NodeType::MenuItem, "text") => "name",
+            _ => field,
+        };
+        
+        let renames_node = match node.node_type {
+            NodeType::MenuItem | NodeType::Document | NodeType::Link => field == "text",
+            NodeType::FaqItem => field == "question",
+            _ => false,
+        };
+        
+        if renames_node {
+            node.title = value.to_string();
+        }
+        
+        match node.find_param_mut(param_id) {
+            Some(param) => param.set_text_value(value.to_string())?,
+            None if param_id == "data" => node.add_data_parameter(value.to_string()),
+            None => {},
+        }
+        
+        Ok(())
+    }
+    
+    /// Flatten bot menu data into entries keyed the same way as graph nodes
+    fn config_entries(config: &BotMenuConfig) -> HashMap<String, EntryFields> {
+        let mut entries = HashMap::new();
+        Self::collect_config_items(&config.main_menu, &mut entries);
+        
+        for faq in &config.faq {
+            entries.insert(Self::faq_key(faq), Self::faq_fields(faq));
+        }
+        
+        entries
+    }
+    
+    fn collect_config_items(items: &[MenuItem], entries: &mut HashMap<String, EntryFields>) {
+        for item in items {
+            entries.insert(item.callback_data.clone(), Self::menu_item_fields(item));
+            
+            for doc in item.documents.iter().flatten() {
+                entries.insert(Self::document_key(doc), Self::document_fields(doc));
+            }
+            
+            let content = Self::content_of(item);
+            for (key, entry) in Self::content_keys(&item.callback_data, &content).into_iter().zip(&content) {
+                entries.insert(key, Self::content_fields(entry).into_iter().collect());
+            }
+            
+            if let Some(submenu) = &item.submenu {
+                Self::collect_config_items(submenu, entries);
+            }
+        }
+    }
+    
+    /// Collect the entries of the current graph together with their node ids
+    fn graph_entries(graph: &SimpleNodeGraph) -> HashMap<String, (NodeId, EntryFields)> {
+        let mut entries = HashMap::new();
+        let mut visited = HashSet::new();
+        
+        for root_id in graph.get_root_menu_nodes() {
+            Self::collect_graph_items(graph, root_id, &mut visited, &mut entries);
+        }
+        
+        for faq_id in graph.get_faq_nodes() {
+            if let Some(node) = graph.get_node_data(faq_id) {
+                let faq = FaqItem {
+                    question: Self::param_or_title(node, "question"),
+                    answer: Self::param_text(node, "answer"),
+                    tags: Self::split_tags(&Self::param_text(node, "tags")),
+                };
+                entries.insert(Self::faq_key(&faq), (faq_id, Self::faq_fields(&faq)));
+            }
+        }
+        
+        entries
+    }
+    
+    fn collect_graph_items(
+        graph: &SimpleNodeGraph,
+        node_id: NodeId,
+        visited: &mut HashSet<NodeId>,
+        entries: &mut HashMap<String, (NodeId, EntryFields)>,
+    ) {
+        if !visited.insert(node_id) {
+            return;
+        }
+        let Some(node) = graph.get_node_data(node_id) else {
+            return;
+        };
+        
+        let item = MenuItem {
+            text: Self::param_or_title(node, "name"),
+            callback_data: Self::param_text(node, "callback_data"),
+            description: Some(Self::param_text(node, "description")),
+            url: Some(Self::param_text(node, "url")),
+            text_content: Some(Self::param_text(node, "text_content")),
+            data: serde_json::from_str(&Self::param_text(node, "data")).ok(),
+            ..Default::default()
+        };
+        if !item.callback_data.is_empty() {
+            entries.insert(item.callback_data.clone(), (node_id, Self::menu_item_fields(&item)));
+        }
+        
+        for doc_id in graph.get_documents_for_node(node_id) {
+            if let Some(doc_node) = graph.get_node_data(doc_id) {
+                let callback_data = Self::param_text(doc_node, "callback_data");
+                let doc = Document {
+                    text: Self::param_or_title(doc_node, "text"),
+                    callback_data: (!callback_data.is_empty()).then_some(callback_data),
+                    url: Self::param_text(doc_node, "url"),
+                };
+                entries.insert(Self::document_key(&doc), (doc_id, Self::document_fields(&doc)));
+            }
+        }
+        
+        if !item.callback_data.is_empty() {
+            let (content_ids, content): (Vec<NodeId>, Vec<ContentEntry>) = graph.get_content_for_node(node_id).into_iter()
+                .filter_map(|id| graph.get_node_data(id).and_then(Self::content_from_node).map(|entry| (id, entry)))
+                .unzip();
+            let keys = Self::content_keys(&item.callback_data, &content);
+            for ((key, entry), content_id) in keys.into_iter().zip(&content).zip(content_ids) {
+                entries.insert(key, (content_id, Self::content_fields(entry).into_iter().collect()));
+            }
+        }
+        
+        for child_id in graph.get_child_menu_nodes(node_id) {
+            Self::collect_graph_items(graph, child_id, visited, entries);
+        }
+    }
+    
+    /// All content of a menu item in one list, in the order the bot sends it
+    fn content_of(item: &MenuItem) -> Vec<ContentEntry> {
+        let images = item.images.iter().flatten().cloned().map(ContentEntry::Image);
+        let links = item.links.iter().flatten().cloned().map(ContentEntry::Link);
+        let messages = item.messages.iter().flatten().cloned().map(ContentEntry::Text);
+        let contacts = item.contacts.iter().flatten().cloned().map(ContentEntry::Contacts);
+        
+        images.chain(links).chain(messages).chain(contacts).collect()
+    }
+    
+    /// Read a content node back as the entry it was imported from
+    fn content_from_node(node: &Node) -> Option<ContentEntry> {
+        let optional = |id: &str| {
+            let value = Self::param_text(node, id);
+            (!value.is_empty()).then_some(value)
+        };
+        
+        match node.node_type {
+            NodeType::Image => Some(ContentEntry::Image(ImageContent {
+                url: Self::param_text(node, "url"),
+                caption: optional("caption"),
+            })),
+            NodeType::Link => Some(ContentEntry::Link(LinkButton {
+                text: Self::param_or_title(node, "text"),
+                url: Self::param_text(node, "url"),
+            })),
+            NodeType::TextContent => Some(ContentEntry::Text(TextMessage {
+                text: Self::param_text(node, "text"),
+            })),
+            NodeType::Contacts => Some(ContentEntry::Contacts(Contacts {
+                address: optional("address"),
+                phone: optional("phone"),
+                email: optional("email"),
+                website: optional("website"),
+            })),
+            _ => None,
+        }
+    }
+    
+    /// Parameters of a content node, named the same as the JSON fields
+    fn content_fields(entry: &ContentEntry) -> Vec<(&'static str, String)> {
+        match entry {
+            ContentEntry::Image(image) => vec![
+                ("url", image.url.clone()),
+                ("caption", image.caption.clone().unwrap_or_default()),
+            ],
+            ContentEntry::Link(link) => vec![
+                ("text", link.text.clone()),
+                ("url", link.url.clone()),
+            ],
+            ContentEntry::Text(message) => vec![
+                ("text", message.text.clone()),
+            ],
+            ContentEntry::Contacts(contacts) => vec![
+                ("address", contacts.address.clone().unwrap_or_default()),
+                ("phone", contacts.phone.clone().unwrap_or_default()),
+                ("email", contacts.email.clone().unwrap_or_default()),
+                ("website", contacts.website.clone().unwrap_or_default()),
+            ],
+        }
+    }
+    
+    /// Merge keys of a menu item's content.
+    ///
+    /// Images and links are matched by URL, texts and contacts by their position
+    /// among the entries of the same kind.
+    fn content_keys(parent_key: &str, content: &[ContentEntry]) -> Vec<String> {
+        let mut messages = 0;
+        let mut contacts = 0;
+        
+        content.iter().map(|entry| match entry {
+            ContentEntry::Image(image) => format!("{}/image:{}", parent_key, image.url),
+            ContentEntry::Link(link) => format!("{}/link:{}", parent_key, link.url),
+            ContentEntry::Text(_) => {
+                messages += 1;
+                format!("{}/message:{}", parent_key, messages - 1)
+            },
+            ContentEntry::Contacts(_) => {
+                contacts += 1;
+                format!("{}/contacts:{}", parent_key, contacts - 1)
+            },
+        }).collect()
+    }
+    
+    fn menu_item_fields(item: &MenuItem) -> EntryFields {
+        BTreeMap::from([
+            ("text", item.text.clone()),
+            ("description", item.description.clone().unwrap_or_default()),
+            ("url", item.url.clone().unwrap_or_default()),
+            ("text_content", item.text_content.clone().unwrap_or_default()),
+            ("data", item.data.as_ref().map(|d| d.to_string()).unwrap_or_default()),
+        ])
+    }
+    
+    fn document_fields(doc: &Document) -> EntryFields {
+        BTreeMap::from([
+            ("text", doc.text.clone()),
+            ("url", doc.url.clone()),
+        ])
+    }
+    
+    fn faq_fields(faq: &FaqItem) -> EntryFields {
+        BTreeMap::from([
+            ("answer", faq.answer.clone()),
+            ("tags", faq.tags.join(", ")),
+        ])
+    }
+    
+    fn document_key(doc: &Document) -> String {
+        match &doc.callback_data {
+            Some(callback_data) if !callback_data.is_empty() => callback_data.clone(),
+            _ => format!("url:{}", doc.url),
+        }
+    }
+    
+    fn faq_key(faq: &FaqItem) -> String {
+        format!("faq:{}", faq.question)
+    }
+    
+    fn param_text(node: &Node, id: &str) -> String {
+        node.find_param(id).map(|p| p.get_text()).unwrap_or_default()
+    }
+    
+    fn param_or_title(node: &Node, id: &str) -> String {
+        let text = Self::param_text(node, id);
+        if text.is_empty() { node.get_title().to_string() } else { text }
+    }
+    
+    fn split_tags(text: &str) -> Vec<String> {
+        text.split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+    
+    fn node_position(graph: &SimpleNodeGraph, node_id: NodeId) -> egui::Pos2 {
+        graph.get_node_data(node_id)
+            .map(|node| node.position)
+            .unwrap_or(egui::pos2(100.0, 100.0))
+    }
+    
+    /// Position below every existing node, so appended roots do not overlap the graph
+    fn free_root_position(graph: &SimpleNodeGraph) -> egui::Pos2 {
+        let max_y = graph.get_root_menu_nodes().iter()
+            .chain(graph.get_faq_nodes().iter())
+            .filter_map(|id| graph.get_node_data(*id))
+            .map(|node| node.position.y + node.size.y)
+            .fold(0.0_f32, f32::max);
+        egui::pos2(100.0, max_y + 150.0)
+    }
+}
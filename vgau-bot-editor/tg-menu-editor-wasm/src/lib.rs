@@ -1,6 +1,20 @@
+pub mod bot_config;
+pub mod callback_data;
+pub mod export_data;
+pub mod graph;
+pub mod i18n;
+pub mod import_data;
+pub mod migrations;
+pub mod schema;
+pub mod settings;
+pub mod theme;
+pub mod ui;
+
 use eframe::egui;
 use std::collections::{HashMap, HashSet};
 
+use i18n::Language;
+
 // ======== Data Models ========
 
 /// Represents a node type in the telegram bot menu editor
@@ -42,35 +56,36 @@ impl NodeType {
         }
     }
     
+    // Slot names are message keys, they are translated when drawn
     fn get_outputs(&self) -> Vec<String> {
         match self {
-            NodeType::MainMenu | NodeType::Submenu => vec!["Items".to_string()],
-            NodeType::Button => vec!["Action".to_string()],
-            NodeType::FAQ => vec!["Question".to_string(), "Answer".to_string()],
-            _ => vec!["Output".to_string()],
+            NodeType::MainMenu | NodeType::Submenu => vec!["workflow.slot.items".to_string()],
+            NodeType::Button => vec!["workflow.slot.action".to_string()],
+            NodeType::FAQ => vec!["workflow.slot.question".to_string(), "workflow.slot.answer".to_string()],
+            _ => vec!["workflow.slot.output".to_string()],
         }
     }
     
     fn get_inputs(&self) -> Vec<String> {
         match self {
             NodeType::MainMenu => vec![],
-            NodeType::Submenu | NodeType::Button => vec!["Parent".to_string()],
-            _ => vec!["Input".to_string()],
+            NodeType::Submenu | NodeType::Button => vec!["workflow.slot.parent".to_string()],
+            _ => vec!["workflow.slot.input".to_string()],
         }
     }
     
-    fn get_description(&self) -> &str {
-        match self {
-            NodeType::MainMenu => "Главное меню бота - начальная точка для всех кнопок",
-            NodeType::Submenu => "Подменю содержит набор связанных кнопок",
-            NodeType::Button => "Кнопка для взаимодействия пользователя с ботом",
-            NodeType::TextContent => "Текстовое сообщение, отправляемое ботом",
-            NodeType::Image => "Изображение, отправляемое ботом",
-            NodeType::Document => "Документ или файл для скачивания",
-            NodeType::Link => "Ссылка на веб-страницу",
-            NodeType::FAQ => "Часто задаваемый вопрос с ответом",
-            NodeType::Contacts => "Контактная информация",
-        }
+    fn get_description(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            NodeType::MainMenu => "workflow.description.main_menu",
+            NodeType::Submenu => "workflow.description.submenu",
+            NodeType::Button => "workflow.description.button",
+            NodeType::TextContent => "workflow.description.text",
+            NodeType::Image => "workflow.description.image",
+            NodeType::Document => "workflow.description.document",
+            NodeType::Link => "workflow.description.link",
+            NodeType::FAQ => "workflow.description.faq",
+            NodeType::Contacts => "workflow.description.contacts",
+        })
     }
 }
 
@@ -279,6 +294,7 @@ impl NodeSystem {
     }
     
     fn draw_nodes(&mut self, ui: &mut egui::Ui, canvas: &CanvasSystem) {
+        let language = Language::current(ui.ctx());
         let mut hovered_port: Option<(usize, usize, bool)> = None; // (node_id, slot_index, is_input)
         let mut connecting_from: Option<(usize, usize)> = None; // (node_id, slot_index)
         
@@ -367,7 +383,7 @@ impl NodeSystem {
             painter.text(
                 input_pos + egui::vec2(12.0, 0.0) * canvas.scale,
                 egui::Align2::LEFT_CENTER,
-                input,
+                language.get(input).unwrap_or(input),
                     egui::FontId::proportional(14.0 * canvas.scale),
                 text_color,
             );
@@ -403,7 +419,7 @@ impl NodeSystem {
             painter.text(
                     output_pos - egui::vec2(12.0, 0.0) * canvas.scale,
                 egui::Align2::RIGHT_CENTER,
-                output,
+                language.get(output).unwrap_or(output),
                     egui::FontId::proportional(14.0 * canvas.scale),
                 text_color,
             );
@@ -784,10 +800,11 @@ impl MenuSystem {
         }
         
         let canvas_pos = canvas.screen_to_canvas_pos(self.menu_position);
+        let language = Language::current(ctx);
         let mut show_menu = true;
         let mut action = NodeAction::None;
         
-        egui::Window::new("Добавить элемент")
+        egui::Window::new(language.tr("workflow.menu.title"))
             .title_bar(true)
             .fixed_pos(self.menu_position)
             .auto_sized()
@@ -803,45 +820,45 @@ impl MenuSystem {
         // Show help window if active
         if self.show_help {
             let mut show_help = true;
-            egui::Window::new("Помощь по редактору")
+            egui::Window::new(language.tr("workflow.help.title"))
                 .title_bar(true)
                 .auto_sized()
                 .open(&mut show_help)
                 .show(ctx, |ui| {
-                    ui.heading("Как пользоваться редактором меню:");
+                    ui.heading(language.tr("workflow.help.heading"));
                     ui.add_space(10.0);
                     
-                    ui.label("1. Начните с добавления элемента 'Главное меню'");
-                    ui.label("2. Добавляйте подменю и кнопки, соединяя их между собой");
-                    ui.label("3. Добавляйте содержимое (текст, изображения и др.) и связывайте с кнопками");
+                    ui.label(language.tr("workflow.help.step1"));
+                    ui.label(language.tr("workflow.help.step2"));
+                    ui.label(language.tr("workflow.help.step3"));
                     ui.add_space(10.0);
                     
-                    ui.collapsing("Элементы меню бота", |ui| {
-                        ui.label("🔝 Главное меню - начальная точка всего бота");
-                        ui.label("📂 Подменю - группирует связанные кнопки");
-                        ui.label("🔘 Кнопка - элемент для взаимодействия пользователя с ботом");
+                    ui.collapsing(language.tr("workflow.help.elements"), |ui| {
+                        ui.label(language.tr("workflow.help.main_menu"));
+                        ui.label(language.tr("workflow.help.submenu"));
+                        ui.label(language.tr("workflow.help.button"));
                     });
                     
-                    ui.collapsing("Содержимое для кнопок", |ui| {
-                        ui.label("📝 Текст - текстовое сообщение от бота");
-                        ui.label("🖼️ Изображение - картинка, отправляемая ботом");
-                        ui.label("📄 Документ - файл для скачивания");
-                        ui.label("🔗 Ссылка - переход на веб-страницу");
-                        ui.label("❓ FAQ - часто задаваемый вопрос с ответом");
-                        ui.label("📞 Контакты - контактная информация");
+                    ui.collapsing(language.tr("workflow.help.content"), |ui| {
+                        ui.label(language.tr("workflow.help.text"));
+                        ui.label(language.tr("workflow.help.image"));
+                        ui.label(language.tr("workflow.help.document"));
+                        ui.label(language.tr("workflow.help.link"));
+                        ui.label(language.tr("workflow.help.faq"));
+                        ui.label(language.tr("workflow.help.contacts"));
                     });
                     
                     ui.add_space(10.0);
-                    ui.collapsing("Горячие клавиши", |ui| {
-                        ui.label("Shift+M - добавить главное меню");
-                        ui.label("Shift+S - добавить подменю");
-                        ui.label("Shift+B - добавить кнопку");
-                        ui.label("Shift+T - добавить текст");
-                        ui.label("Ctrl+Space - открыть меню добавления элементов");
+                    ui.collapsing(language.tr("workflow.help.shortcuts"), |ui| {
+                        ui.label(language.tr("workflow.help.shortcut.main_menu"));
+                        ui.label(language.tr("workflow.help.shortcut.submenu"));
+                        ui.label(language.tr("workflow.help.shortcut.button"));
+                        ui.label(language.tr("workflow.help.shortcut.text"));
+                        ui.label(language.tr("workflow.help.shortcut.menu"));
                     });
                     
                     ui.add_space(20.0);
-                    if ui.button("Закрыть справку").clicked() {
+                    if ui.button(language.tr("workflow.help.close")).clicked() {
                         self.show_help = false;
                     }
                 });
@@ -856,11 +873,12 @@ impl MenuSystem {
     
     fn render_menu_content(&self, ui: &mut egui::Ui, canvas_pos: egui::Pos2) -> NodeAction {
         ui.set_min_width(280.0);
+        let language = Language::current(ui.ctx());
         let mut action = NodeAction::None;
         
         // Help button with icon
         if ui.add(
-            Self::create_menu_button("❓ Показать справку", "")
+            Self::create_menu_button(language.tr("workflow.menu.show_help"), "")
                 .min_size([280.0, 32.0].into())
                 .fill(egui::Color32::from_rgb(45, 45, 55))
         ).clicked() {
@@ -874,7 +892,7 @@ impl MenuSystem {
         
         // Menu structure section
         ui.collapsing(
-            egui::RichText::new("Структура меню")
+            egui::RichText::new(language.tr("workflow.menu.structure"))
                 .strong()
                 .size(16.0)
                 .color(egui::Color32::from_rgb(150, 150, 230)),
@@ -884,22 +902,22 @@ impl MenuSystem {
                     .spacing([8.0, 8.0])
                     .show(ui, |ui| {
                         // Main Menu button
-                        if ui.add(Self::create_menu_button("🔝 Главное меню", "Shift+M"))
-                        .on_hover_text("Начальная точка для всего меню бота")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.main_menu"), "Shift+M"))
+                        .on_hover_text(language.tr("workflow.menu.main_menu_hint"))
                         .clicked() {
                         action = NodeAction::CreateMainMenu(canvas_pos);
                         ui.close_menu();
                     }
                         ui.label(egui::RichText::new("(Shift+M)").size(14.0).color(egui::Color32::from_rgb(170, 170, 170)));
-                        if ui.add(Self::create_menu_button("Шаблон", "")).clicked() {
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.template"), "")).clicked() {
                         action = NodeAction::CreateTemplateMenu(canvas_pos);
                         ui.close_menu();
                     }
                     ui.end_row();
                     
                         // Submenu button
-                        if ui.add(Self::create_menu_button("📂 Подменю", "Shift+S"))
-                        .on_hover_text("Группа связанных кнопок")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.submenu"), "Shift+S"))
+                        .on_hover_text(language.tr("workflow.menu.submenu_hint"))
                         .clicked() {
                         action = NodeAction::CreateSubmenu(canvas_pos);
                         ui.close_menu();
@@ -909,8 +927,8 @@ impl MenuSystem {
                     ui.end_row();
                     
                         // Button button
-                        if ui.add(Self::create_menu_button("🔘 Кнопка", "Shift+B"))
-                        .on_hover_text("Элемент для взаимодействия")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.button"), "Shift+B"))
+                        .on_hover_text(language.tr("workflow.menu.button_hint"))
                         .clicked() {
                         action = NodeAction::CreateButton(canvas_pos);
                         ui.close_menu();
@@ -926,7 +944,7 @@ impl MenuSystem {
         
         // Content nodes section
         ui.collapsing(
-            egui::RichText::new("Содержимое")
+            egui::RichText::new(language.tr("workflow.menu.content"))
                 .strong()
                 .size(16.0)
                 .color(egui::Color32::from_rgb(150, 230, 150)),
@@ -936,8 +954,8 @@ impl MenuSystem {
                     ui.add_space(4.0);
                     
                     ui.horizontal(|ui| {
-                        if ui.add(Self::create_menu_button("📝 Текст", "Shift+T"))
-                            .on_hover_text("Текстовое сообщение от бота")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.text"), "Shift+T"))
+                            .on_hover_text(language.tr("workflow.menu.text_hint"))
                             .clicked() {
                             action = NodeAction::CreateTextContent(canvas_pos);
                             ui.close_menu();
@@ -946,8 +964,8 @@ impl MenuSystem {
                         
                         ui.add_space(10.0);
                         
-                        if ui.add(Self::create_menu_button("🖼️ Изображение", "Shift+I"))
-                            .on_hover_text("Изображение, отправляемое ботом")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.image"), "Shift+I"))
+                            .on_hover_text(language.tr("workflow.menu.image_hint"))
                             .clicked() {
                             action = NodeAction::CreateImage(canvas_pos);
                             ui.close_menu();
@@ -958,8 +976,8 @@ impl MenuSystem {
                     ui.add_space(8.0);
                     
                     ui.horizontal(|ui| {
-                        if ui.add(Self::create_menu_button("📄 Документ", "Shift+F"))
-                            .on_hover_text("Документ для скачивания")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.document"), "Shift+F"))
+                            .on_hover_text(language.tr("workflow.menu.document_hint"))
                             .clicked() {
                             action = NodeAction::CreateDocument(canvas_pos);
                             ui.close_menu();
@@ -968,8 +986,8 @@ impl MenuSystem {
                         
                         ui.add_space(10.0);
                         
-                        if ui.add(Self::create_menu_button("🔗 Ссылка", "Shift+L"))
-                            .on_hover_text("Ссылка на веб-страницу")
+                        if ui.add(Self::create_menu_button(language.tr("workflow.menu.link"), "Shift+L"))
+                            .on_hover_text(language.tr("workflow.menu.link_hint"))
                             .clicked() {
                             action = NodeAction::CreateLink(canvas_pos);
                             ui.close_menu();
@@ -984,7 +1002,7 @@ impl MenuSystem {
         
         // Special nodes section
         ui.collapsing(
-            egui::RichText::new("Специальные элементы")
+            egui::RichText::new(language.tr("workflow.menu.special"))
                 .strong()
                 .size(16.0)
                 .color(egui::Color32::from_rgb(230, 150, 150)),
@@ -992,8 +1010,8 @@ impl MenuSystem {
                 ui.add_space(4.0);
                 
                 ui.horizontal(|ui| {
-                    if ui.add(Self::create_menu_button("❓ FAQ", "Shift+Q"))
-                        .on_hover_text("Часто задаваемый вопрос с ответом")
+                    if ui.add(Self::create_menu_button(language.tr("workflow.menu.faq"), "Shift+Q"))
+                        .on_hover_text(language.tr("workflow.menu.faq_hint"))
                         .clicked() {
                         action = NodeAction::CreateFAQ(canvas_pos);
                         ui.close_menu();
@@ -1004,8 +1022,8 @@ impl MenuSystem {
                 ui.add_space(8.0);
                 
                 ui.horizontal(|ui| {
-                    if ui.add(Self::create_menu_button("📞 Контакты", "Shift+C"))
-                        .on_hover_text("Контактная информация")
+                    if ui.add(Self::create_menu_button(language.tr("workflow.menu.contacts"), "Shift+C"))
+                        .on_hover_text(language.tr("workflow.menu.contacts_hint"))
                         .clicked() {
                         action = NodeAction::CreateContacts(canvas_pos);
                         ui.close_menu();
@@ -1060,15 +1078,17 @@ pub struct WorkflowEditorApp {
     node_system: NodeSystem,
     connection_system: ConnectionSystem,
     menu_system: MenuSystem,
+    /// Language of the context, nodes are created with titles in it
+    language: Language,
 }
 
 impl NodeFactory for WorkflowEditorApp {
     fn create_menu_button_node(&mut self, pos: egui::Pos2) -> usize {
-        self.node_system.create_node("Menu Button", pos, NodeType::MainMenu)
+        self.node_system.create_node(self.language.tr("workflow.node.main_menu"), pos, NodeType::MainMenu)
     }
     
     fn create_submenu_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Submenu", pos, NodeType::Submenu);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.submenu"), pos, NodeType::Submenu);
         self.node_system.customize_node(id, HashMap::from([
             ("callback_data".to_string(), "submenu".to_string()),
         ]));
@@ -1076,15 +1096,15 @@ impl NodeFactory for WorkflowEditorApp {
     }
     
     fn create_text_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Text", pos, NodeType::TextContent);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.text"), pos, NodeType::TextContent);
         self.node_system.customize_node(id, HashMap::from([
-            ("text".to_string(), "Содержимое текста...".to_string()),
+            ("text".to_string(), self.language.tr("workflow.node.text_content").to_string()),
         ]));
         id
     }
     
     fn create_image_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Image", pos, NodeType::Image);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.image"), pos, NodeType::Image);
         self.node_system.customize_node(id, HashMap::from([
             ("url".to_string(), "https://example.com/image.jpg".to_string()),
         ]));
@@ -1092,7 +1112,7 @@ impl NodeFactory for WorkflowEditorApp {
     }
     
     fn create_file_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("File", pos, NodeType::Document);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.document"), pos, NodeType::Document);
         self.node_system.customize_node(id, HashMap::from([
             ("url".to_string(), "https://example.com/document.pdf".to_string()),
         ]));
@@ -1100,21 +1120,21 @@ impl NodeFactory for WorkflowEditorApp {
     }
     
     fn create_link_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Link", pos, NodeType::Link);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.link"), pos, NodeType::Link);
         id
     }
     
     fn create_condition_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Condition", pos, NodeType::FAQ);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.condition"), pos, NodeType::FAQ);
         self.node_system.customize_node(id, HashMap::from([
-            ("question".to_string(), "Часто задаваемый вопрос?".to_string()),
-            ("answer".to_string(), "Ответ на вопрос...".to_string()),
+            ("question".to_string(), self.language.tr("workflow.node.question").to_string()),
+            ("answer".to_string(), self.language.tr("workflow.node.answer").to_string()),
         ]));
         id
     }
     
     fn create_action_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Action", pos, NodeType::Button);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.action"), pos, NodeType::Button);
         self.node_system.customize_node(id, HashMap::from([
             ("callback_data".to_string(), "button".to_string()),
         ]));
@@ -1122,14 +1142,14 @@ impl NodeFactory for WorkflowEditorApp {
     }
     
     fn create_faq_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("FAQ", pos, NodeType::FAQ);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.faq"), pos, NodeType::FAQ);
         id
     }
     
     fn create_custom_node(&mut self, pos: egui::Pos2) -> usize {
-        let id = self.node_system.create_node("Custom", pos, NodeType::Contacts);
+        let id = self.node_system.create_node(self.language.tr("workflow.node.custom"), pos, NodeType::Contacts);
         self.node_system.customize_node(id, HashMap::from([
-            ("address".to_string(), "Адрес организации".to_string()),
+            ("address".to_string(), self.language.tr("workflow.node.address").to_string()),
             ("phone".to_string(), "+7 (XXX) XXX-XX-XX".to_string()),
             ("email".to_string(), "example@example.com".to_string()),
         ]));
@@ -1144,6 +1164,7 @@ impl WorkflowEditorApp {
             node_system: NodeSystem::new(),
             connection_system: ConnectionSystem::new(),
             menu_system: MenuSystem::new(),
+            language: Language::default(),
         }
     }
     
@@ -1151,58 +1172,58 @@ impl WorkflowEditorApp {
         match action {
             NodeAction::None => {},
             NodeAction::CreateMainMenu(pos) => {
-                let id = self.node_system.create_node("Главное меню", pos, NodeType::MainMenu);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.main_menu"), pos, NodeType::MainMenu);
                 self.node_system.customize_node(id, HashMap::from([
                     ("callback_data".to_string(), "main_menu".to_string()),
                 ]));
             },
             NodeAction::CreateSubmenu(pos) => {
-                let id = self.node_system.create_node("Подменю", pos, NodeType::Submenu);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.submenu"), pos, NodeType::Submenu);
                 self.node_system.customize_node(id, HashMap::from([
                     ("callback_data".to_string(), "submenu".to_string()),
                 ]));
             },
             NodeAction::CreateButton(pos) => {
-                let id = self.node_system.create_node("Кнопка", pos, NodeType::Button);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.button"), pos, NodeType::Button);
                 self.node_system.customize_node(id, HashMap::from([
                     ("callback_data".to_string(), "button".to_string()),
                 ]));
             },
             NodeAction::CreateTextContent(pos) => {
-                let id = self.node_system.create_node("Текст", pos, NodeType::TextContent);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.text"), pos, NodeType::TextContent);
                 self.node_system.customize_node(id, HashMap::from([
-                    ("text".to_string(), "Содержимое текста...".to_string()),
+                    ("text".to_string(), self.language.tr("workflow.node.text_content").to_string()),
                 ]));
             },
             NodeAction::CreateImage(pos) => {
-                let id = self.node_system.create_node("Изображение", pos, NodeType::Image);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.image"), pos, NodeType::Image);
                 self.node_system.customize_node(id, HashMap::from([
                     ("url".to_string(), "https://example.com/image.jpg".to_string()),
                 ]));
             },
             NodeAction::CreateDocument(pos) => {
-                let id = self.node_system.create_node("Документ", pos, NodeType::Document);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.document"), pos, NodeType::Document);
                 self.node_system.customize_node(id, HashMap::from([
                     ("url".to_string(), "https://example.com/document.pdf".to_string()),
                 ]));
             },
             NodeAction::CreateLink(pos) => {
-                let id = self.node_system.create_node("Ссылка", pos, NodeType::Link);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.link"), pos, NodeType::Link);
                 self.node_system.customize_node(id, HashMap::from([
                     ("url".to_string(), "https://example.com".to_string()),
                 ]));
             },
             NodeAction::CreateFAQ(pos) => {
-                let id = self.node_system.create_node("FAQ", pos, NodeType::FAQ);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.faq"), pos, NodeType::FAQ);
                 self.node_system.customize_node(id, HashMap::from([
-                    ("question".to_string(), "Часто задаваемый вопрос?".to_string()),
-                    ("answer".to_string(), "Ответ на вопрос...".to_string()),
+                    ("question".to_string(), self.language.tr("workflow.node.question").to_string()),
+                    ("answer".to_string(), self.language.tr("workflow.node.answer").to_string()),
                 ]));
             },
             NodeAction::CreateContacts(pos) => {
-                let id = self.node_system.create_node("Контакты", pos, NodeType::Contacts);
+                let id = self.node_system.create_node(self.language.tr("workflow.node.contacts"), pos, NodeType::Contacts);
                 self.node_system.customize_node(id, HashMap::from([
-                    ("address".to_string(), "Адрес организации".to_string()),
+                    ("address".to_string(), self.language.tr("workflow.node.address").to_string()),
                     ("phone".to_string(), "+7 (XXX) XXX-XX-XX".to_string()),
                     ("email".to_string(), "example@example.com".to_string()),
                 ]));
@@ -1216,30 +1237,30 @@ impl WorkflowEditorApp {
     /// Creates a template menu structure at the given position
     fn create_template_menu(&mut self, pos: egui::Pos2) {
         // Create main menu
-        let main_menu_id = self.node_system.create_node("Главное меню бота", pos, NodeType::MainMenu);
+        let main_menu_id = self.node_system.create_node(self.language.tr("workflow.template.main_menu"), pos, NodeType::MainMenu);
         self.node_system.customize_node(main_menu_id, HashMap::from([
-            ("title".to_string(), "Меню бота".to_string()),
+            ("title".to_string(), self.language.tr("workflow.template.menu_title").to_string()),
         ]));
         
         // Create submenu
-        let submenu_id = self.node_system.create_node("Раздел меню", 
+        let submenu_id = self.node_system.create_node(self.language.tr("workflow.template.submenu"), 
                                pos + egui::vec2(250.0, -100.0), NodeType::Submenu);
         self.node_system.customize_node(submenu_id, HashMap::from([
             ("callback_data".to_string(), "submenu_1".to_string()),
         ]));
         
         // Create a button
-        let button_id = self.node_system.create_node("Кнопка",
+        let button_id = self.node_system.create_node(self.language.tr("workflow.template.button"),
                               pos + egui::vec2(250.0, 50.0), NodeType::Button);
         self.node_system.customize_node(button_id, HashMap::from([
             ("callback_data".to_string(), "button_1".to_string()),
         ]));
         
         // Create text content
-        let text_id = self.node_system.create_node("Текстовое сообщение",
+        let text_id = self.node_system.create_node(self.language.tr("workflow.template.text"),
                              pos + egui::vec2(500.0, 50.0), NodeType::TextContent);
         self.node_system.customize_node(text_id, HashMap::from([
-            ("text".to_string(), "Пример текстового сообщения от бота".to_string()),
+            ("text".to_string(), self.language.tr("workflow.template.text_content").to_string()),
         ]));
         
         // Create connections
@@ -1337,6 +1358,8 @@ impl eframe::App for WorkflowEditorApp {
             ctx.set_style(style);
         }
         
+        self.language = Language::current(ctx);
+        
         // Handle keyboard shortcuts
         self.handle_keyboard_shortcuts(ctx);
         
@@ -1353,7 +1376,7 @@ impl eframe::App for WorkflowEditorApp {
             ui.horizontal(|ui| {
                 ui.add_space(8.0);
                 ui.heading(
-                    egui::RichText::new("Редактор меню телеграм-бота")
+                    egui::RichText::new(self.language.tr("workflow.title"))
                         .size(20.0)
                         .color(egui::Color32::from_rgb(220, 220, 255))
                         .strong()
@@ -1364,7 +1387,7 @@ impl eframe::App for WorkflowEditorApp {
                 
                 if ui.add(
                     egui::Button::new(
-                        egui::RichText::new("➕ Добавить элемент")
+                        egui::RichText::new(self.language.tr("workflow.add_element"))
                             .size(16.0)
                             .color(egui::Color32::from_rgb(230, 230, 230))
                     )
@@ -1383,7 +1406,7 @@ impl eframe::App for WorkflowEditorApp {
                 
                 if ui.add(
                     egui::Button::new(
-                        egui::RichText::new("❓ Справка")
+                        egui::RichText::new(self.language.tr("workflow.help"))
                             .size(16.0)
                             .color(egui::Color32::from_rgb(230, 230, 230))
                     )
@@ -1401,7 +1424,7 @@ impl eframe::App for WorkflowEditorApp {
                     ui.separator();
                     ui.add_space(16.0);
                     ui.label(
-                        egui::RichText::new(format!("Выбрано: {}", selected_count))
+                        egui::RichText::new(self.language.tr_args("workflow.selected", &[("count", &selected_count)]))
                             .size(16.0)
                             .color(egui::Color32::from_rgb(200, 200, 255))
                     );
@@ -1409,7 +1432,7 @@ impl eframe::App for WorkflowEditorApp {
                     
                     if ui.add(
                         egui::Button::new(
-                            egui::RichText::new("🗑 Удалить")
+                            egui::RichText::new(self.language.tr("workflow.delete"))
                                 .size(16.0)
                                 .color(egui::Color32::from_rgb(255, 200, 200))
                         )
@@ -1425,10 +1448,26 @@ impl eframe::App for WorkflowEditorApp {
                 }
                 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add_space(8.0);
+                    let mut language = self.language;
+                    egui::ComboBox::from_id_source("language")
+                        .selected_text(language.label())
+                        .show_ui(ui, |ui| {
+                            for option in Language::ALL {
+                                ui.selectable_value(&mut language, option, option.label());
+                            }
+                        })
+                        .response
+                        .on_hover_text(self.language.tr("workflow.language"));
+                    if language != self.language {
+                        language.apply(ctx);
+                        self.language = language;
+                    }
+                    
                     ui.add_space(8.0);
                     if ui.add(
                         egui::Button::new(
-                            egui::RichText::new("📥 Импорт JSON")
+                            egui::RichText::new(self.language.tr("workflow.import_json"))
                                 .size(16.0)
                                 .color(egui::Color32::from_rgb(230, 230, 230))
                         )
@@ -1443,7 +1482,7 @@ impl eframe::App for WorkflowEditorApp {
                     ui.add_space(8.0);
                     if ui.add(
                         egui::Button::new(
-                            egui::RichText::new("📤 Экспорт JSON")
+                            egui::RichText::new(self.language.tr("workflow.export_json"))
                                 .size(16.0)
                                 .color(egui::Color32::from_rgb(230, 230, 230))
                         )
@@ -1463,23 +1502,23 @@ impl eframe::App for WorkflowEditorApp {
         // Status bar
         egui::TopBottomPanel::bottom("bottom_panel").show(ctx, |ui| {
             ui.horizontal(|ui| {
-                ui.label(format!("Узлов: {}", self.node_system.nodes.len()));
+                ui.label(self.language.tr_args("workflow.nodes", &[("count", &self.node_system.nodes.len())]));
                 ui.separator();
-                ui.label(format!("Соединений: {}", self.connection_system.connections.len()));
+                ui.label(self.language.tr_args("workflow.connections", &[("count", &self.connection_system.connections.len())]));
                 ui.separator();
                 
                 // Display zoom level
-                ui.label(format!("Масштаб: {}%", (self.canvas.scale * 100.0) as i32));
+                ui.label(self.language.tr_args("workflow.zoom", &[("percent", &((self.canvas.scale * 100.0) as i32))]));
                 
                 // Show selection status if nodes are selected
                 let selected_count = self.node_system.get_selected_node_count();
                 if selected_count > 0 {
                     ui.separator();
-                    ui.label(format!("Выбрано: {}", selected_count));
+                    ui.label(self.language.tr_args("workflow.selected", &[("count", &selected_count)]));
                 }
                 
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    ui.label("Ctrl+клик: мульти-выбор   Shift+клик: диапазон   Del: удалить");
+                    ui.label(self.language.tr("workflow.selection_hint"));
                 });
             });
         });
@@ -1551,7 +1590,7 @@ pub fn start_app(canvas_id: &str) -> Result<(), JsValue> {
             .start(
                 &canvas_id,
                 web_options,
-                Box::new(|cc| Box::new(WorkflowEditorApp::new(cc))),
+                Box::new(|cc| Box::new(ui::MenuEditorApp::new(cc))),
             )
             .await
             .expect("failed to start eframe");
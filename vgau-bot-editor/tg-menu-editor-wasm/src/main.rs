@@ -1,4 +1,5 @@
-use tg_menu_editor_wasm::WorkflowEditorApp;
+use tg_menu_editor_wasm::i18n::Language;
+use tg_menu_editor_wasm::ui::MenuEditorApp;
 
 #[cfg(not(target_arch = "wasm32"))]
 fn main() {
@@ -10,10 +11,11 @@ fn main() {
         ..Default::default()
     };
 
+    // The name also locates the stored settings, so it stays the same in every language
     eframe::run_native(
-        "ВГАУ Бот Меню Редактор",
+        Language::default().tr("app.title"),
         native_options,
-        Box::new(|cc| Box::new(WorkflowEditorApp::new(cc))),
+        Box::new(|cc| Box::new(MenuEditorApp::new(cc))),
     ).unwrap();
 }
 
@@ -0,0 +1,83 @@
+use serde_json::Value;
+
+use crate::bot_config::{CURRENT_SCHEMA_VERSION, LEGACY_SCHEMA_VERSION};
+
+/// A single upgrade step from one schema version to the next
+type Migration = fn(&mut Value) -> Result<(), String>;
+
+/// Migration chain, indexed by the version each step upgrades from
+const MIGRATIONS: &[(u32, Migration)] = &[
+    (1, migrate_v1_to_v2),
+    (2, migrate_v2_to_v3),
+];
+
+/// Read the schema version of raw bot data, documents without one are legacy
+pub fn schema_version_of(value: &Value) -> Result<u32, String> {
+    match value.get("schema_version") {
+        None => Ok(LEGACY_SCHEMA_VERSION),
+        Some(version) => version.as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| format!("schema_version must be a positive integer, got {}", version)),
+    }
+}
+
+/// Upgrade raw bot data step by step until it matches the current schema
+pub fn migrate_to_current(mut value: Value) -> Result<Value, String> {
+    if !value.is_object() {
+        return Err("Bot data must be a JSON object".to_string());
+    }
+
+    let mut version = schema_version_of(&value)?;
+
+    if version > CURRENT_SCHEMA_VERSION {
+        return Err(format!(
+            "Unsupported schema_version {}: this editor understands versions up to {}",
+            version, CURRENT_SCHEMA_VERSION
+        ));
+    }
+
+    while version < CURRENT_SCHEMA_VERSION {
+        let (_, migration) = MIGRATIONS.iter()
+            .find(|(from, _)| *from == version)
+            .ok_or_else(|| format!("No migration from schema_version {}", version))?;
+
+        migration(&mut value)?;
+        version += 1;
+        value["schema_version"] = Value::from(version);
+    }
+
+    Ok(value)
+}
+
+/// v1 -> v2: the first versioned format.
+///
+/// Unversioned files could omit `faq` entirely and some hand-written ones kept
+/// FAQ tags as a comma-separated string, v2 always has arrays for both.
+fn migrate_v1_to_v2(value: &mut Value) -> Result<(), String> {
+    let root = value.as_object_mut()
+        .ok_or_else(|| "Bot data must be a JSON object".to_string())?;
+
+    let faq = root.entry("faq").or_insert_with(|| Value::Array(Vec::new()));
+
+    for item in faq.as_array_mut().into_iter().flatten() {
+        if let Some(tags) = item.get("tags").and_then(|t| t.as_str()) {
+            let tags: Vec<Value> = tags.split(',')
+                .map(|s| s.trim())
+                .filter(|s| !s.is_empty())
+                .map(Value::from)
+                .collect();
+            item["tags"] = Value::Array(tags);
+        }
+    }
+
+    Ok(())
+}
+
+/// v2 -> v3: menu items may carry `images`, `links`, `messages` and `contacts`.
+///
+/// All of them are optional, so v2 data is already valid v3 and only the
+/// version number changes. The bump makes older editors report an unsupported
+/// version instead of a list of unknown fields.
+fn migrate_v2_to_v3(_value: &mut Value) -> Result<(), String> {
+    Ok(())
+}
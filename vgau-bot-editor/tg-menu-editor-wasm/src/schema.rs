@@ -0,0 +1,335 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::bot_config::BotMenuConfig;
+
+/// A single problem found in imported bot data
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+    /// JSON pointer to the offending value, e.g. `/main_menu/0/submenu/3/documents/2/url`
+    pub pointer: String,
+    pub message: String,
+    /// 1-based line in the source text, if it could be located
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pointer = if self.pointer.is_empty() { "/" } else { &self.pointer };
+        match self.line {
+            Some(line) => write!(f, "{} (line {}): {}", pointer, line, self.message),
+            None => write!(f, "{}: {}", pointer, self.message),
+        }
+    }
+}
+
+/// JSON Schema of bot_data.json, generated from [`BotMenuConfig`]
+pub fn bot_data_schema() -> Value {
+    serde_json::to_value(schemars::schema_for!(BotMenuConfig))
+        .expect("generated schema is always valid JSON")
+}
+
+/// JSON Schema of bot_data.json as pretty-printed text, for use outside the editor
+pub fn bot_data_schema_json() -> Result<String, String> {
+    serde_json::to_string_pretty(&bot_data_schema())
+        .map_err(|e| format!("Error serializing schema: {}", e))
+}
+
+/// Check bot data against the generated schema and collect every problem
+pub fn validate_bot_data(value: &Value) -> Vec<ValidationError> {
+    let schema = bot_data_schema();
+    let mut errors = Vec::new();
+    Validator { root: &schema }.check(&schema, value, "", &mut errors);
+    errors
+}
+
+/// Fill in source lines for errors found in `source`
+pub fn locate_errors(source: &str, errors: &mut [ValidationError]) {
+    for error in errors.iter_mut() {
+        if error.line.is_none() {
+            error.line = line_for_pointer(source, &error.pointer);
+        }
+    }
+}
+
+/// Find the line a JSON pointer refers to in the source text.
+///
+/// Pointers to values that are missing in the text resolve to the closest
+/// parent that exists, so a missing field points at its object.
+pub fn line_for_pointer(source: &str, pointer: &str) -> Option<usize> {
+    let mut lines = Vec::new();
+    let mut scanner = LineScanner { bytes: source.as_bytes(), pos: 0, line: 1 };
+    scanner.value(String::new(), &mut lines).ok()?;
+    
+    let mut target = pointer;
+    loop {
+        if let Some((_, line)) = lines.iter().find(|(p, _)| p == target) {
+            return Some(*line);
+        }
+        target = &target[..target.rfind('/')?];
+    }
+}
+
+/// Escape one segment of a JSON pointer
+fn escape_segment(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_i64() || n.is_u64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn matches_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "number" => value.is_number(),
+        "integer" => value.as_i64().is_some() || value.as_u64().is_some(),
+        other => type_name(value) == other,
+    }
+}
+
+/// Validator for the subset of JSON Schema that schemars produces for our types
+struct Validator<'a> {
+    root: &'a Value,
+}
+
+impl<'a> Validator<'a> {
+    fn resolve(&self, reference: &str) -> Option<&'a Value> {
+        let name = reference.strip_prefix("#/definitions/")?;
+        self.root.get("definitions")?.get(name)
+    }
+    
+    fn check(&self, schema: &'a Value, value: &Value, pointer: &str, errors: &mut Vec<ValidationError>) {
+        let schema = match schema {
+            Value::Object(schema) => schema,
+            // `true` and `false` schemas
+            Value::Bool(accept) => {
+                if !accept {
+                    self.error(errors, pointer, "no value is allowed here".to_string());
+                }
+                return;
+            },
+            _ => return,
+        };
+        
+        if let Some(target) = schema.get("$ref").and_then(|r| r.as_str()).and_then(|r| self.resolve(r)) {
+            self.check(target, value, pointer, errors);
+        }
+        
+        if let Some(all_of) = schema.get("allOf").and_then(|a| a.as_array()) {
+            for part in all_of {
+                self.check(part, value, pointer, errors);
+            }
+        }
+        
+        if let Some(any_of) = schema.get("anyOf").and_then(|a| a.as_array()) {
+            self.check_any_of(any_of, value, pointer, errors);
+        }
+        
+        if let Some(expected) = schema.get("type") {
+            let allowed: Vec<&str> = match expected {
+                Value::String(t) => vec![t.as_str()],
+                Value::Array(types) => types.iter().filter_map(|t| t.as_str()).collect(),
+                _ => Vec::new(),
+            };
+            
+            if !allowed.is_empty() && !allowed.iter().any(|t| matches_type(value, t)) {
+                self.error(errors, pointer, format!("expected {}, found {}", allowed.join(" or "), type_name(value)));
+                // Nested checks only make sense for the right type
+                return;
+            }
+        }
+        
+        if let (Some(minimum), Some(number)) = (schema.get("minimum").and_then(|m| m.as_f64()), value.as_f64()) {
+            if number < minimum {
+                self.error(errors, pointer, format!("must be at least {}", minimum));
+            }
+        }
+        
+        match value {
+            Value::Object(object) => self.check_object(schema, object, pointer, errors),
+            Value::Array(items) => {
+                if let Some(item_schema) = schema.get("items") {
+                    for (index, item) in items.iter().enumerate() {
+                        self.check(item_schema, item, &format!("{}/{}", pointer, index), errors);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+    
+    fn check_object(&self, schema: &'a Map<String, Value>, object: &Map<String, Value>, pointer: &str, errors: &mut Vec<ValidationError>) {
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        
+        for field in schema.get("required").and_then(|r| r.as_array()).into_iter().flatten() {
+            if let Some(field) = field.as_str() {
+                if !object.contains_key(field) {
+                    self.error(errors, pointer, format!("missing required field `{}`", field));
+                }
+            }
+        }
+        
+        for (key, item) in object {
+            let item_pointer = format!("{}/{}", pointer, escape_segment(key));
+            match properties.and_then(|p| p.get(key)) {
+                Some(property) => self.check(property, item, &item_pointer, errors),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        self.error(errors, &item_pointer, format!("unknown field `{}`", key));
+                    },
+                    Some(additional) => self.check(additional, item, &item_pointer, errors),
+                    None => {}
+                },
+            }
+        }
+    }
+    
+    /// Accept the value if any branch does, otherwise report the closest branch
+    fn check_any_of(&self, branches: &'a [Value], value: &Value, pointer: &str, errors: &mut Vec<ValidationError>) {
+        let mut best: Option<Vec<ValidationError>> = None;
+        
+        for branch in branches {
+            let mut branch_errors = Vec::new();
+            self.check(branch, value, pointer, &mut branch_errors);
+            if branch_errors.is_empty() {
+                return;
+            }
+            if best.as_ref().is_none_or(|b| branch_errors.len() < b.len()) {
+                best = Some(branch_errors);
+            }
+        }
+        
+        errors.extend(best.unwrap_or_default());
+    }
+    
+    fn error(&self, errors: &mut Vec<ValidationError>, pointer: &str, message: String) {
+        errors.push(ValidationError {
+            pointer: pointer.to_string(),
+            message,
+            line: None,
+        });
+    }
+}
+
+/// Minimal JSON scanner that records the line where every value starts
+struct LineScanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+}
+
+impl<'a> LineScanner<'a> {
+    fn skip_whitespace(&mut self) {
+        while let Some(&b) = self.bytes.get(self.pos) {
+            match b {
+                b'\n' => self.line += 1,
+                b' ' | b'\t' | b'\r' => {},
+                _ => break,
+            }
+            self.pos += 1;
+        }
+    }
+    
+    fn expect(&mut self, byte: u8) -> Result<(), ()> {
+        self.skip_whitespace();
+        if self.bytes.get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(())
+        }
+    }
+    
+    fn value(&mut self, pointer: String, lines: &mut Vec<(String, usize)>) -> Result<(), ()> {
+        self.skip_whitespace();
+        lines.push((pointer.clone(), self.line));
+        
+        match self.bytes.get(self.pos).ok_or(())? {
+            b'{' => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.bytes.get(self.pos) == Some(&b'}') {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                loop {
+                    self.skip_whitespace();
+                    let key_line = self.line;
+                    let key = self.string()?;
+                    self.expect(b':')?;
+                    let item_pointer = format!("{}/{}", pointer, escape_segment(&key));
+                    // Point at the key, the value may start on a later line
+                    lines.push((item_pointer.clone(), key_line));
+                    self.value(item_pointer, lines)?;
+                    self.skip_whitespace();
+                    match self.bytes.get(self.pos) {
+                        Some(b',') => self.pos += 1,
+                        Some(b'}') => { self.pos += 1; return Ok(()); },
+                        _ => return Err(()),
+                    }
+                }
+            },
+            b'[' => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.bytes.get(self.pos) == Some(&b']') {
+                    self.pos += 1;
+                    return Ok(());
+                }
+                let mut index = 0;
+                loop {
+                    self.value(format!("{}/{}", pointer, index), lines)?;
+                    index += 1;
+                    self.skip_whitespace();
+                    match self.bytes.get(self.pos) {
+                        Some(b',') => self.pos += 1,
+                        Some(b']') => { self.pos += 1; return Ok(()); },
+                        _ => return Err(()),
+                    }
+                }
+            },
+            b'"' => self.string().map(|_| ()),
+            _ => {
+                // Numbers, booleans and null: everything up to the next delimiter
+                while let Some(&b) = self.bytes.get(self.pos) {
+                    if matches!(b, b',' | b'}' | b']') || b.is_ascii_whitespace() {
+                        break;
+                    }
+                    self.pos += 1;
+                }
+                Ok(())
+            },
+        }
+    }
+    
+    fn string(&mut self) -> Result<String, ()> {
+        if self.bytes.get(self.pos) != Some(&b'"') {
+            return Err(());
+        }
+        let start = self.pos;
+        self.pos += 1;
+        
+        while let Some(&b) = self.bytes.get(self.pos) {
+            match b {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    let raw = std::str::from_utf8(&self.bytes[start..self.pos]).map_err(|_| ())?;
+                    return serde_json::from_str(raw).map_err(|_| ());
+                },
+                _ => self.pos += 1,
+            }
+        }
+        
+        Err(())
+    }
+}
@@ -0,0 +1,92 @@
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Language;
+use crate::theme::Theme;
+
+/// Key the settings are stored under, in localStorage on the web
+/// and in the eframe storage file on native builds
+pub const STORAGE_KEY: &str = "editor_settings";
+
+/// How connections between nodes are drawn
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConnectionStyle {
+    #[default]
+    Bezier,
+    Straight,
+}
+
+impl ConnectionStyle {
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            ConnectionStyle::Bezier => "connection_style.bezier",
+            ConnectionStyle::Straight => "connection_style.straight",
+        })
+    }
+}
+
+/// Preferences of the editor itself, kept between sessions.
+///
+/// Fields missing in stored settings take their default value, so settings
+/// saved by an older version still load.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EditorSettings {
+    pub language: Language,
+    pub theme: Theme,
+    /// Scale of the whole interface, 1.0 is 100%
+    pub ui_scale: f32,
+    /// Whether the grid is drawn behind the nodes
+    pub show_grid: bool,
+    /// Whether dragged nodes snap to the grid
+    pub snap_to_grid: bool,
+    pub connection_style: ConnectionStyle,
+    /// Number of undo steps kept
+    pub history_depth: usize,
+}
+
+impl Default for EditorSettings {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            theme: Theme::default(),
+            ui_scale: 1.0,
+            show_grid: true,
+            snap_to_grid: false,
+            connection_style: ConnectionStyle::default(),
+            history_depth: 30,
+        }
+    }
+}
+
+impl EditorSettings {
+    /// Interface scales offered in the settings tab
+    pub const UI_SCALE_RANGE: RangeInclusive<f32> = 0.75..=2.0;
+    /// Step of the font size buttons
+    pub const UI_SCALE_STEP: f32 = 0.125;
+    pub const HISTORY_DEPTH_RANGE: RangeInclusive<usize> = 5..=200;
+    
+    /// Settings saved in `storage`, or the defaults when there are none
+    pub fn load(storage: Option<&dyn eframe::Storage>) -> Self {
+        storage
+            .and_then(|storage| eframe::get_value::<Self>(storage, STORAGE_KEY))
+            .unwrap_or_default()
+            .sanitized()
+    }
+    
+    pub fn save(&self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, STORAGE_KEY, self);
+    }
+    
+    /// The settings with every value moved into the range the editor supports
+    pub fn sanitized(mut self) -> Self {
+        self.ui_scale = if self.ui_scale.is_finite() {
+            self.ui_scale.clamp(*Self::UI_SCALE_RANGE.start(), *Self::UI_SCALE_RANGE.end())
+        } else {
+            1.0
+        };
+        self.history_depth = self.history_depth.clamp(*Self::HISTORY_DEPTH_RANGE.start(), *Self::HISTORY_DEPTH_RANGE.end());
+        self
+    }
+}
@@ -0,0 +1,319 @@
+use eframe::egui;
+use egui::Color32;
+use serde::{Deserialize, Serialize};
+
+use crate::graph::models::{NodeType, PortType};
+use crate::i18n::Language;
+
+/// Colours the editor is drawn with: panels and widgets, the canvas,
+/// node types, port types and connections
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    /// Whether the palette is light text on a dark background
+    pub dark: bool,
+    /// Background of the canvas and the central panel
+    pub canvas: Color32,
+    /// Side panels, windows and menus
+    pub panel: Color32,
+    /// Toolbars and frames raised above a panel
+    pub surface: Color32,
+    /// Borders of panels, frames and buttons
+    pub border: Color32,
+    pub text: Color32,
+    /// Headings and text that has to stand out
+    pub text_strong: Color32,
+    /// Hints and secondary text
+    pub text_muted: Color32,
+    /// Idle buttons
+    pub button: Color32,
+    /// Buttons being pressed, selected items and links
+    pub accent: Color32,
+    /// Buttons under the pointer
+    pub accent_hover: Color32,
+    pub error: Color32,
+    pub warning: Color32,
+    pub info: Color32,
+    pub success: Color32,
+    pub grid_minor: Color32,
+    pub grid_major: Color32,
+    /// Frame of nodes that are not selected
+    pub node_frame: Color32,
+    /// Frame of selected nodes, the rubber band and the minimap viewport
+    pub selection: Color32,
+    /// Alignment guides shown while dragging
+    pub guide: Color32,
+    /// Connection being dragged from a port
+    pub connection_pending: Color32,
+    /// Connection being dragged over a port it can be connected to
+    pub connection_valid: Color32,
+    /// Connection being dragged over a port of another type
+    pub connection_invalid: Color32,
+    /// Width of connections at 100% zoom
+    pub connection_width: f32,
+    /// Fills of the node types, in the order of `NodeType`
+    nodes: [Color32; 10],
+    /// Colours of the port types, in the order of `PortType`.
+    /// Connections take the colour of their output port.
+    ports: [Color32; 4],
+}
+
+impl Palette {
+    pub fn node_color(&self, node_type: &NodeType) -> Color32 {
+        let index = match node_type {
+            NodeType::MenuItem => 0,
+            NodeType::FaqItem => 1,
+            NodeType::Process => 2,
+            NodeType::Input => 3,
+            NodeType::Output => 4,
+            NodeType::Document => 5,
+            NodeType::Image => 6,
+            NodeType::Link => 7,
+            NodeType::TextContent => 8,
+            NodeType::Contacts => 9,
+        };
+        self.nodes[index]
+    }
+    
+    pub fn port_color(&self, port_type: &PortType) -> Color32 {
+        let index = match port_type {
+            PortType::String => 0,
+            PortType::Number => 1,
+            PortType::Object => 2,
+            PortType::Action => 3,
+        };
+        self.ports[index]
+    }
+    
+    /// Black or white, whichever is easier to read on `fill`
+    pub fn text_on(fill: Color32) -> Color32 {
+        let linear = egui::Rgba::from(fill);
+        let luminance = 0.2126 * linear.r() + 0.7152 * linear.g() + 0.0722 * linear.b();
+        if luminance > 0.18 {
+            Color32::BLACK
+        } else {
+            Color32::WHITE
+        }
+    }
+}
+
+/// The original look, Tailwind grays with blue accents
+const DARK: Palette = Palette {
+    dark: true,
+    canvas: Color32::from_rgb(17, 24, 39), // gray-900
+    panel: Color32::from_rgb(31, 41, 55), // gray-800
+    surface: Color32::from_rgb(55, 65, 81), // gray-700
+    border: Color32::from_rgb(75, 85, 99), // gray-600
+    text: Color32::from_rgb(209, 213, 219), // gray-300
+    text_strong: Color32::WHITE,
+    text_muted: Color32::from_rgb(156, 163, 175), // gray-400
+    button: Color32::from_rgb(30, 58, 138), // blue-900
+    accent: Color32::from_rgb(59, 130, 246), // blue-500
+    accent_hover: Color32::from_rgb(96, 165, 250), // blue-400
+    error: Color32::from_rgb(239, 68, 68), // red-500
+    warning: Color32::from_rgb(245, 158, 11), // amber-500
+    info: Color32::from_rgb(59, 130, 246), // blue-500
+    success: Color32::from_rgb(134, 239, 172), // green-300
+    grid_minor: Color32::from_gray(40),
+    grid_major: Color32::from_gray(60),
+    node_frame: Color32::from_gray(180),
+    selection: Color32::WHITE,
+    guide: Color32::from_rgb(236, 72, 153), // pink-500
+    connection_pending: Color32::from_rgba_premultiplied(128, 128, 128, 128),
+    connection_valid: Color32::from_rgba_premultiplied(78, 200, 78, 200),
+    connection_invalid: Color32::from_rgba_premultiplied(200, 78, 78, 200),
+    connection_width: 2.0,
+    nodes: [
+        Color32::from_rgb(100, 150, 200),
+        Color32::from_rgb(200, 150, 100),
+        Color32::from_rgb(150, 200, 100),
+        Color32::from_rgb(100, 200, 150),
+        Color32::from_rgb(200, 100, 150),
+        Color32::from_rgb(100, 100, 200),
+        Color32::from_rgb(200, 60, 60),
+        Color32::from_rgb(120, 80, 200),
+        Color32::from_rgb(40, 140, 40),
+        Color32::from_rgb(100, 50, 180),
+    ],
+    ports: [
+        Color32::from_rgb(200, 200, 100),
+        Color32::from_rgb(100, 200, 200),
+        Color32::from_rgb(150, 200, 255),
+        Color32::from_rgb(255, 150, 150),
+    ],
+};
+
+/// Dark text on white with pastel nodes, for bright rooms
+const LIGHT: Palette = Palette {
+    dark: false,
+    canvas: Color32::from_rgb(249, 250, 251), // gray-50
+    panel: Color32::WHITE,
+    surface: Color32::from_rgb(243, 244, 246), // gray-100
+    border: Color32::from_rgb(209, 213, 219), // gray-300
+    text: Color32::from_rgb(55, 65, 81), // gray-700
+    text_strong: Color32::from_rgb(17, 24, 39), // gray-900
+    text_muted: Color32::from_rgb(107, 114, 128), // gray-500
+    button: Color32::from_rgb(229, 231, 235), // gray-200
+    accent: Color32::from_rgb(59, 130, 246), // blue-500
+    accent_hover: Color32::from_rgb(147, 197, 253), // blue-300
+    error: Color32::from_rgb(220, 38, 38), // red-600
+    warning: Color32::from_rgb(217, 119, 6), // amber-600
+    info: Color32::from_rgb(37, 99, 235), // blue-600
+    success: Color32::from_rgb(21, 128, 61), // green-700
+    grid_minor: Color32::from_rgb(229, 231, 235), // gray-200
+    grid_major: Color32::from_rgb(209, 213, 219), // gray-300
+    node_frame: Color32::from_rgb(156, 163, 175), // gray-400
+    selection: Color32::from_rgb(37, 99, 235), // blue-600
+    guide: Color32::from_rgb(219, 39, 119), // pink-600
+    connection_pending: Color32::from_rgb(107, 114, 128), // gray-500
+    connection_valid: Color32::from_rgb(22, 163, 74), // green-600
+    connection_invalid: Color32::from_rgb(220, 38, 38), // red-600
+    connection_width: 2.0,
+    nodes: [
+        Color32::from_rgb(147, 197, 253), // blue-300
+        Color32::from_rgb(253, 186, 116), // orange-300
+        Color32::from_rgb(190, 242, 100), // lime-300
+        Color32::from_rgb(110, 231, 183), // emerald-300
+        Color32::from_rgb(249, 168, 212), // pink-300
+        Color32::from_rgb(165, 180, 252), // indigo-300
+        Color32::from_rgb(252, 165, 165), // red-300
+        Color32::from_rgb(196, 181, 253), // violet-300
+        Color32::from_rgb(134, 239, 172), // green-300
+        Color32::from_rgb(216, 180, 254), // purple-300
+    ],
+    ports: [
+        Color32::from_rgb(202, 138, 4), // yellow-600
+        Color32::from_rgb(8, 145, 178), // cyan-600
+        Color32::from_rgb(37, 99, 235), // blue-600
+        Color32::from_rgb(220, 38, 38), // red-600
+    ],
+};
+
+/// White on black with the Okabe–Ito colours, which stay distinct with
+/// every common kind of colour blindness and on washed-out projectors
+const HIGH_CONTRAST: Palette = Palette {
+    dark: true,
+    canvas: Color32::BLACK,
+    panel: Color32::from_gray(16),
+    surface: Color32::from_gray(36),
+    border: Color32::WHITE,
+    text: Color32::WHITE,
+    text_strong: Color32::WHITE,
+    text_muted: Color32::from_gray(200),
+    button: Color32::from_gray(36),
+    accent: Color32::from_rgb(0, 114, 178), // blue
+    accent_hover: Color32::from_rgb(0, 84, 132), // darker blue, white text stays readable
+    error: Color32::from_rgb(213, 94, 0), // vermillion
+    warning: Color32::from_rgb(230, 159, 0), // orange
+    info: Color32::from_rgb(86, 180, 233), // sky blue
+    success: Color32::from_rgb(0, 158, 115), // bluish green
+    grid_minor: Color32::from_gray(45),
+    grid_major: Color32::from_gray(90),
+    node_frame: Color32::WHITE,
+    selection: Color32::from_rgb(240, 228, 66), // yellow
+    guide: Color32::from_rgb(204, 121, 167), // reddish purple
+    connection_pending: Color32::WHITE,
+    connection_valid: Color32::from_rgb(0, 158, 115), // bluish green
+    connection_invalid: Color32::from_rgb(213, 94, 0), // vermillion
+    connection_width: 3.0,
+    nodes: [
+        Color32::from_rgb(86, 180, 233), // sky blue
+        Color32::from_rgb(230, 159, 0), // orange
+        Color32::from_gray(170),
+        Color32::from_gray(220),
+        Color32::from_gray(120),
+        Color32::from_rgb(0, 114, 178), // blue
+        Color32::from_rgb(213, 94, 0), // vermillion
+        Color32::from_rgb(204, 121, 167), // reddish purple
+        Color32::from_rgb(0, 158, 115), // bluish green
+        Color32::from_rgb(240, 228, 66), // yellow
+    ],
+    ports: [
+        Color32::from_rgb(240, 228, 66), // yellow
+        Color32::from_rgb(86, 180, 233), // sky blue
+        Color32::WHITE,
+        Color32::from_rgb(213, 94, 0), // vermillion
+    ],
+};
+
+/// Colour scheme of the editor
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Theme {
+    #[default]
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::HighContrast];
+    
+    pub fn label(&self, language: Language) -> &'static str {
+        language.tr(match self {
+            Theme::Dark => "theme.dark",
+            Theme::Light => "theme.light",
+            Theme::HighContrast => "theme.high_contrast",
+        })
+    }
+    
+    pub fn palette(&self) -> &'static Palette {
+        match self {
+            Theme::Dark => &DARK,
+            Theme::Light => &LIGHT,
+            Theme::HighContrast => &HIGH_CONTRAST,
+        }
+    }
+    
+    /// egui visuals built from the palette, with the rounding the editor uses everywhere
+    pub fn visuals(&self) -> egui::Visuals {
+        let palette = self.palette();
+        let mut visuals = if palette.dark {
+            egui::Visuals::dark()
+        } else {
+            egui::Visuals::light()
+        };
+        
+        visuals.panel_fill = palette.panel;
+        visuals.window_fill = palette.panel;
+        visuals.window_stroke = egui::Stroke::new(1.0, palette.border);
+        visuals.extreme_bg_color = palette.canvas;
+        visuals.faint_bg_color = palette.surface;
+        visuals.hyperlink_color = palette.accent;
+        visuals.error_fg_color = palette.error;
+        visuals.warn_fg_color = palette.warning;
+        visuals.selection.bg_fill = palette.accent;
+        
+        visuals.widgets.noninteractive.bg_fill = palette.panel;
+        visuals.widgets.noninteractive.bg_stroke = egui::Stroke::new(1.0, palette.border);
+        visuals.widgets.noninteractive.fg_stroke = egui::Stroke::new(1.0, palette.text);
+        visuals.widgets.inactive.bg_fill = palette.button;
+        visuals.widgets.inactive.weak_bg_fill = palette.button;
+        visuals.widgets.hovered.bg_fill = palette.accent_hover;
+        visuals.widgets.hovered.weak_bg_fill = palette.accent_hover;
+        visuals.widgets.active.bg_fill = palette.accent;
+        visuals.widgets.active.weak_bg_fill = palette.accent;
+        if *self == Theme::HighContrast {
+            // Outlined buttons with white text, the defaults are too faint on a projector
+            for widget in [&mut visuals.widgets.inactive, &mut visuals.widgets.hovered, &mut visuals.widgets.active] {
+                widget.bg_stroke = egui::Stroke::new(1.0, palette.border);
+                widget.fg_stroke = egui::Stroke::new(1.5, palette.text_strong);
+            }
+        }
+        
+        visuals.window_rounding = egui::Rounding::same(8.0);
+        visuals.window_shadow.extrusion = 8.0;
+        visuals.menu_rounding = egui::Rounding::same(6.0);
+        visuals
+    }
+    
+    /// Make this the theme of `ctx`: its visuals and the palette the graph
+    /// and the panels are drawn with
+    pub fn apply(&self, ctx: &egui::Context) {
+        ctx.set_visuals(self.visuals());
+        ctx.memory_mut(|mem| mem.data.insert_temp(egui::Id::new("editor_theme"), *self));
+    }
+    
+    /// Theme applied to `ctx` last, the dark one before any was applied
+    pub fn current(ctx: &egui::Context) -> Self {
+        ctx.memory(|mem| mem.data.get_temp::<Self>(egui::Id::new("editor_theme"))).unwrap_or_default()
+    }
+}
@@ -1,95 +1,85 @@
 use eframe::egui;
-use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use wasm_bindgen::prelude::*;
 
-use crate::graph::SimpleNodeGraph;
-use crate::import_data::DataImporter;
-use crate::ui::components::*;
+use crate::graph::{SimpleNodeGraph, ProjectFile};
+use crate::graph::project::PROJECT_FILE_EXTENSION;
+use crate::graph::rendering::export::{ExportScope, GraphImage, ImageFormat};
+use crate::bot_config::{BotMenuConfig, DEFAULT_MENU_TITLE};
+use crate::export_data::DataExporter;
+use crate::i18n::Language;
+use crate::import_data::{DataImporter, ImportMode, MergePlan, ConflictKind, ConflictResolution};
+use crate::schema::ValidationError;
+use crate::settings::EditorSettings;
+use crate::theme::Theme;
+use super::diagnostics_panel::DiagnosticsPanel;
+use super::inspector_panel::InspectorPanel;
+use super::outline_panel::OutlinePanel;
+use super::menu_bar::MenuBar;
+use super::search_palette::SearchPalette;
+use super::status_bar::StatusBar;
+use super::tabs::{ExportView, Tab, Tabs};
+use super::utils::now_iso;
 
-mod menu_bar;
-mod status_bar;
-mod tabs;
-mod utils;
+pub use crate::graph::WorkflowMetadata;
 
-use menu_bar::MenuBar;
-use status_bar::StatusBar;
-use tabs::{Tab, Tabs};
-use utils::apply_tailwind_style;
-
-#[derive(Serialize, Deserialize, Default, Clone)]
-pub struct WorkflowMetadata {
-    pub created_at: String,
-    pub modified_at: String,
-    pub author: String,
-    pub description: String,
-    pub tags: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, Default, Clone)]
-pub struct MenuItem {
-    pub text: String,
-    pub callback_data: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub url: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub submenu: Option<Vec<MenuItem>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub documents: Option<Vec<Document>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub data: Option<serde_json::Value>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub text_content: Option<String>,
-}
-
-#[derive(Serialize, Deserialize, Default, Clone)]
-pub struct Document {
-    pub text: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub callback_data: Option<String>,
-    pub url: String,
+/// Something the menu bar or a tab asked for, carried out by the app once
+/// they are drawn
+#[derive(Clone, Debug, PartialEq)]
+pub enum AppAction {
+    Status(String, f32),
+    ShowTab(Tab),
+    Export,
+    Import,
+    ImportBotData,
+    SaveProject,
+    OpenProject,
+    SaveImage(ImageFormat, ExportScope),
+    ToggleDiagnostics,
+    ToggleSearch,
+    ToggleInspector,
+    ToggleOutline,
 }
 
-#[derive(Serialize, Deserialize, Default, Clone)]
-pub struct FaqItem {
-    pub question: String,
-    pub answer: String,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub tags: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, Default)]
-pub struct BotMenuConfig {
-    pub title: String,
-    pub main_menu: Vec<MenuItem>,
-    pub faq: Vec<FaqItem>,
-    #[serde(skip)]
-    pub workflow_metadata: WorkflowMetadata,
-}
+/// Pixels per graph unit in exported PNG pictures, sharp on high-DPI screens
+const PNG_EXPORT_SCALE: f32 = 2.0;
 
 /// Your app state
 pub struct MenuEditorApp {
     // Node graph for menu editing
     pub node_graph: SimpleNodeGraph,
-    // Current tab
-    pub current_tab: Tab,
     // Exported data as string
     pub exported_data: String,
     // Import text field
     pub import_text: String,
+    // Problems found in the import text, highlighted in the import tab
+    pub import_errors: Vec<ValidationError>,
     // Status message
     pub status_message: Option<(String, f32)>, // (message, time_remaining)
     // Current workflow ID
     pub workflow_id: String,
     // Workflow metadata
     pub workflow_metadata: WorkflowMetadata,
+    // Title of the exported bot menu
+    pub menu_title: String,
+    // How the Import button combines data with the current graph
+    pub import_mode: ImportMode,
+    // Data imported last time, used as the common base for merges
+    pub import_base: Option<BotMenuConfig>,
+    // Merge waiting for the user to resolve conflicts
+    pub pending_merge: Option<MergePlan>,
     // Dirty flag - indicates unsaved changes
     pub dirty: bool,
+    // Editor preferences, persisted between sessions
+    pub settings: EditorSettings,
+    // Settings that are in effect, compared with `settings` every frame
+    applied_settings: Option<EditorSettings>,
     tabs: Tabs,
     menu_bar: MenuBar,
     status_bar: StatusBar,
+    diagnostics_panel: DiagnosticsPanel,
+    inspector_panel: InspectorPanel,
+    outline_panel: OutlinePanel,
+    search_palette: SearchPalette,
 }
 
 impl Default for MenuEditorApp {
@@ -97,27 +87,36 @@ impl Default for MenuEditorApp {
         let id = Uuid::new_v4().to_string();
         
         // Get current date/time as ISO string
-        let now = js_sys::Date::new_0().to_iso_string().as_string()
-            .unwrap_or_else(|| "Unknown date".to_string());
+        let now = now_iso();
         
         Self {
             node_graph: SimpleNodeGraph::new(),
-            current_tab: Tab::Editor,
             exported_data: String::new(),
             import_text: String::new(),
+            import_errors: Vec::new(),
             status_message: None,
-            workflow_id: id,
+            workflow_id: id.clone(),
             workflow_metadata: WorkflowMetadata {
                 created_at: now.clone(),
                 modified_at: now,
                 author: "User".to_string(),
-                description: "New ВГАУ Bot Menu".to_string(),
+                description: Language::default().tr("project.default_description").to_string(),
                 tags: vec!["menu".to_string(), "bot".to_string()],
             },
+            menu_title: DEFAULT_MENU_TITLE.to_string(),
+            import_mode: ImportMode::default(),
+            import_base: None,
+            pending_merge: None,
             dirty: false,
+            settings: EditorSettings::default(),
+            applied_settings: None,
             tabs: Tabs::new(),
-            menu_bar: MenuBar::new(id.clone(), false),
-            status_bar: StatusBar::new(id.clone(), false, None),
+            menu_bar: MenuBar::new(false),
+            status_bar: StatusBar::new(id, false, None),
+            diagnostics_panel: DiagnosticsPanel::new(),
+            inspector_panel: InspectorPanel::new(),
+            outline_panel: OutlinePanel::new(),
+            search_palette: SearchPalette::new(),
         }
     }
 }
@@ -125,281 +124,406 @@ impl Default for MenuEditorApp {
 impl MenuEditorApp {
     /// Called once before the first frame.
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
-        // Apply custom styles to match React/Tailwind aesthetic
-        let ctx = &cc.egui_ctx;
-        let mut style = (*ctx.style()).clone();
-        
-        // Rounded corners like in Tailwind
-        style.visuals.window_rounding = egui::Rounding::same(8.0);
-        style.visuals.window_shadow.extrusion = 8.0;
-        style.visuals.menu_rounding = egui::Rounding::same(6.0);
-        
-        // Colors similar to Tailwind blue shades
-        style.visuals.widgets.active.bg_fill = egui::Color32::from_rgb(59, 130, 246); // blue-500
-        style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(96, 165, 250); // blue-400
-        style.visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(30, 58, 138); // blue-900
+        // Styles follow the saved theme, they are applied on the first frame
+        Self {
+            settings: EditorSettings::load(cc.storage),
+            ..Default::default()
+        }
+    }
+    
+    /// Put changed settings into effect: language, theme, interface scale and the canvas options
+    fn apply_settings(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        if self.applied_settings.as_ref() == Some(&self.settings) {
+            return;
+        }
+        let previous = self.applied_settings.replace(self.settings.clone());
         
-        // Apply the style
-        ctx.set_style(style);
+        if previous.as_ref().map(|s| s.language) != Some(self.settings.language) {
+            self.settings.language.apply(ctx);
+            frame.set_window_title(self.settings.language.tr("app.title"));
+        }
+        if previous.as_ref().map(|s| s.theme) != Some(self.settings.theme) {
+            self.settings.theme.apply(ctx);
+        }
+        if previous.as_ref().map(|s| s.ui_scale) != Some(self.settings.ui_scale) {
+            let native = frame.info().native_pixels_per_point.unwrap_or(1.0);
+            ctx.set_pixels_per_point(native * self.settings.ui_scale);
+        }
+        self.node_graph.apply_settings(&self.settings);
         
-        Default::default()
+        // Changed in the settings tab, stored right away instead of at the next auto-save
+        if previous.is_some() {
+            if let Some(storage) = frame.storage_mut() {
+                self.settings.save(storage);
+                storage.flush();
+            }
+        }
     }
 
     pub fn export_data(&mut self) {
         // Update modified timestamp
-        let now = js_sys::Date::new_0().to_iso_string().as_string()
-            .unwrap_or_else(|| "Unknown date".to_string());
+        let now = now_iso();
         self.workflow_metadata.modified_at = now;
+        let language = self.settings.language;
         
-        // Create a new BotMenuConfig structure with default title
-        let mut config = BotMenuConfig {
-            title: "Данные для кнопок для телеграмм бота Верхневолжского ГАУ".to_string(),
-            main_menu: Vec::new(),
-            faq: Vec::new(),
-            workflow_metadata: self.workflow_metadata.clone(),
+        // Convert node_graph to BotMenuConfig structure, errors found by the validator block the export
+        let config = match DataExporter::export_checked(
+            &self.node_graph,
+            &self.menu_title,
+            self.workflow_metadata.clone(),
+        ) {
+            Ok(config) => config,
+            Err(errors) => {
+                self.diagnostics_panel.open = true;
+                self.status_bar.update_status(
+                    &language.tr_args("status.export_blocked", &[("count", &errors.len())]),
+                    3.0,
+                );
+                return;
+            }
         };
         
-        // Convert node_graph to BotMenuConfig structure
-        self.build_menu_structure(&mut config);
-        
         // Convert to JSON and store in exported_data
         match serde_json::to_string_pretty(&config) {
             Ok(json) => {
                 self.exported_data = json;
-                self.status_bar.update_status("Данные успешно экспортированы", 3.0);
+                self.status_bar.update_status(language.tr("status.exported"), 3.0);
                 self.dirty = false;
             },
             Err(e) => {
-                self.exported_data = format!("Ошибка экспорта: {}", e);
-                self.status_bar.update_status("Ошибка при экспорте данных", 3.0);
+                self.exported_data = language.tr_args("status.export_error", &[("error", &e)]);
+                self.status_bar.update_status(language.tr("status.export_failed"), 3.0);
             }
         }
     }
     
-    // Function to build menu structure from node graph
-    fn build_menu_structure(&self, config: &mut BotMenuConfig) {
-        // Find all root menu items (nodes without input connections)
-        let root_nodes = self.node_graph.get_root_menu_nodes();
-        
-        // Convert root nodes to menu items
-        for node_id in root_nodes {
-            if let Some(menu_item) = self.build_menu_item(node_id) {
-                config.main_menu.push(menu_item);
-            }
+    /// Serialize the whole editor state into the native project format
+    pub fn save_project(&mut self) -> Result<String, String> {
+        let now = now_iso();
+        self.workflow_metadata.modified_at = now;
+
+        let mut project = ProjectFile::new(
+            self.workflow_id.clone(),
+            self.workflow_metadata.clone(),
+            self.node_graph.to_graph_state(),
+        );
+        project.menu_title = self.menu_title.clone();
+        project.import_base = self.import_base.clone();
+
+        let json = project.to_json()?;
+        self.dirty = false;
+        Ok(json)
+    }
+
+    /// Restore the editor exactly as it was saved in a project file
+    pub fn load_project(&mut self, json_data: &str) -> Result<(), String> {
+        let project = ProjectFile::from_json(json_data)?;
+
+        self.workflow_id = project.workflow_id;
+        self.workflow_metadata = project.metadata;
+        if !project.menu_title.is_empty() {
+            self.menu_title = project.menu_title;
         }
-        
-        // Find all FAQ items
-        let faq_nodes = self.node_graph.get_faq_nodes();
-        
-        // Convert FAQ nodes to FAQ items
-        for node_id in faq_nodes {
-            if let Some(faq_item) = self.build_faq_item(node_id) {
-                config.faq.push(faq_item);
+        self.import_base = project.import_base;
+        self.pending_merge = None;
+        self.node_graph.load_graph_state(project.graph);
+        self.menu_bar = MenuBar::new(false);
+        self.status_bar = StatusBar::new(self.workflow_id.clone(), false, None);
+        self.dirty = false;
+
+        Ok(())
+    }
+
+    /// Save the project to a file chosen by the user (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_project_to_file(&mut self) {
+        let language = self.settings.language;
+        let file = rfd::FileDialog::new()
+            .add_filter(language.tr("project.file_filter"), &[PROJECT_FILE_EXTENSION])
+            .set_file_name(&format!("menu.{}", PROJECT_FILE_EXTENSION))
+            .save_file();
+
+        if let Some(path) = file {
+            let result = self.save_project()
+                .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+            match result {
+                Ok(_) => self.status_bar.update_status(language.tr("status.project_saved"), 3.0),
+                Err(e) => self.status_bar.update_status(&language.tr_args("status.project_save_error", &[("error", &e)]), 3.0),
             }
         }
     }
-    
-    // Recursively build menu items
-    fn build_menu_item(&self, node_id: usize) -> Option<MenuItem> {
-        let node_data = self.node_graph.get_node_data(node_id)?;
-        
-        // Create menu item from node data
-        let mut menu_item = MenuItem {
-            text: node_data.get_title().to_string(),
-            callback_data: self.generate_callback_data(node_data.get_title()),
-            description: None,
-            url: None,
-            submenu: None,
-            documents: None,
-            data: None,
-            text_content: None,
-        };
-        
-        // Parse parameters from node
-        for param in node_data.get_params() {
-            match param.kind() {
-                "name" => {
-                    if !param.get_text().is_empty() {
-                        menu_item.text = param.get_text();
-                    }
-                },
-                "callback_data" => {
-                    if !param.get_text().is_empty() {
-                        menu_item.callback_data = param.get_text();
-                    }
-                },
-                "description" => {
-                    let desc = param.get_text();
-                    if !desc.is_empty() {
-                        menu_item.description = Some(desc);
-                    }
-                },
-                "url" => {
-                    let url = param.get_text();
-                    if !url.is_empty() {
-                        menu_item.url = Some(url);
-                    }
-                },
-                "text_content" => {
-                    let content = param.get_text();
-                    if !content.is_empty() {
-                        menu_item.text_content = Some(content);
-                    }
-                },
-                "data" => {
-                    let data = param.get_text();
-                    if !data.is_empty() {
-                        if let Ok(json) = serde_json::from_str(&data) {
-                            menu_item.data = Some(json);
-                        }
-                    }
+
+    /// Save the project into the export text area (web build has no file system access)
+    #[cfg(target_arch = "wasm32")]
+    pub fn save_project_to_file(&mut self) {
+        let language = self.settings.language;
+        match self.save_project() {
+            Ok(json) => {
+                self.exported_data = json;
+                self.tabs.set_tab(Tab::Export);
+                self.status_bar.update_status(language.tr("status.project_in_export_tab"), 3.0);
+            },
+            Err(e) => self.status_bar.update_status(&language.tr_args("status.project_save_error", &[("error", &e)]), 3.0),
+        }
+    }
+
+    /// Open a project file chosen by the user (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open_project_from_file(&mut self) {
+        let language = self.settings.language;
+        let file = rfd::FileDialog::new()
+            .add_filter(language.tr("project.file_filter"), &[PROJECT_FILE_EXTENSION])
+            .pick_file();
+
+        if let Some(path) = file {
+            let result = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|json| self.load_project(&json));
+            match result {
+                Ok(_) => {
+                    self.tabs.set_tab(Tab::Editor);
+                    self.status_bar.update_status(language.tr("status.project_opened"), 3.0);
                 },
-                _ => {}
+                Err(e) => self.status_bar.update_status(&language.tr_args("status.project_open_error", &[("error", &e)]), 3.0),
             }
         }
-        
-        // Find child nodes (submenu items)
-        let children = self.node_graph.get_child_menu_nodes(node_id);
-        if !children.is_empty() {
-            let mut submenu = Vec::new();
-            for child_id in children {
-                if let Some(child_item) = self.build_menu_item(child_id) {
-                    submenu.push(child_item);
-                }
+    }
+
+    /// Open a project pasted into the import text area (web build has no file system access)
+    #[cfg(target_arch = "wasm32")]
+    pub fn open_project_from_file(&mut self) {
+        self.tabs.set_tab(Tab::Export);
+        self.status_bar.update_status(self.settings.language.tr("status.paste_project"), 3.0);
+    }
+
+    /// Picture of the graph or of the selected nodes in the current theme
+    fn render_image(&self, format: ImageFormat, scope: ExportScope) -> Result<Vec<u8>, String> {
+        let image = GraphImage::new(&self.node_graph, scope, self.settings.theme)?;
+        match format {
+            ImageFormat::Svg => Ok(image.to_svg().into_bytes()),
+            ImageFormat::Png => image.to_png(PNG_EXPORT_SCALE),
+        }
+    }
+
+    /// Save a picture of the graph to a file chosen by the user (native only)
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn export_image(&mut self, format: ImageFormat, scope: ExportScope) {
+        let language = self.settings.language;
+        let bytes = match self.render_image(format, scope) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                self.status_bar.update_status(&language.tr_args("status.image_error", &[("error", &e)]), 3.0);
+                return;
             }
-            
-            if !submenu.is_empty() {
-                menu_item.submenu = Some(submenu);
+        };
+        let file = rfd::FileDialog::new()
+            .add_filter(format.label(), &[format.extension()])
+            .set_file_name(&format!("menu.{}", format.extension()))
+            .save_file();
+
+        if let Some(path) = file {
+            match std::fs::write(&path, bytes) {
+                Ok(_) => self.status_bar.update_status(&language.tr_args("status.image_saved", &[("format", &format.label())]), 3.0),
+                Err(e) => self.status_bar.update_status(&language.tr_args("status.image_error", &[("error", &e)]), 3.0),
             }
         }
+    }
+
+    /// Download a picture of the graph through the browser (web build has no file system access)
+    #[cfg(target_arch = "wasm32")]
+    pub fn export_image(&mut self, format: ImageFormat, scope: ExportScope) {
+        let language = self.settings.language;
+        let file_name = format!("menu.{}", format.extension());
+        let result = self.render_image(format, scope)
+            .and_then(|bytes| download_file(&bytes, &file_name, format.mime_type()));
+        match result {
+            Ok(_) => self.status_bar.update_status(&language.tr_args("status.image_downloaded", &[("format", &format.label())]), 3.0),
+            Err(e) => self.status_bar.update_status(&language.tr_args("status.image_error", &[("error", &e)]), 3.0),
+        }
+    }
+
+    pub fn import_data(&mut self) {
+        self.import_errors.clear();
+        let language = self.settings.language;
         
-        // Find document nodes
-        let documents = self.node_graph.get_documents_for_node(node_id);
-        if !documents.is_empty() {
-            let mut doc_items = Vec::new();
-            for doc_id in documents {
-                if let Some(doc_data) = self.node_graph.get_node_data(doc_id) {
-                    let mut doc = Document {
-                        text: doc_data.get_title().to_string(),
-                        callback_data: None,
-                        url: String::new(),
-                    };
-                    
-                    for param in doc_data.get_params() {
-                        match param.kind() {
-                            "text" => {
-                                if !param.get_text().is_empty() {
-                                    doc.text = param.get_text();
-                                }
-                            },
-                            "callback_data" => {
-                                let cb = param.get_text();
-                                if !cb.is_empty() {
-                                    doc.callback_data = Some(cb);
-                                }
-                            },
-                            "url" => {
-                                doc.url = param.get_text();
-                            },
-                            _ => {}
-                        }
-                    }
-                    
-                    // Only add if has URL
-                    if !doc.url.is_empty() {
-                        doc_items.push(doc);
-                    }
+        // Project files carry the whole graph, load them directly
+        if ProjectFile::is_project_json(&self.import_text) {
+            let import_text = self.import_text.clone();
+            match self.load_project(&import_text) {
+                Ok(_) => {
+                    self.tabs.set_tab(Tab::Editor);
+                    self.status_bar.update_status(language.tr("status.project_opened"), 3.0);
+                },
+                Err(e) => {
+                    self.status_bar.update_status(&language.tr_args("status.import_error", &[("error", &e)]), 3.0);
                 }
             }
-            
-            if !doc_items.is_empty() {
-                menu_item.documents = Some(doc_items);
+            return;
+        }
+
+        // Parse and validate the import text, errors are highlighted in the text area
+        match DataImporter::parse_config_checked(&self.import_text) {
+            Ok(config) if self.import_mode == ImportMode::Merge => {
+                // Conflicts are shown in a dialog before anything is applied
+                let plan = DataImporter::plan_merge(&self.node_graph, self.import_base.as_ref(), config);
+                self.pending_merge = Some(plan);
+            },
+            Ok(config) => {
+                // Build the graph from the typed menu structure
+                if let Err(e) = DataImporter::import_config_with_mode(&mut self.node_graph, &config, self.import_mode) {
+                    self.status_bar.update_status(&language.tr_args("status.import_error", &[("error", &e)]), 3.0);
+                    return;
+                }
+                
+                self.menu_title = config.title.clone();
+                self.import_base = Some(config);
+                
+                self.tabs.set_tab(Tab::Editor);
+                self.status_bar.update_status(language.tr("status.imported"), 3.0);
+                self.dirty = true;
+            },
+            Err(errors) => {
+                let message = match errors.as_slice() {
+                    [error] => language.tr_args("status.import_error", &[("error", error)]),
+                    _ => language.tr_args("status.import_problems", &[("count", &errors.len())]),
+                };
+                self.status_bar.update_status(&message, 3.0);
+                self.import_errors = errors;
             }
         }
-        
-        Some(menu_item)
     }
     
-    // Build FAQ items
-    fn build_faq_item(&self, node_id: usize) -> Option<FaqItem> {
-        let node_data = self.node_graph.get_node_data(node_id)?;
-        
-        // Create FAQ item
-        let mut faq_item = FaqItem {
-            question: node_data.get_title().to_string(),
-            answer: String::new(),
-            tags: Vec::new(),
+    /// Apply the pending merge with the resolutions chosen in the dialog
+    pub fn apply_pending_merge(&mut self) {
+        let Some(plan) = self.pending_merge.take() else {
+            return;
         };
+        let language = self.settings.language;
         
-        // Parse parameters
-        for param in node_data.get_params() {
-            match param.kind() {
-                "question" => {
-                    if !param.get_text().is_empty() {
-                        faq_item.question = param.get_text();
+        match DataImporter::apply_merge(&mut self.node_graph, &plan) {
+            Ok(_) => {
+                self.menu_title = plan.incoming().title.clone();
+                self.import_base = Some(plan.incoming().clone());
+                self.tabs.set_tab(Tab::Editor);
+                self.status_bar.update_status(language.tr("status.merged"), 3.0);
+                self.dirty = true;
+            },
+            Err(e) => {
+                self.status_bar.update_status(&language.tr_args("status.merge_error", &[("error", &e)]), 3.0);
+            }
+        }
+    }
+    
+    /// Carry out what the menu bar or a tab asked for while it was drawn
+    fn perform(&mut self, actions: Vec<AppAction>) {
+        let language = self.settings.language;
+        for action in actions {
+            match action {
+                AppAction::Status(message, duration) => self.status_bar.update_status(&message, duration),
+                AppAction::ShowTab(tab) => self.tabs.set_tab(tab),
+                AppAction::Export => {
+                    self.export_data();
+                    self.tabs.set_tab(Tab::Export);
+                },
+                AppAction::Import => self.import_data(),
+                AppAction::ImportBotData => match self.import_predefined_bot_data() {
+                    Ok(_) => self.status_bar.update_status(language.tr("status.bot_data_imported"), 3.0),
+                    Err(e) => self.status_bar.update_status(&language.tr_args("status.import_error", &[("error", &e)]), 3.0),
+                },
+                AppAction::SaveProject => self.save_project_to_file(),
+                AppAction::OpenProject => self.open_project_from_file(),
+                AppAction::SaveImage(format, scope) => self.export_image(format, scope),
+                AppAction::ToggleDiagnostics => self.diagnostics_panel.open = !self.diagnostics_panel.open,
+                AppAction::ToggleSearch => {
+                    self.search_palette.toggle();
+                    // Results are picked on the canvas
+                    if self.search_palette.open {
+                        self.tabs.set_tab(Tab::Editor);
                     }
                 },
-                "answer" => {
-                    faq_item.answer = param.get_text();
+                AppAction::ToggleInspector => {
+                    self.inspector_panel.open = !self.inspector_panel.open;
+                    if self.inspector_panel.open {
+                        self.tabs.set_tab(Tab::Editor);
+                    }
                 },
-                "tag" | "tags" => {
-                    let tags_text = param.get_text();
-                    if !tags_text.is_empty() {
-                        faq_item.tags = tags_text.split(',')
-                            .map(|s| s.trim().to_string())
-                            .filter(|s| !s.is_empty())
-                            .collect();
+                AppAction::ToggleOutline => {
+                    self.outline_panel.open = !self.outline_panel.open;
+                    if self.outline_panel.open {
+                        self.tabs.set_tab(Tab::Editor);
                     }
                 },
-                _ => {}
             }
         }
-        
-        // Only return if has non-empty answer
-        if !faq_item.answer.is_empty() {
-            Some(faq_item)
-        } else {
-            None
-        }
     }
     
-    // Generate a valid callback_data from title
-    fn generate_callback_data(&self, title: &str) -> String {
-        // Replace spaces with underscore, remove special characters, convert to lowercase
-        let mut callback = title.to_lowercase()
-            .replace(|c: char| !c.is_alphanumeric() && c != ' ', "")
-            .replace(' ', "_");
+    /// Draw the list of merge conflicts for the pending merge
+    fn draw_merge_dialog(&mut self, ctx: &egui::Context) {
+        let mut apply = false;
+        let mut cancel = false;
+        let language = self.settings.language;
         
-        // Limit length
-        if callback.len() > 64 {
-            callback = callback.chars().take(64).collect();
-        }
-        
-        // Ensure it's not empty
-        if callback.is_empty() {
-            callback = format!("item_{}", rand::random::<u16>());
+        if let Some(plan) = &mut self.pending_merge {
+            egui::Window::new(language.tr("merge.title"))
+                .id(egui::Id::new("merge_dialog"))
+                .collapsible(false)
+                .resizable(true)
+                .default_width(520.0)
+                .show(ctx, |ui| {
+                    ui.label(language.tr_args("merge.updates", &[("count", &plan.updates.len())]));
+                    ui.label(language.tr_args("merge.new_items", &[("count", &plan.new_items)]));
+                    ui.label(language.tr_args("merge.conflicts", &[("count", &plan.conflicts.len())]));
+                    
+                    ui.separator();
+                    
+                    egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                        for (i, conflict) in plan.conflicts.iter_mut().enumerate() {
+                            ui.push_id(i, |ui| {
+                                ui.label(egui::RichText::new(&conflict.key).strong());
+                                
+                                match &conflict.kind {
+                                    ConflictKind::FieldChanged { field, local, incoming } => {
+                                        ui.label(language.tr_args("merge.field_changed", &[("field", field)]));
+                                        ui.label(language.tr_args("merge.local", &[("value", local)]));
+                                        ui.label(language.tr_args("merge.incoming", &[("value", incoming)]));
+                                        
+                                        ui.horizontal(|ui| {
+                                            ui.radio_value(&mut conflict.resolution, ConflictResolution::KeepLocal, language.tr("merge.keep_local"));
+                                            ui.radio_value(&mut conflict.resolution, ConflictResolution::TakeIncoming, language.tr("merge.take_incoming"));
+                                        });
+                                    },
+                                    ConflictKind::RemovedUpstream => {
+                                        ui.label(language.tr("merge.removed_upstream"));
+                                        
+                                        ui.horizontal(|ui| {
+                                            ui.radio_value(&mut conflict.resolution, ConflictResolution::KeepLocal, language.tr("merge.keep_node"));
+                                            ui.radio_value(&mut conflict.resolution, ConflictResolution::TakeIncoming, language.tr("merge.remove_node"));
+                                        });
+                                    },
+                                }
+                                
+                                ui.separator();
+                            });
+                        }
+                    });
+                    
+                    ui.horizontal(|ui| {
+                        if ui.button(language.tr("merge.apply")).clicked() {
+                            apply = true;
+                        }
+                        
+                        if ui.button(language.tr("merge.cancel")).clicked() {
+                            cancel = true;
+                        }
+                    });
+                });
         }
         
-        callback
-    }
-    
-    pub fn import_data(&mut self) {
-        // Try to parse the import text as JSON
-        match serde_json::from_str::<BotMenuConfig>(&self.import_text) {
-            Ok(config) => {
-                // Update workflow metadata
-                self.workflow_metadata = config.workflow_metadata;
-                
-                // TODO: Convert BotMenuConfig to node_graph
-                // This is a simplified placeholder implementation
-                
-                self.current_tab = Tab::Editor;
-                self.status_bar.update_status("Данные успешно импортированы", 3.0);
-                self.dirty = false;
-            },
-            Err(e) => {
-                self.status_bar.update_status(&format!("Ошибка импорта: {}", e), 3.0);
-            }
+        if apply {
+            self.apply_pending_merge();
+        } else if cancel {
+            self.pending_merge = None;
+            self.status_bar.update_status(language.tr("status.merge_cancelled"), 2.0);
         }
     }
     
@@ -407,87 +531,11 @@ impl MenuEditorApp {
         self.status_message = Some((message.to_string(), duration));
     }
 
-    // Update clipboard implementation
-    pub fn copy_to_clipboard(&self, text: &str) -> Result<(), String> {
-        #[cfg(target_arch = "wasm32")]
-        {
-            use wasm_bindgen::JsCast;
-            let window = web_sys::window().ok_or("No window found")?;
-            let navigator = window.navigator();
-            
-            // Use clipboard API if available
-            let clipboard = navigator.clipboard();
-            if !clipboard.is_undefined() {
-                let promise = clipboard.write_text(text);
-                // Fire and forget - we can't await in synchronous code
-                let _ = wasm_bindgen_futures::JsFuture::from(promise);
-                return Ok(());
-            }
-            
-            // Fallback method if clipboard API is not available
-            let document = window.document().ok_or("No document found")?;
-            
-            // Create a temporary textarea
-            let textarea: web_sys::HtmlTextAreaElement = document
-                .create_element("textarea")
-                .map_err(|_| "Failed to create textarea".to_string())?
-                .dyn_into()
-                .map_err(|_| "Failed to convert to textarea".to_string())?;
-            
-            // Set textarea properties
-            textarea.set_value(text);
-            textarea.style().set_property("position", "fixed").unwrap();
-            textarea.style().set_property("top", "0").unwrap();
-            textarea.style().set_property("left", "0").unwrap();
-            textarea.style().set_property("width", "2em").unwrap();
-            textarea.style().set_property("height", "2em").unwrap();
-            textarea.style().set_property("padding", "0").unwrap();
-            textarea.style().set_property("border", "none").unwrap();
-            textarea.style().set_property("outline", "none").unwrap();
-            textarea.style().set_property("box-shadow", "none").unwrap();
-            textarea.style().set_property("background", "transparent").unwrap();
-            
-            // Append to document
-            let body = document.body().ok_or("No body found".to_string())?;
-            body.append_child(&textarea).map_err(|_| "Failed to append textarea".to_string())?;
-            
-            // Select the text
-            textarea.select();
-            
-            // Try to execute copy command using JS
-            let result = js_sys::Reflect::get(
-                &document,
-                &"execCommand".into()
-            )
-            .map_err(|_| "No execCommand method found".to_string())?;
-            
-            let exec_command = js_sys::Function::from(result);
-            let success = exec_command
-                .call1(&document, &"copy".into())
-                .map_err(|_| "Failed to execute copy command".to_string())?;
-                
-            let success = js_sys::Boolean::from(success).value_of();
-            
-            // Clean up
-            body.remove_child(&textarea).map_err(|_| "Failed to remove textarea".to_string())?;
-            
-            if success {
-                Ok(())
-            } else {
-                Err("Copy command failed".to_string())
-            }
-        }
-        
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            // For non-WASM targets, we don't implement clipboard access
-            Err("Clipboard not supported in this environment".to_string())
-        }
-    }
-
     /// Import menu data from JSON string
     pub fn import_from_json(&mut self, json_data: &str) -> Result<(), String> {
-        DataImporter::import_menu_data(&mut self.node_graph, json_data)
+        let config = DataImporter::import_menu_data(&mut self.node_graph, json_data, ImportMode::Append)?;
+        self.menu_title = config.title;
+        Ok(())
     }
 
     /// Import predefined telegram bot data
@@ -498,62 +546,78 @@ impl MenuEditorApp {
 }
 
 impl eframe::App for MenuEditorApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Set global visuals to match React/Tailwind dark mode
-        let mut style = (*ctx.style()).clone();
-        style.visuals.dark_mode = true;
-        style.visuals.panel_fill = egui::Color32::from_rgb(17, 24, 39); // bg-gray-900
-        ctx.set_style(style);
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        self.settings.save(storage);
+    }
+    
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        self.apply_settings(ctx, frame);
+        let language = self.settings.language;
+        
+        // Text fields, in the inspector for one, undo their own typing
+        let keyboard_free = ctx.memory(|mem| mem.focus().is_none());
         
         // Handle global keyboard shortcuts
         ctx.input(|i| {
             // Only process keyboard shortcuts when in Editor tab
-            if self.current_tab == Tab::Editor {
+            if *self.tabs.get_current_tab() == Tab::Editor {
                 // Undo: Ctrl+Z
-                if i.key_pressed(egui::Key::Z) && i.modifiers.ctrl && !i.modifiers.shift {
+                if keyboard_free && i.key_pressed(egui::Key::Z) && i.modifiers.ctrl && !i.modifiers.shift {
                     if self.node_graph.can_undo() {
                         if self.node_graph.undo() {
-                            self.status_bar.update_status("Отменено последнее действие", 2.0);
+                            self.status_bar.update_status(language.tr("status.undone"), 2.0);
                             self.dirty = true;
                         } else {
-                            self.status_bar.update_status("Нет действий для отмены", 2.0);
+                            self.status_bar.update_status(language.tr("status.nothing_to_undo"), 2.0);
                         }
                     } else {
-                        self.status_bar.update_status("Нет действий для отмены", 2.0);
+                        self.status_bar.update_status(language.tr("status.nothing_to_undo"), 2.0);
                     }
                 }
                 
                 // Redo: Ctrl+Y or Ctrl+Shift+Z
-                if (i.key_pressed(egui::Key::Y) && i.modifiers.ctrl) || 
-                   (i.key_pressed(egui::Key::Z) && i.modifiers.ctrl && i.modifiers.shift) {
+                if keyboard_free && ((i.key_pressed(egui::Key::Y) && i.modifiers.ctrl) || 
+                   (i.key_pressed(egui::Key::Z) && i.modifiers.ctrl && i.modifiers.shift)) {
                     if self.node_graph.can_redo() {
                         if self.node_graph.redo() {
-                            self.status_bar.update_status("Действие возвращено", 2.0);
+                            self.status_bar.update_status(language.tr("status.redone"), 2.0);
                             self.dirty = true;
                         } else {
-                            self.status_bar.update_status("Нет действий для возврата", 2.0);
+                            self.status_bar.update_status(language.tr("status.nothing_to_redo"), 2.0);
                         }
                     } else {
-                        self.status_bar.update_status("Нет действий для возврата", 2.0);
+                        self.status_bar.update_status(language.tr("status.nothing_to_redo"), 2.0);
                     }
                 }
+                
+                // Find: Ctrl+F or Ctrl+P
+                if (i.key_pressed(egui::Key::F) || i.key_pressed(egui::Key::P)) && i.modifiers.command {
+                    self.search_palette.toggle();
+                }
             }
         });
         
+        self.menu_bar.set_dirty(self.dirty);
+        self.status_bar.set_dirty(self.dirty);
+        
         // Draw the menu bar
-        self.menu_bar.draw(
-            ctx,
-            &mut self.node_graph,
-            &mut |msg, duration| self.status_bar.update_status(msg, duration),
-            &mut |tab| self.tabs.set_tab(tab),
-            &mut || self.export_data(),
-            &mut || self.import_predefined_bot_data(),
-            &self.workflow_metadata,
-        );
+        let actions = self.menu_bar.draw(ctx, &mut self.node_graph, &self.workflow_metadata);
+        self.perform(actions);
+        
+        // Draw the status bar below everything else
+        self.status_bar.draw(ctx, &self.node_graph, ctx);
+        
+        // Side panels are docked next to the main content, so they go before the central panel
+        self.diagnostics_panel.draw(ctx, &mut self.node_graph);
+        if *self.tabs.get_current_tab() == Tab::Editor {
+            self.inspector_panel.draw(ctx, &mut self.node_graph);
+            self.outline_panel.draw(ctx, &mut self.node_graph);
+        }
+        self.search_palette.draw(ctx, &mut self.node_graph);
         
         // Main content with styling similar to App.jsx
-        egui::CentralPanel::default()
-            .frame(egui::Frame::none().fill(egui::Color32::from_rgb(17, 24, 39))) // bg-gray-900
+        let actions = egui::CentralPanel::default()
+            .frame(egui::Frame::none().fill(Theme::current(ctx).palette().canvas))
             .show(ctx, |ui| {
                 ui.spacing_mut().item_spacing = egui::vec2(16.0, 16.0); // Match Tailwind spacing
                 
@@ -561,14 +625,47 @@ impl eframe::App for MenuEditorApp {
                 self.tabs.draw(
                     ui,
                     &mut self.node_graph,
-                    &mut |msg, duration| self.status_bar.update_status(msg, duration),
-                    &mut || self.export_data(),
-                    &mut || self.import_data(),
-                    &self.workflow_metadata,
-                );
-            });
+                    &mut self.settings,
+                    ExportView {
+                        exported_data: &self.exported_data,
+                        import_mode: &mut self.import_mode,
+                        import_text: &mut self.import_text,
+                        import_errors: &self.import_errors,
+                        workflow_metadata: &self.workflow_metadata,
+                    },
+                )
+            })
+            .inner;
+        self.perform(actions);
         
-        // Draw the status bar
-        self.status_bar.draw(ctx, &self.node_graph, ctx);
+        // Draw the merge conflict dialog on top of the current tab
+        self.draw_merge_dialog(ctx);
     }
-} 
\ No newline at end of file
+}
+
+/// Hand bytes to the browser as a downloaded file
+#[cfg(target_arch = "wasm32")]
+fn download_file(bytes: &[u8], file_name: &str, mime_type: &str) -> Result<(), String> {
+    use wasm_bindgen::JsCast;
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or("No document found")?;
+    
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|_| "Cannot create the file")?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|_| "Cannot create a link to the file")?;
+    
+    let anchor: web_sys::HtmlAnchorElement = document.create_element("a")
+        .ok()
+        .and_then(|element| element.dyn_into().ok())
+        .ok_or("Cannot create a download link")?;
+    anchor.set_href(&url);
+    anchor.set_download(file_name);
+    anchor.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Ok(())
+}
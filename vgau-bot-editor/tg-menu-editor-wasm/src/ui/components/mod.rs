@@ -1,8 +0,0 @@
-// UI components module
-mod menu_bar;
-mod status_bar;
-mod tabs;
-
-pub use menu_bar::MenuBar;
-pub use status_bar::StatusBar;
-pub use tabs::{EditorTab, ExportTab, SettingsTab, HelpTab}; 
\ No newline at end of file
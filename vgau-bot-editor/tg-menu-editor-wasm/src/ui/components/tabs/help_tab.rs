@@ -1,47 +0,0 @@
-use eframe::egui;
-use crate::MenuEditorApp;
-use crate::ui::app::Tab;
-
-pub struct HelpTab;
-
-impl HelpTab {
-    pub fn draw(app: &mut MenuEditorApp, ui: &mut egui::Ui) {
-        ui.heading("О программе");
-        
-        ui.add_space(10.0);
-        
-        ui.label("ВГАУ Бот Редактор v1.0");
-        ui.label("Редактор структуры меню и FAQ для телеграм-бота Верхневолжского государственного аграрного университета");
-        
-        ui.add_space(20.0);
-        
-        ui.heading("Возможности");
-        
-        ui.label("• Интуитивный редактор на основе узлов с интерактивными элементами");
-        ui.label("• Drag-and-drop интерфейс для создания и соединения компонентов");
-        ui.label("• Визуальное редактирование структуры меню телеграм-бота");
-        ui.label("• Управление вопросами и ответами (FAQ) через узловые компоненты");
-        ui.label("• Экспорт и импорт данных в формате JSON");
-        ui.label("• Контекстные меню и панель инструментов для быстрого доступа к функциям");
-        ui.label("• Масштабирование и панорамирование рабочей области");
-        ui.label("• Подробная настройка параметров бота через свойства узлов");
-        ui.label("• История изменений с функциями отмены и повтора действий");
-        
-        ui.add_space(20.0);
-        
-        ui.heading("Горячие клавиши");
-        
-        ui.label("• Ctrl+Z — Отменить последнее действие");
-        ui.label("• Ctrl+Y или Ctrl+Shift+Z — Повторить отмененное действие");
-        ui.label("• Delete — Удалить выбранный узел");
-        ui.label("• Ctrl+колесо мыши — Изменить масштаб");
-        ui.label("• Перетаскивание с зажатым ЛКМ — Перемещение узлов");
-        ui.label("• Перетаскивание с зажатым СКМ/Alt — Панорамирование рабочей области");
-        
-        ui.add_space(20.0);
-        
-        if ui.button("Закрыть").clicked() {
-            app.current_tab = Tab::Editor;
-        }
-    }
-} 
\ No newline at end of file
@@ -1,10 +0,0 @@
-// UI tabs module
-mod editor_tab;
-mod export_tab;
-mod settings_tab;
-mod help_tab;
-
-pub use editor_tab::EditorTab;
-pub use export_tab::ExportTab;
-pub use settings_tab::SettingsTab;
-pub use help_tab::HelpTab; 
\ No newline at end of file
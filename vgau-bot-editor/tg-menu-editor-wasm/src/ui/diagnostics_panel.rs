@@ -0,0 +1,191 @@
+use eframe::egui;
+use crate::graph::{Diagnostic, Severity, SimpleNodeGraph};
+use crate::graph::validation::runtime::{RuntimeAnalyzer, RuntimeProfile, RuntimeReport};
+use crate::i18n::Language;
+use crate::theme::Theme;
+
+/// Where the diagnostics panel is docked
+#[derive(Clone, Copy, PartialEq)]
+pub enum PanelDock {
+    Bottom,
+    Right,
+}
+
+/// Results of checking the graph, kept until the graph or the profile changes
+struct Checked {
+    revision: u64,
+    profile: Option<RuntimeProfile>,
+    diagnostics: Vec<Diagnostic>,
+    report: RuntimeReport,
+}
+
+/// Panel listing validation results, clicking an entry jumps to its node
+pub struct DiagnosticsPanel {
+    pub open: bool,
+    pub dock: PanelDock,
+    show_info: bool,
+    /// Bot runtime to check reachability against, if any
+    profile: Option<RuntimeProfile>,
+    checked: Option<Checked>,
+}
+
+impl DiagnosticsPanel {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            dock: PanelDock::Bottom,
+            show_info: false,
+            profile: Some(RuntimeProfile::python_bot()),
+            checked: None,
+        }
+    }
+    
+    pub fn draw(&mut self, ctx: &egui::Context, node_graph: &mut SimpleNodeGraph) {
+        if !self.open {
+            return;
+        }
+        
+        // The list stays live while editing, but is only rebuilt after a change
+        let checked = match self.checked.take() {
+            Some(checked) if checked.revision == node_graph.revision() && checked.profile == self.profile => checked,
+            _ => self.check(node_graph),
+        };
+        let (diagnostics, report) = (&checked.diagnostics, &checked.report);
+        
+        let frame = egui::Frame::none()
+            .fill(Theme::current(ctx).palette().panel)
+            .inner_margin(egui::style::Margin::same(8.0));
+        
+        match self.dock {
+            PanelDock::Bottom => {
+                egui::TopBottomPanel::bottom("diagnostics_panel")
+                    .resizable(true)
+                    .default_height(160.0)
+                    .frame(frame)
+                    .show(ctx, |ui| self.draw_contents(ui, node_graph, diagnostics, report));
+            },
+            PanelDock::Right => {
+                egui::SidePanel::right("diagnostics_panel")
+                    .resizable(true)
+                    .default_width(320.0)
+                    .frame(frame)
+                    .show(ctx, |ui| self.draw_contents(ui, node_graph, diagnostics, report));
+            },
+        }
+        self.checked = Some(checked);
+    }
+    
+    fn check(&self, node_graph: &mut SimpleNodeGraph) -> Checked {
+        let mut diagnostics = node_graph.diagnostics().to_vec();
+        let report = self.profile.as_ref()
+            .map(|profile| RuntimeAnalyzer::analyze(node_graph, profile))
+            .unwrap_or_default();
+        diagnostics.extend(report.diagnostics.iter().cloned());
+        diagnostics.sort_by_key(|d| d.severity);
+        
+        Checked {
+            revision: node_graph.revision(),
+            profile: self.profile.clone(),
+            diagnostics,
+            report,
+        }
+    }
+    
+    fn draw_contents(&mut self, ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, diagnostics: &[Diagnostic], report: &RuntimeReport) {
+        let palette = Theme::current(ui.ctx()).palette();
+        let language = Language::current(ui.ctx());
+        let count = |severity: Severity| diagnostics.iter().filter(|d| d.severity == severity).count();
+        
+        ui.horizontal(|ui| {
+            ui.add(egui::Label::new(egui::RichText::new(language.tr("diagnostics.title"))
+                .strong()
+                .color(palette.text)));
+            
+            ui.label(language.tr_args(
+                "diagnostics.counts",
+                &[("errors", &count(Severity::Error)), ("warnings", &count(Severity::Warning))],
+            ));
+            
+            ui.checkbox(&mut self.show_info, language.tr_args("diagnostics.info", &[("count", &count(Severity::Info))]));
+            
+            let selected = self.profile.as_ref()
+                .map(|p| p.label(language))
+                .unwrap_or(language.tr("diagnostics.no_profile"));
+            egui::ComboBox::from_id_source("runtime_profile")
+                .selected_text(selected)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.profile, None, language.tr("diagnostics.no_profile"));
+                    for profile in [RuntimeProfile::python_bot(), RuntimeProfile::unrestricted()] {
+                        let name = profile.label(language);
+                        ui.selectable_value(&mut self.profile, Some(profile), name);
+                    }
+                })
+                .response
+                .on_hover_text(language.tr("diagnostics.profile_hint"));
+            
+            if self.profile.is_some() {
+                ui.label(language.tr_args("diagnostics.max_depth", &[("depth", &report.max_depth())]))
+                    .on_hover_text(language.tr("diagnostics.max_depth_hint"));
+            }
+            
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.small_button("✖").on_hover_text(language.tr("diagnostics.close")).clicked() {
+                    self.open = false;
+                }
+                
+                let (icon, hint, dock) = match self.dock {
+                    PanelDock::Bottom => ("⏵", language.tr("diagnostics.dock_right"), PanelDock::Right),
+                    PanelDock::Right => ("⏷", language.tr("diagnostics.dock_bottom"), PanelDock::Bottom),
+                };
+                if ui.small_button(icon).on_hover_text(hint).clicked() {
+                    self.dock = dock;
+                }
+            });
+        });
+        
+        ui.separator();
+        
+        let visible: Vec<&Diagnostic> = diagnostics.iter()
+            .filter(|d| self.show_info || d.severity != Severity::Info)
+            .collect();
+        
+        if visible.is_empty() {
+            ui.label(egui::RichText::new(language.tr("diagnostics.no_problems"))
+                .color(palette.success));
+            return;
+        }
+        
+        egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+            for diagnostic in visible {
+                let color = match diagnostic.severity {
+                    Severity::Error => palette.error,
+                    Severity::Warning => palette.warning,
+                    Severity::Info => palette.info,
+                };
+                
+                let node_title = node_graph.get_node_data(diagnostic.node_id)
+                    .map(|node| node.get_title().to_string())
+                    .unwrap_or_default();
+                
+                let depth = report.depths.get(&diagnostic.node_id)
+                    .map(|depth| language.tr_args("diagnostics.depth", &[("depth", depth)]))
+                    .unwrap_or_default();
+                
+                let text = egui::RichText::new(format!(
+                    "{}  {}{} — {}",
+                    diagnostic.severity.label(language),
+                    node_title,
+                    depth,
+                    diagnostic.message
+                )).color(color);
+                
+                if ui.add(egui::Label::new(text).sense(egui::Sense::click()))
+                    .on_hover_text(language.tr("diagnostics.go_to_node"))
+                    .clicked()
+                {
+                    node_graph.focus_node(diagnostic.node_id);
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,347 @@
+use eframe::egui;
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::{Node, NodeId, Parameter};
+use crate::graph::rendering::GraphRenderer;
+use crate::i18n::Language;
+use crate::theme::Theme;
+
+/// Parameters that have to differ between nodes and are not edited in bulk
+const UNIQUE_PARAMS: &[&str] = &["name", "callback_data"];
+
+/// Side panel showing the selected nodes.
+///
+/// A single node is shown with its type, ports, connections, parameters and
+/// the order of its children.
+/// Several nodes of one type share the fields they can have in common, a
+/// value set there goes to all of them. Changes are applied to the graph as
+/// they are made and recorded as one undo step when the field is left.
+pub struct InspectorPanel {
+    pub open: bool,
+    /// Changes applied to the graph that have no undo step yet
+    uncommitted: bool,
+    /// Selection the panel showed last frame
+    shown: Vec<NodeId>,
+    /// Child dragged in the order list, with the output port it hangs from
+    child_drag: Option<(String, NodeId)>,
+}
+
+impl InspectorPanel {
+    pub fn new() -> Self {
+        Self {
+            open: true,
+            uncommitted: false,
+            shown: Vec::new(),
+            child_drag: None,
+        }
+    }
+    
+    pub fn draw(&mut self, ctx: &egui::Context, node_graph: &mut SimpleNodeGraph) {
+        if node_graph.take_inspector_request() {
+            self.open = true;
+        }
+        
+        let selected = node_graph.selected_nodes();
+        if selected != self.shown {
+            // Leaving a node finishes the edit made on it
+            self.commit(node_graph);
+            self.shown = selected.clone();
+        }
+        
+        if !self.open {
+            return;
+        }
+        
+        let palette = Theme::current(ctx).palette();
+        let language = Language::current(ctx);
+        let frame = egui::Frame::none()
+            .fill(palette.panel)
+            .inner_margin(egui::style::Margin::same(8.0));
+        
+        let mut changed = false;
+        egui::SidePanel::right("inspector_panel")
+            .resizable(true)
+            .default_width(320.0)
+            .frame(frame)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::Label::new(egui::RichText::new(language.tr("inspector.title"))
+                        .strong()
+                        .color(palette.text)));
+                    
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("✖").on_hover_text(language.tr("inspector.close")).clicked() {
+                            self.open = false;
+                        }
+                    });
+                });
+                
+                ui.separator();
+                
+                egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    changed = match selected.as_slice() {
+                        [] => {
+                            Self::hint(ui, language.tr("inspector.nothing_selected"));
+                            false
+                        },
+                        [node_id] => {
+                            let changed = Self::draw_node(ui, node_graph, *node_id);
+                            self.draw_children(ui, node_graph, *node_id);
+                            changed
+                        },
+                        _ => Self::draw_group(ui, node_graph, &selected),
+                    };
+                });
+            });
+        
+        if changed {
+            self.uncommitted = true;
+        }
+        // A drag released outside the list is dropped without moving anything
+        if ctx.input(|i| !i.pointer.any_down()) {
+            self.child_drag = None;
+        }
+        // Text fields keep focus while typing, the edit is done when they lose it
+        if ctx.memory(|mem| mem.focus().is_none()) {
+            self.commit(node_graph);
+        }
+    }
+    
+    fn commit(&mut self, node_graph: &mut SimpleNodeGraph) {
+        if std::mem::take(&mut self.uncommitted) {
+            node_graph.save_state();
+        }
+    }
+    
+    fn draw_node(ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, node_id: NodeId) -> bool {
+        let Some(node) = node_graph.get_node_data(node_id).cloned() else {
+            return false;
+        };
+        let mut changed = false;
+        let language = Language::current(ui.ctx());
+        
+        ui.label(egui::RichText::new(node.node_type.label(language))
+            .color(Theme::current(ui.ctx()).palette().text_muted));
+        
+        egui::Grid::new("inspector_node_grid")
+            .num_columns(2)
+            .spacing(egui::vec2(12.0, 8.0))
+            .show(ui, |ui| {
+                ui.label(format!("{}:", language.tr("param.name")));
+                let mut title = node.get_title().to_string();
+                if ui.text_edit_singleline(&mut title).changed() {
+                    changed |= node_graph.set_node_title(node_id, title);
+                }
+                ui.end_row();
+                
+                for param in node.get_params() {
+                    changed |= Self::draw_param(ui, node_graph, &[node_id], param, false);
+                }
+            });
+        
+        ui.add_space(8.0);
+        egui::CollapsingHeader::new(language.tr("inspector.ports")).default_open(false).show(ui, |ui| {
+            for port in node.inputs() {
+                ui.label(format!("⬇ {} ({})", port.label(language), port.id()));
+            }
+            for port in node.outputs() {
+                ui.label(format!("⬆ {} ({})", port.label(language), port.id()));
+            }
+        });
+        
+        egui::CollapsingHeader::new(language.tr("inspector.connections")).default_open(true).show(ui, |ui| {
+            Self::draw_connections(ui, node_graph, &node);
+        });
+        
+        changed
+    }
+    
+    /// Fields shared by all selected nodes, or a summary when their types differ
+    fn draw_group(ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, selected: &[NodeId]) -> bool {
+        let nodes: Vec<Node> = selected.iter()
+            .filter_map(|id| node_graph.get_node_data(*id).cloned())
+            .collect();
+        let Some(first) = nodes.first() else {
+            return false;
+        };
+        let language = Language::current(ui.ctx());
+        
+        if nodes.iter().any(|node| node.node_type != first.node_type) {
+            ui.label(language.tr_args("inspector.selected_nodes", &[("count", &nodes.len())]));
+            Self::hint(ui, language.tr("inspector.mixed_types"));
+            return false;
+        }
+        
+        ui.label(language.tr_args(
+            "inspector.selected_of_type",
+            &[("count", &nodes.len()), ("node_type", &first.node_type.label(language))],
+        ));
+        Self::hint(ui, language.tr("inspector.group_hint"));
+        ui.add_space(4.0);
+        
+        let mut changed = false;
+        egui::Grid::new("inspector_group_grid")
+            .num_columns(2)
+            .spacing(egui::vec2(12.0, 8.0))
+            .show(ui, |ui| {
+                for param in first.get_params().iter().filter(|p| !UNIQUE_PARAMS.contains(&p.id())) {
+                    let mixed = nodes.iter().any(|node| {
+                        node.find_param(param.id()).map(|p| p.value()) != Some(param.value())
+                    });
+                    changed |= Self::draw_param(ui, node_graph, selected, param, mixed);
+                }
+            });
+        changed
+    }
+    
+    /// One grid row editing `param` on all `node_ids`
+    fn draw_param(ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, node_ids: &[NodeId], param: &Parameter, mixed: bool) -> bool {
+        let mut edited = param.clone();
+        let mut changed = false;
+        let language = Language::current(ui.ctx());
+        
+        ui.label(format!("{}:", param.label(language)));
+        ui.vertical(|ui| {
+            ui.push_id(param.id(), |ui| {
+                if GraphRenderer::draw_parameter_widget(ui, &mut edited) {
+                    changed = node_graph.set_parameter_value(node_ids, edited.id(), edited.value()) > 0;
+                }
+            });
+            if mixed {
+                Self::hint(ui, language.tr("inspector.mixed_values"));
+            }
+            if let Err(e) = edited.validate() {
+                ui.colored_label(Theme::current(ui.ctx()).palette().error, e);
+            }
+        });
+        ui.end_row();
+        
+        changed
+    }
+    
+    /// Incoming and outgoing connections, clicking one jumps to the other node
+    fn draw_connections(ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, node: &Node) {
+        let language = Language::current(ui.ctx());
+        let port_label = |node: &Node, port_id: &str, is_input: bool| {
+            let ports = if is_input { node.inputs() } else { node.outputs() };
+            ports.iter()
+                .find(|port| port.id() == port_id)
+                .map(|port| port.label(language).to_string())
+                .unwrap_or_else(|| port_id.to_string())
+        };
+        
+        let links: Vec<(String, NodeId)> = node_graph.connections().iter()
+            .filter_map(|conn| {
+                let (arrow, other_id, own_port, is_input) = if conn.to_node() == node.id() {
+                    ("←", conn.from_node(), conn.to_port(), true)
+                } else if conn.from_node() == node.id() {
+                    ("→", conn.to_node(), conn.from_port(), false)
+                } else {
+                    return None;
+                };
+                let other = node_graph.get_node_data(other_id)?;
+                Some((
+                    format!("{} {} {}", port_label(node, own_port, is_input), arrow, other.get_title()),
+                    other_id,
+                ))
+            })
+            .collect();
+        
+        if links.is_empty() {
+            Self::hint(ui, language.tr("inspector.no_connections"));
+            return;
+        }
+        
+        for (text, other_id) in links {
+            if ui.add(egui::Label::new(text).sense(egui::Sense::click()))
+                .on_hover_text(language.tr("inspector.go_to_node"))
+                .clicked()
+            {
+                node_graph.focus_node(other_id);
+            }
+        }
+    }
+    
+    /// Children in export order for every output port that has several.
+    /// They are moved with the arrows or by dragging the handle, each move
+    /// is an undo step of its own.
+    fn draw_children(&mut self, ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, node_id: NodeId) {
+        let Some(node) = node_graph.get_node_data(node_id) else {
+            return;
+        };
+        let language = Language::current(ui.ctx());
+        let ports: Vec<(String, String, Vec<NodeId>)> = node.outputs().iter()
+            .map(|port| (port.id().to_string(), port.label(language).to_string(), node_graph.get_children(node_id, port.id())))
+            .filter(|(_, _, children)| children.len() > 1)
+            .collect();
+        if ports.is_empty() {
+            return;
+        }
+        let accent = Theme::current(ui.ctx()).palette().accent;
+        
+        egui::CollapsingHeader::new(language.tr("inspector.order")).default_open(true).show(ui, |ui| {
+            for (port_id, label, children) in ports {
+                ui.label(egui::RichText::new(label).strong());
+                
+                let mut rows = Vec::with_capacity(children.len());
+                let mut moved = None;
+                for (index, child_id) in children.iter().copied().enumerate() {
+                    let title = node_graph.get_node_data(child_id)
+                        .map(|child| child.get_title().to_string())
+                        .unwrap_or_default();
+                    let row = ui.horizontal(|ui| {
+                        let handle = ui.add(egui::Label::new("☰").sense(egui::Sense::drag()))
+                            .on_hover_text(language.tr("inspector.drag_to_reorder"));
+                        if handle.hovered() {
+                            ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grab);
+                        }
+                        if handle.drag_started() {
+                            self.child_drag = Some((port_id.clone(), child_id));
+                        }
+                        
+                        if ui.add_enabled(index > 0, egui::Button::new("⬆").small()).on_hover_text(language.tr("inspector.move_up")).clicked() {
+                            moved = Some((child_id, index - 1));
+                        }
+                        if ui.add_enabled(index + 1 < children.len(), egui::Button::new("⬇").small()).on_hover_text(language.tr("inspector.move_down")).clicked() {
+                            moved = Some((child_id, index + 1));
+                        }
+                        if ui.add(egui::Label::new(title).sense(egui::Sense::click()))
+                            .on_hover_text(language.tr("inspector.go_to_node"))
+                            .clicked()
+                        {
+                            node_graph.focus_node(child_id);
+                        }
+                    });
+                    rows.push(row.response.rect);
+                }
+                
+                let dragged = self.child_drag.as_ref()
+                    .filter(|(drag_port, _)| *drag_port == port_id)
+                    .map(|(_, child_id)| *child_id);
+                if let (Some(dragged), Some(pointer)) = (dragged, ui.input(|i| i.pointer.interact_pos())) {
+                    // The child goes before the first row below the pointer
+                    let target = rows.iter().filter(|rect| rect.center().y < pointer.y).count();
+                    let line_y = rows.get(target).map(|rect| rect.top()).unwrap_or_else(|| rows[rows.len() - 1].bottom());
+                    ui.painter().hline(ui.max_rect().x_range(), line_y, egui::Stroke::new(2.0, accent));
+                    
+                    if ui.input(|i| !i.pointer.any_down()) {
+                        // Rows after the dragged child move up once it leaves its place
+                        let from = children.iter().position(|id| *id == dragged).unwrap_or(target);
+                        moved = Some((dragged, if from < target { target - 1 } else { target }));
+                        self.child_drag = None;
+                    }
+                }
+                
+                if let Some((child_id, index)) = moved {
+                    node_graph.move_child(node_id, &port_id, child_id, index);
+                }
+                ui.add_space(4.0);
+            }
+        });
+    }
+    
+    fn hint(ui: &mut egui::Ui, text: &str) {
+        ui.label(egui::RichText::new(text)
+            .small()
+            .color(Theme::current(ui.ctx()).palette().text_muted));
+    }
+}
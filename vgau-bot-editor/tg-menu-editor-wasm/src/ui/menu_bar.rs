@@ -1,32 +1,39 @@
 use eframe::egui;
 use crate::graph::SimpleNodeGraph;
+use crate::graph::alignment::Alignment;
+use crate::graph::rendering::export::{ExportScope, ImageFormat};
+use crate::i18n::Language;
+use crate::theme::Theme;
+use super::app::AppAction;
+use super::tabs::Tab;
 
 pub struct MenuBar {
-    workflow_id: String,
     dirty: bool,
 }
 
 impl MenuBar {
-    pub fn new(workflow_id: String, dirty: bool) -> Self {
+    pub fn new(dirty: bool) -> Self {
         Self {
-            workflow_id,
             dirty,
         }
     }
+    
+    pub fn set_dirty(&mut self, dirty: bool) {
+        self.dirty = dirty;
+    }
 
     pub fn draw(
         &self, 
         ctx: &egui::Context, 
         node_graph: &mut SimpleNodeGraph,
-        status_callback: &mut dyn FnMut(&str, f32),
-        tab_callback: &mut dyn FnMut(super::app::Tab),
-        export_callback: &mut dyn FnMut(),
-        import_from_predefined_callback: &mut dyn FnMut() -> Result<(), String>,
         workflow_metadata: &super::app::WorkflowMetadata,
-    ) {
+    ) -> Vec<AppAction> {
+        let mut actions = Vec::new();
+        let palette = Theme::current(ctx).palette();
+        let language = Language::current(ctx);
         egui::TopBottomPanel::top("menu_bar")
             .frame(egui::Frame::none()
-                .fill(egui::Color32::from_rgb(31, 41, 55)) // bg-gray-800
+                .fill(palette.panel)
                 .shadow(egui::epaint::Shadow::small_light())
             )
             .show(ctx, |ui| {
@@ -35,61 +42,75 @@ impl MenuBar {
                     ui.visuals_mut().button_frame = false;  // No button frames in menu
                     
                     // File menu
-                    ui.menu_button("Файл", |ui| {
-                        ui.style_mut().visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(75, 85, 99); // gray-600
+                    ui.menu_button(language.tr("menu.file"), |ui| {
+                        ui.style_mut().visuals.widgets.hovered.bg_fill = palette.surface;
                         
-                        if ui.button("Новый").clicked() {
+                        if ui.button(language.tr("menu.file.new")).clicked() {
                             // TODO: Add confirmation dialog
-                            status_callback("Создан новый проект", 3.0);
+                            actions.push(AppAction::Status(language.tr("status.project_created").to_string(), 3.0));
                             ui.close_menu();
                         }
                         
-                        if ui.button("Экспорт").clicked() {
-                            export_callback();
-                            tab_callback(super::app::Tab::Export);
+                        if ui.button(language.tr("menu.file.open")).clicked() {
+                            actions.push(AppAction::OpenProject);
                             ui.close_menu();
                         }
                         
-                        if ui.button("Импорт").clicked() {
-                            tab_callback(super::app::Tab::Export);
+                        if ui.button(language.tr("menu.file.save")).clicked() {
+                            actions.push(AppAction::SaveProject);
                             ui.close_menu();
                         }
                         
                         ui.separator();
                         
-                        if ui.button("Сохранить как PNG").clicked() {
-                            status_callback("Сохранение как PNG пока не реализовано", 3.0);
+                        if ui.button(language.tr("menu.file.export")).clicked() {
+                            actions.push(AppAction::Export);
+                            actions.push(AppAction::ShowTab(Tab::Export));
+                            ui.close_menu();
+                        }
+                        
+                        if ui.button(language.tr("menu.file.import")).clicked() {
+                            actions.push(AppAction::ShowTab(Tab::Export));
                             ui.close_menu();
                         }
                         
-                        // Add import telegrambot data button
                         ui.separator();
-                        if ui.button("Импорт данных Telegram-бота ВГАУ").clicked() {
-                            match import_from_predefined_callback() {
-                                Ok(_) => {
-                                    status_callback("Данные Telegram-бота успешно импортированы", 3.0);
+                        
+                        ui.menu_button(language.tr("menu.file.save_image"), |ui| {
+                            let has_selection = !node_graph.selected_nodes().is_empty();
+                            for format in ImageFormat::ALL {
+                                if ui.button(language.tr_args("menu.file.image_all", &[("format", &format.label())])).clicked() {
+                                    actions.push(AppAction::SaveImage(format, ExportScope::All));
+                                    ui.close_menu();
                                 }
-                                Err(e) => {
-                                    status_callback(&format!("Ошибка импорта: {}", e), 3.0);
+                                if ui.add_enabled(has_selection, egui::Button::new(language.tr_args("menu.file.image_selection", &[("format", &format.label())]))).clicked() {
+                                    actions.push(AppAction::SaveImage(format, ExportScope::Selection));
+                                    ui.close_menu();
                                 }
                             }
+                        });
+                        
+                        // Add import telegrambot data button
+                        ui.separator();
+                        if ui.button(language.tr("menu.file.import_bot_data")).clicked() {
+                            actions.push(AppAction::ImportBotData);
                             ui.close_menu();
                         }
                     });
                     
                     // Edit menu
-                    ui.menu_button("Правка", |ui| {
+                    ui.menu_button(language.tr("menu.edit"), |ui| {
                         let can_undo = node_graph.can_undo();
                         let can_redo = node_graph.can_redo();
                         
-                        let undo_text = if can_undo { "Отменить (Ctrl+Z)" } else { "Отменить" };
-                        let redo_text = if can_redo { "Вернуть (Ctrl+Y)" } else { "Вернуть" };
+                        let undo_text = language.tr(if can_undo { "menu.edit.undo_shortcut" } else { "menu.edit.undo" });
+                        let redo_text = language.tr(if can_redo { "menu.edit.redo_shortcut" } else { "menu.edit.redo" });
                         
                         ui.add_enabled(can_undo, egui::Button::new(undo_text))
                             .clicked()
                             .then(|| {
                                 if node_graph.undo() {
-                                    status_callback("Отменено последнее действие", 2.0);
+                                    actions.push(AppAction::Status(language.tr("status.undone").to_string(), 2.0));
                                 }
                                 ui.close_menu();
                             });
@@ -98,120 +119,178 @@ impl MenuBar {
                             .clicked()
                             .then(|| {
                                 if node_graph.redo() {
-                                    status_callback("Действие возвращено", 2.0);
+                                    actions.push(AppAction::Status(language.tr("status.redone").to_string(), 2.0));
                                 }
                                 ui.close_menu();
                             });
                         
                         ui.separator();
                         
-                        if ui.button("Копировать узел").clicked() {
+                        if ui.button(language.tr("menu.edit.find")).clicked() {
+                            actions.push(AppAction::ToggleSearch);
+                            ui.close_menu();
+                        }
+                        
+                        ui.separator();
+                        
+                        if ui.button(language.tr("menu.edit.copy_node")).clicked() {
                             // TODO: Implement copy
-                            status_callback("Копирование узла пока не реализовано", 2.0);
+                            actions.push(AppAction::Status(language.tr("status.copy_unavailable").to_string(), 2.0));
                             ui.close_menu();
                         }
                         
-                        if ui.button("Вставить узел").clicked() {
+                        if ui.button(language.tr("menu.edit.paste_node")).clicked() {
                             // TODO: Implement paste
-                            status_callback("Вставка узла пока не реализована", 2.0);
+                            actions.push(AppAction::Status(language.tr("status.paste_unavailable").to_string(), 2.0));
                             ui.close_menu();
                         }
                         
                         ui.separator();
                         
-                        if ui.button("Удалить выбранный узел").clicked() {
+                        if ui.button(language.tr("menu.edit.delete_node")).clicked() {
                             if let Some(node_id) = node_graph.active_node {
                                 node_graph.delete_node(node_id);
                             } else {
-                                status_callback("Не выбран узел для удаления", 2.0);
+                                actions.push(AppAction::Status(language.tr("status.no_node_to_delete").to_string(), 2.0));
                             }
                             ui.close_menu();
                         }
                     });
                     
                     // Export menu
-                    ui.menu_button("Экспорт", |ui| {
-                        if ui.button("Сформировать JSON").clicked() {
-                            export_callback();
+                    ui.menu_button(language.tr("menu.export"), |ui| {
+                        if ui.button(language.tr("menu.export.json")).clicked() {
+                            actions.push(AppAction::Export);
                             ui.close_menu();
                         }
                         
-                        if ui.button("Скопировать в буфер обмена").clicked() {
-                            status_callback("Копирование в буфер обмена пока не реализовано", 3.0);
+                        if ui.button(language.tr("menu.export.clipboard")).clicked() {
+                            actions.push(AppAction::Status(language.tr("status.clipboard_unavailable").to_string(), 3.0));
                             ui.close_menu();
                         }
                     });
                     
                     // View menu
-                    ui.menu_button("Вид", |ui| {
-                        if ui.button("Редактор").clicked() {
-                            tab_callback(super::app::Tab::Editor);
+                    ui.menu_button(language.tr("menu.view"), |ui| {
+                        if ui.button(language.tr("menu.view.editor")).clicked() {
+                            actions.push(AppAction::ShowTab(Tab::Editor));
                             ui.close_menu();
                         }
                         
-                        if ui.button("Экспорт/Импорт").clicked() {
-                            tab_callback(super::app::Tab::Export);
+                        if ui.button(language.tr("menu.view.export")).clicked() {
+                            actions.push(AppAction::ShowTab(Tab::Export));
                             ui.close_menu();
                         }
                         
-                        if ui.button("Настройки").clicked() {
-                            tab_callback(super::app::Tab::Settings);
+                        if ui.button(language.tr("menu.view.settings")).clicked() {
+                            actions.push(AppAction::ShowTab(Tab::Settings));
+                            ui.close_menu();
+                        }
+                        
+                        if ui.button(language.tr("menu.view.diagnostics")).clicked() {
+                            actions.push(AppAction::ToggleDiagnostics);
+                            ui.close_menu();
+                        }
+                        
+                        if ui.button(language.tr("menu.view.inspector")).clicked() {
+                            actions.push(AppAction::ToggleInspector);
+                            ui.close_menu();
+                        }
+                        
+                        if ui.button(language.tr("menu.view.outline")).clicked() {
+                            actions.push(AppAction::ToggleOutline);
                             ui.close_menu();
                         }
                         
                         ui.separator();
                         
-                        if ui.button("Сбросить масштаб").clicked() {
-                            // TODO: Reset zoom level
-                            status_callback("Масштаб сброшен", 2.0);
+                        if ui.button(language.tr("menu.view.reset_zoom")).clicked() {
+                            node_graph.reset_zoom();
+                            actions.push(AppAction::Status(language.tr("status.zoom_reset").to_string(), 2.0));
+                            ui.close_menu();
+                        }
+                        
+                        if ui.button(language.tr("menu.view.fit_all")).clicked() {
+                            if node_graph.fit_all() {
+                                actions.push(AppAction::Status(language.tr("status.graph_centered").to_string(), 2.0));
+                            } else {
+                                actions.push(AppAction::Status(language.tr("status.graph_empty").to_string(), 2.0));
+                            }
                             ui.close_menu();
                         }
                         
-                        if ui.button("Центрировать граф").clicked() {
-                            // TODO: Center graph
-                            status_callback("Граф центрирован", 2.0);
+                        if ui.button(language.tr("menu.view.fit_selection")).clicked() {
+                            if !node_graph.fit_selection() {
+                                actions.push(AppAction::Status(language.tr("status.no_nodes_selected").to_string(), 2.0));
+                            }
                             ui.close_menu();
                         }
+                        
+                        ui.checkbox(&mut node_graph.show_minimap, language.tr("menu.view.minimap"));
                     });
                     
                     // Nodes menu
-                    ui.menu_button("Узлы", |ui| {
-                        if ui.button("Добавить пункт меню").clicked() {
+                    ui.menu_button(language.tr("menu.nodes"), |ui| {
+                        if ui.button(language.tr("canvas.add_menu_item")).clicked() {
                             // Add new menu item at center position
                             node_graph.add_menu_item(
                                 egui::pos2(300.0, 300.0),
-                                "Новый пункт меню".to_string()
+                                language.tr("node.new_menu_item").to_string()
                             );
                             ui.close_menu();
                         }
                         
-                        if ui.button("Добавить FAQ").clicked() {
+                        if ui.button(language.tr("canvas.add_faq")).clicked() {
                             // Add new FAQ item at center position
                             node_graph.add_faq_item(
                                 egui::pos2(300.0, 300.0),
-                                "Новый FAQ".to_string()
+                                language.tr("node.new_faq").to_string()
                             );
                             ui.close_menu();
                         }
                         
                         ui.separator();
                         
-                        if ui.button("Организовать узлы").clicked() {
-                            // TODO: Auto-organize nodes
-                            status_callback("Автоматическая организация узлов пока не реализована", 3.0);
+                        if ui.button(language.tr("canvas.auto_layout")).clicked() {
+                            node_graph.auto_layout(false);
+                            ui.close_menu();
+                        }
+                        
+                        let has_selection = !node_graph.selected_nodes().is_empty();
+                        if ui.add_enabled(has_selection, egui::Button::new(language.tr("menu.nodes.layout_selected"))).clicked() {
+                            node_graph.auto_layout(true);
                             ui.close_menu();
                         }
+                        
+                        let selected = node_graph.selected_nodes().len();
+                        ui.add_enabled_ui(selected > 1, |ui| {
+                            ui.menu_button(language.tr("menu.nodes.align"), |ui| {
+                                for alignment in Alignment::ALL {
+                                    if ui.button(alignment.label(language)).clicked() {
+                                        node_graph.align_selected(alignment);
+                                        ui.close_menu();
+                                    }
+                                }
+                                
+                                ui.separator();
+                                
+                                if ui.add_enabled(selected > 2, egui::Button::new(language.tr("canvas.distribute_vertically"))).clicked() {
+                                    node_graph.distribute_selected_vertically();
+                                    ui.close_menu();
+                                }
+                            });
+                        });
                     });
                     
                     // Help menu
-                    ui.menu_button("Помощь", |ui| {
-                        if ui.button("О редакторе").clicked() {
-                            tab_callback(super::app::Tab::Help);
+                    ui.menu_button(language.tr("menu.help"), |ui| {
+                        if ui.button(language.tr("menu.help.about")).clicked() {
+                            actions.push(AppAction::ShowTab(Tab::Help));
                             ui.close_menu();
                         }
                         
-                        if ui.button("Документация").clicked() {
-                            status_callback("Открытие документации пока не реализовано", 3.0);
+                        if ui.button(language.tr("menu.help.docs")).clicked() {
+                            actions.push(AppAction::Status(language.tr("status.docs_unavailable").to_string(), 3.0));
                             ui.close_menu();
                         }
                     });
@@ -224,9 +303,10 @@ impl MenuBar {
                         );
                         ui.add(egui::Label::new(egui::RichText::new(workflow_name)
                             .text_style(egui::TextStyle::Heading)
-                            .color(egui::Color32::from_rgb(229, 231, 235)))); // gray-200
+                            .color(palette.text_strong)));
                     });
                 });
             });
+        actions
     }
 } 
\ No newline at end of file
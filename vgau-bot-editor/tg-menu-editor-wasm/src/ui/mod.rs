@@ -1,6 +1,10 @@
 // UI module for the menu editor app
 mod app;
+mod diagnostics_panel;
+mod inspector_panel;
 mod menu_bar;
+mod outline_panel;
+mod search_palette;
 mod status_bar;
 mod tabs;
 mod utils;
@@ -0,0 +1,336 @@
+use eframe::egui;
+use crate::graph::SimpleNodeGraph;
+use crate::graph::models::NodeId;
+use crate::graph::outline::{DropPlace, Outline, OutlineKind, OutlineMove, OutlineRow, OutlineStep};
+use crate::i18n::Language;
+use crate::theme::Theme;
+
+/// Pixels each level of the tree is indented by
+const INDENT: f32 = 14.0;
+
+/// Side panel showing the menu as a tree next to the canvas.
+///
+/// Clicking a row selects its node on the canvas, picking a node on the
+/// canvas puts the cursor on its row. Rows are dragged onto other rows to
+/// reorder them or move them to another menu item, which rewires the
+/// connections. Once clicked, the tree is used from the keyboard alone and
+/// the canvas shortcuts wait until it loses focus.
+pub struct OutlinePanel {
+    pub open: bool,
+    outline: Outline,
+    /// Row the keyboard works on
+    cursor: Option<(Option<NodeId>, NodeId)>,
+    /// Active node the cursor was put on last, another one was picked on the canvas
+    synced: Option<NodeId>,
+    /// Row being dragged
+    drag: Option<OutlineRow>,
+    /// Node being renamed, with the text typed so far
+    renaming: Option<(NodeId, String)>,
+    /// Whether the cursor row has to be scrolled into view
+    scroll_to_cursor: bool,
+}
+
+impl OutlinePanel {
+    pub fn new() -> Self {
+        Self {
+            open: true,
+            outline: Outline::new(),
+            cursor: None,
+            synced: None,
+            drag: None,
+            renaming: None,
+            scroll_to_cursor: false,
+        }
+    }
+    
+    pub fn draw(&mut self, ctx: &egui::Context, node_graph: &mut SimpleNodeGraph) {
+        if !self.open {
+            return;
+        }
+        
+        // A node picked on the canvas gets its row shown
+        if node_graph.active_node != self.synced {
+            self.synced = node_graph.active_node;
+            if let Some(node_id) = self.synced {
+                self.outline.reveal(node_graph, node_id);
+                let rows = self.outline.rows(node_graph);
+                let on_node = self.cursor.is_some_and(|(_, id)| id == node_id);
+                if let Some(row) = rows.iter().find(|row| row.node_id == node_id).filter(|_| !on_node) {
+                    self.cursor = Some(row.key());
+                }
+                self.scroll_to_cursor = true;
+            }
+        }
+        
+        let palette = Theme::current(ctx).palette();
+        let language = Language::current(ctx);
+        let frame = egui::Frame::none()
+            .fill(palette.panel)
+            .inner_margin(egui::style::Margin::same(8.0));
+        
+        egui::SidePanel::left("outline_panel")
+            .resizable(true)
+            .default_width(260.0)
+            .frame(frame)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.add(egui::Label::new(egui::RichText::new(language.tr("outline.title"))
+                        .strong()
+                        .color(palette.text)));
+                    
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.small_button("✖").on_hover_text(language.tr("outline.close")).clicked() {
+                            self.open = false;
+                        }
+                    });
+                });
+                ui.label(egui::RichText::new(language.tr("outline.keys"))
+                    .small()
+                    .color(palette.text_muted));
+                
+                ui.separator();
+                
+                let rows = self.outline.rows(node_graph);
+                let focus_id = ui.id().with("outline_focus");
+                let has_focus = ui.memory(|mem| mem.has_focus(focus_id));
+                let mut rects = Vec::with_capacity(rows.len());
+                
+                let scroll = egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    if rows.is_empty() {
+                        ui.label(egui::RichText::new(language.tr("outline.empty")).color(palette.text_muted));
+                    }
+                    for (index, row) in rows.iter().enumerate() {
+                        let section_starts = rows[..index].last().is_none_or(|previous| {
+                            (previous.kind == OutlineKind::Faq) != (row.kind == OutlineKind::Faq)
+                        });
+                        if section_starts {
+                            let section = if row.kind == OutlineKind::Faq { "outline.faq" } else { "outline.menu" };
+                            ui.label(egui::RichText::new(language.tr(section)).strong().color(palette.text_muted));
+                        }
+                        rects.push(self.draw_row(ui, node_graph, row, has_focus, focus_id, language));
+                    }
+                });
+                
+                // The list holds the keyboard focus, the rows only take clicks
+                let list = ui.interact(scroll.inner_rect, focus_id, egui::Sense::focusable_noninteractive());
+                if list.clicked_elsewhere() {
+                    ui.memory_mut(|mem| mem.surrender_focus(focus_id));
+                }
+                if has_focus && self.renaming.is_none() {
+                    self.handle_keys(ui, node_graph, &rows);
+                }
+                self.handle_drop(ui, node_graph, &rows, &rects, palette.accent);
+            });
+    }
+    
+    /// Draw a row and return where it is
+    fn draw_row(
+        &mut self,
+        ui: &mut egui::Ui,
+        node_graph: &mut SimpleNodeGraph,
+        row: &OutlineRow,
+        has_focus: bool,
+        focus_id: egui::Id,
+        language: Language,
+    ) -> egui::Rect {
+        let accent = Theme::current(ui.ctx()).palette().accent;
+        let is_cursor = self.cursor == Some(row.key());
+        let mut renamed = None;
+        
+        let response = ui.horizontal(|ui| {
+            ui.add_space(row.depth as f32 * INDENT);
+            if row.has_children {
+                let (icon, hint) = if row.expanded { ("⏷", "outline.collapse") } else { ("⏵", "outline.expand") };
+                if ui.small_button(icon).on_hover_text(language.tr(hint)).clicked() {
+                    self.outline.set_expanded(row.node_id, !row.expanded);
+                }
+            } else {
+                ui.add_space(INDENT + ui.spacing().button_padding.x * 2.0);
+            }
+            ui.label(row.kind.icon());
+            
+            let editing = self.renaming.as_mut().filter(|(node_id, _)| *node_id == row.node_id && is_cursor);
+            if let Some((_, text)) = editing {
+                let edit = ui.add(egui::TextEdit::singleline(text).id(Self::rename_id()));
+                if edit.lost_focus() {
+                    // Escape takes the focus away as well, it leaves the title as it was
+                    let cancelled = ui.input(|i| i.key_pressed(egui::Key::Escape));
+                    renamed = Some(!cancelled);
+                }
+                return;
+            }
+            
+            let title = node_graph.get_node_data(row.node_id)
+                .map(|node| node.get_title().to_string())
+                .unwrap_or_default();
+            let label = ui.add(egui::SelectableLabel::new(node_graph.is_selected(row.node_id), title.clone()))
+                .interact(egui::Sense::click_and_drag())
+                .on_hover_text(language.tr("outline.drag"));
+            if label.clicked() {
+                let toggle = ui.input(|i| i.modifiers.command || i.modifiers.shift);
+                self.pick(node_graph, row, toggle);
+                ui.memory_mut(|mem| mem.request_focus(focus_id));
+            }
+            if label.double_clicked() {
+                self.start_renaming(ui, row.node_id, title);
+            }
+            if label.drag_started() {
+                self.drag = Some(row.clone());
+            }
+        }).response;
+        
+        match (renamed, self.renaming.take()) {
+            (Some(true), Some((node_id, title))) => {
+                let unchanged = node_graph.get_node_data(node_id).is_none_or(|node| node.get_title() == title);
+                if !title.trim().is_empty() && !unchanged && node_graph.set_node_title(node_id, title) {
+                    node_graph.save_state();
+                }
+                ui.memory_mut(|mem| mem.request_focus(focus_id));
+            },
+            (Some(false), Some(_)) => ui.memory_mut(|mem| mem.request_focus(focus_id)),
+            (_, renaming) => self.renaming = renaming,
+        }
+        
+        if is_cursor && has_focus {
+            ui.painter().rect_stroke(response.rect.expand(1.0), 2.0, egui::Stroke::new(1.0, accent));
+        }
+        if is_cursor && self.scroll_to_cursor {
+            response.scroll_to_me(None);
+            self.scroll_to_cursor = false;
+        }
+        response.rect
+    }
+    
+    fn rename_id() -> egui::Id {
+        egui::Id::new("outline_rename")
+    }
+    
+    fn start_renaming(&mut self, ui: &egui::Ui, node_id: NodeId, title: String) {
+        self.renaming = Some((node_id, title));
+        ui.memory_mut(|mem| mem.request_focus(Self::rename_id()));
+    }
+    
+    /// Put the cursor on a row and select its node on the canvas, or add it
+    /// to the selection and take it out again when `toggle`
+    fn pick(&mut self, node_graph: &mut SimpleNodeGraph, row: &OutlineRow, toggle: bool) {
+        self.cursor = Some(row.key());
+        if toggle {
+            node_graph.toggle_node_selection(row.node_id);
+        } else {
+            node_graph.select_node(row.node_id, false);
+            node_graph.scroll_to_node(row.node_id);
+        }
+        // Picked here, so the canvas sync leaves the cursor alone
+        self.synced = node_graph.active_node;
+    }
+    
+    fn handle_keys(&mut self, ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, rows: &[OutlineRow]) {
+        if rows.is_empty() {
+            return;
+        }
+        let (step, movement, rename, show) = ui.input(|i| {
+            let arrow = [
+                (egui::Key::ArrowUp, OutlineStep::Up, OutlineMove::Up),
+                (egui::Key::ArrowDown, OutlineStep::Down, OutlineMove::Down),
+                (egui::Key::ArrowLeft, OutlineStep::Left, OutlineMove::Outdent),
+                (egui::Key::ArrowRight, OutlineStep::Right, OutlineMove::Indent),
+            ].into_iter().find(|(key, _, _)| i.key_pressed(*key));
+            
+            let step = if i.key_pressed(egui::Key::Home) {
+                Some(OutlineStep::First)
+            } else if i.key_pressed(egui::Key::End) {
+                Some(OutlineStep::Last)
+            } else {
+                arrow.filter(|_| !i.modifiers.alt).map(|(_, step, _)| step)
+            };
+            let movement = arrow.filter(|_| i.modifiers.alt).map(|(_, _, movement)| movement);
+            (step, movement, i.key_pressed(egui::Key::F2), i.key_pressed(egui::Key::Enter))
+        });
+        
+        let Some(cursor) = self.cursor.and_then(|key| rows.iter().position(|row| row.key() == key)) else {
+            // The first key puts the cursor on the first row
+            if step.is_some() || movement.is_some() {
+                self.pick(node_graph, &rows[0], false);
+                self.scroll_to_cursor = true;
+            }
+            return;
+        };
+        let row = &rows[cursor];
+        
+        if let Some(step) = step {
+            let next = self.outline.step(rows, cursor, step);
+            if next != cursor {
+                self.pick(node_graph, &rows[next], false);
+                self.scroll_to_cursor = true;
+            }
+        }
+        if let Some(movement) = movement {
+            if self.outline.move_row(node_graph, rows, cursor, movement) {
+                // After a level change the row is under another parent
+                let moved = self.outline.rows(node_graph);
+                self.cursor = moved.iter()
+                    .find(|other| other.key() == row.key())
+                    .or_else(|| moved.iter().find(|other| other.node_id == row.node_id))
+                    .map(|other| other.key());
+                self.scroll_to_cursor = true;
+            }
+        }
+        if rename {
+            if let Some(node) = node_graph.get_node_data(row.node_id) {
+                let title = node.get_title().to_string();
+                self.start_renaming(ui, row.node_id, title);
+            }
+        }
+        if show {
+            node_graph.focus_node(row.node_id);
+            self.synced = node_graph.active_node;
+        }
+    }
+    
+    /// Show where the dragged row would go and move it there when the button is released
+    fn handle_drop(&mut self, ui: &mut egui::Ui, node_graph: &mut SimpleNodeGraph, rows: &[OutlineRow], rects: &[egui::Rect], accent: egui::Color32) {
+        let Some(dragged) = self.drag.clone() else {
+            return;
+        };
+        ui.output_mut(|o| o.cursor_icon = egui::CursorIcon::Grabbing);
+        
+        let pointer = ui.input(|i| i.pointer.interact_pos());
+        let target = pointer.and_then(|pointer| {
+            let index = rects.iter().position(|rect| rect.top() <= pointer.y && pointer.y < rect.bottom())?;
+            Some((index, pointer))
+        });
+        if let Some((index, pointer)) = target {
+            let (row, rect) = (&rows[index], rects[index]);
+            // The middle of a menu item puts the row inside it, the edges next to it
+            let edge = rect.height() / 4.0;
+            let place = if row.kind == OutlineKind::Menu && pointer.y > rect.top() + edge && pointer.y < rect.bottom() - edge {
+                DropPlace::Inside
+            } else if pointer.y < rect.center().y {
+                DropPlace::Before
+            } else {
+                DropPlace::After
+            };
+            
+            let stroke = egui::Stroke::new(2.0, accent);
+            match place {
+                DropPlace::Inside => ui.painter().rect_stroke(rect, 2.0, stroke),
+                DropPlace::Before => ui.painter().hline(rect.x_range(), rect.top(), stroke),
+                DropPlace::After => ui.painter().hline(rect.x_range(), rect.bottom(), stroke),
+            }
+            
+            if ui.input(|i| !i.pointer.any_down()) && self.outline.drop_row(node_graph, &dragged, row, place) {
+                let moved = self.outline.rows(node_graph);
+                self.cursor = moved.iter()
+                    .find(|other| other.node_id == dragged.node_id && other.parent != dragged.parent)
+                    .or_else(|| moved.iter().find(|other| other.key() == dragged.key()))
+                    .map(|other| other.key());
+                self.scroll_to_cursor = true;
+            }
+        }
+        
+        // A drag released outside the rows is dropped without moving anything
+        if ui.input(|i| !i.pointer.any_down()) {
+            self.drag = None;
+        }
+    }
+}
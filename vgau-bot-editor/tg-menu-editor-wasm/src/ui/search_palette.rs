@@ -0,0 +1,139 @@
+use eframe::egui;
+use crate::graph::SimpleNodeGraph;
+use crate::graph::search::GraphSearch;
+use crate::i18n::Language;
+use crate::theme::Theme;
+
+/// Results shown at once, the rest is reached by typing more
+const MAX_RESULTS: usize = 50;
+
+/// Ctrl+F / Ctrl+P palette finding nodes by title, callback_data, URL and text.
+/// Picking a result selects the node and centres the canvas on it.
+pub struct SearchPalette {
+    pub open: bool,
+    query: String,
+    /// Result picked with Enter
+    highlighted: usize,
+    /// Give the query field focus on the next frame
+    focus_query: bool,
+}
+
+impl SearchPalette {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            query: String::new(),
+            highlighted: 0,
+            focus_query: false,
+        }
+    }
+    
+    /// Open the palette, keeping the previous query, or close it
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+        self.focus_query = self.open;
+        self.highlighted = 0;
+    }
+    
+    pub fn draw(&mut self, ctx: &egui::Context, node_graph: &mut SimpleNodeGraph) {
+        if !self.open {
+            return;
+        }
+        
+        let results = GraphSearch::search(node_graph, &self.query, MAX_RESULTS);
+        
+        let (up, down, enter, escape) = ctx.input(|i| (
+            i.key_pressed(egui::Key::ArrowUp),
+            i.key_pressed(egui::Key::ArrowDown),
+            i.key_pressed(egui::Key::Enter),
+            i.key_pressed(egui::Key::Escape),
+        ));
+        if escape {
+            self.open = false;
+            return;
+        }
+        if down {
+            self.highlighted = (self.highlighted + 1).min(results.len().saturating_sub(1));
+        }
+        if up {
+            self.highlighted = self.highlighted.saturating_sub(1);
+        }
+        
+        let mut picked = if enter { results.get(self.highlighted).map(|r| r.node_id) } else { None };
+        let mut open = self.open;
+        let palette = Theme::current(ctx).palette();
+        let language = Language::current(ctx);
+        
+        egui::Window::new(language.tr("search.title"))
+            .id(egui::Id::new("search_palette"))
+            .open(&mut open)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 60.0))
+            .default_width(520.0)
+            .show(ctx, |ui| {
+                let response = ui.add(egui::TextEdit::singleline(&mut self.query)
+                    .hint_text(language.tr("search.hint"))
+                    .desired_width(f32::INFINITY));
+                if self.focus_query {
+                    response.request_focus();
+                    self.focus_query = false;
+                }
+                if response.changed() {
+                    self.highlighted = 0;
+                }
+                
+                ui.separator();
+                
+                if self.query.trim().is_empty() {
+                    ui.label(egui::RichText::new(language.tr("search.empty_query"))
+                        .color(palette.text_muted));
+                    return;
+                }
+                if results.is_empty() {
+                    ui.label(egui::RichText::new(language.tr("search.nothing_found"))
+                        .color(palette.text_muted));
+                    return;
+                }
+                
+                egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                    for (index, result) in results.iter().enumerate() {
+                        let mut job = egui::text::LayoutJob::default();
+                        job.append(&result.title, 0.0, egui::TextFormat {
+                            font_id: egui::FontId::proportional(14.0),
+                            color: palette.text_strong,
+                            ..Default::default()
+                        });
+                        
+                        let breadcrumb = result.breadcrumb();
+                        let field = language.tr(result.field);
+                        let details = if breadcrumb.is_empty() {
+                            format!("\n{}: {}", field, result.snippet())
+                        } else {
+                            format!("\n{}  ·  {}: {}", breadcrumb, field, result.snippet())
+                        };
+                        job.append(&details, 0.0, egui::TextFormat {
+                            font_id: egui::FontId::proportional(12.0),
+                            color: palette.text_muted,
+                            ..Default::default()
+                        });
+                        
+                        let highlighted = index == self.highlighted;
+                        let response = ui.add(egui::SelectableLabel::new(highlighted, job));
+                        if highlighted && (up || down) {
+                            response.scroll_to_me(None);
+                        }
+                        if response.clicked() {
+                            picked = Some(result.node_id);
+                        }
+                    }
+                });
+            });
+        
+        if let Some(node_id) = picked {
+            node_graph.focus_node(node_id);
+            open = false;
+        }
+        self.open = open;
+    }
+}
@@ -1,5 +1,7 @@
 use eframe::egui;
 use crate::graph::SimpleNodeGraph;
+use crate::i18n::Language;
+use crate::theme::Theme;
 
 pub struct StatusBar {
     workflow_id: String,
@@ -17,16 +19,18 @@ impl StatusBar {
     }
 
     pub fn draw(&mut self, ctx: &egui::Context, node_graph: &SimpleNodeGraph, ui_ctx: &egui::Context) {
+        let palette = Theme::current(ctx).palette();
+        let language = Language::current(ctx);
         egui::TopBottomPanel::bottom("status_bar")
             .frame(egui::Frame::none()
-                .fill(egui::Color32::from_rgb(31, 41, 55)) // bg-gray-800
+                .fill(palette.panel)
             )
             .show(ctx, |ui| {
                 ui.horizontal(|ui| {
                     // Status message with Tailwind-like styling
                     if let Some((message, time_left)) = &mut self.status_message {
                         ui.add(egui::Label::new(egui::RichText::new(message.as_str())
-                            .color(egui::Color32::from_rgb(156, 163, 175)))); // gray-400
+                            .color(palette.text_muted)));
                         
                         // Update timer
                         *time_left -= ui_ctx.input(|i| i.predicted_dt);
@@ -35,12 +39,12 @@ impl StatusBar {
                         }
                     } else {
                         let status_text = if self.dirty {
-                            "Несохраненные изменения"
+                            language.tr("status.unsaved_changes")
                         } else {
-                            "Готов к работе"
+                            language.tr("status.ready")
                         };
                         ui.add(egui::Label::new(egui::RichText::new(status_text)
-                            .color(egui::Color32::from_rgb(156, 163, 175)))); // gray-400
+                            .color(palette.text_muted)));
                     }
                     
                     // Show undo/redo status in the middle
@@ -48,19 +52,19 @@ impl StatusBar {
                         let can_undo = node_graph.can_undo();
                         let can_redo = node_graph.can_redo();
                         
-                        let undo_text = if can_undo { "⟲ Отменить (Ctrl+Z)" } else { "⟲ Отменить" };
-                        let redo_text = if can_redo { "⟳ Вернуть (Ctrl+Y)" } else { "⟳ Вернуть" };
+                        let undo_text = language.tr(if can_undo { "status.undo_shortcut" } else { "status.undo" });
+                        let redo_text = language.tr(if can_redo { "status.redo_shortcut" } else { "status.redo" });
                         
                         let undo_color = if can_undo { 
-                            egui::Color32::from_rgb(156, 220, 254) // Light blue
+                            palette.info
                         } else {
-                            egui::Color32::from_rgb(100, 100, 100) // Disabled gray
+                            palette.text_muted.gamma_multiply(0.5) // Disabled
                         };
                         
                         let redo_color = if can_redo {
-                            egui::Color32::from_rgb(156, 220, 254) // Light blue
+                            palette.info
                         } else {
-                            egui::Color32::from_rgb(100, 100, 100) // Disabled gray
+                            palette.text_muted.gamma_multiply(0.5) // Disabled
                         };
                         
                         ui.add(egui::Label::new(egui::RichText::new(undo_text).color(undo_color)));
@@ -70,8 +74,8 @@ impl StatusBar {
                     
                     // Right-aligned content
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                        ui.add(egui::Label::new(egui::RichText::new("ВГАУ Бот Редактор v1.0")
-                            .color(egui::Color32::from_rgb(156, 163, 175)))); // gray-400
+                        ui.add(egui::Label::new(egui::RichText::new(language.tr("help.version"))
+                            .color(palette.text_muted)));
                         
                         // Display workflow ID with shortened format
                         let id_short = if self.workflow_id.len() > 8 {
@@ -80,7 +84,7 @@ impl StatusBar {
                             format!("ID: {}", self.workflow_id)
                         };
                         ui.add(egui::Label::new(egui::RichText::new(id_short)
-                            .color(egui::Color32::from_rgb(156, 163, 175)))); // gray-400
+                            .color(palette.text_muted)));
                     });
                 });
             });
@@ -90,21 +94,7 @@ impl StatusBar {
         self.status_message = Some((message.to_string(), duration));
     }
     
-    pub fn update_status_if_none(&mut self, message: &str, duration: f32) {
-        if self.status_message.is_none() {
-            self.status_message = Some((message.to_string(), duration));
-        }
-    }
-    
-    pub fn clear_status(&mut self) {
-        self.status_message = None;
-    }
-    
     pub fn set_dirty(&mut self, dirty: bool) {
         self.dirty = dirty;
     }
-    
-    pub fn is_dirty(&self) -> bool {
-        self.dirty
-    }
 } 
\ No newline at end of file
@@ -1,6 +1,13 @@
 use eframe::egui;
 use crate::graph::SimpleNodeGraph;
+use crate::i18n::Language;
+use crate::import_data::ImportMode;
+use crate::schema::ValidationError;
+use crate::settings::{ConnectionStyle, EditorSettings};
+use crate::theme::{Palette, Theme};
+use super::app::AppAction;
 
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Tab {
     Editor,
     Export,
@@ -8,6 +15,15 @@ pub enum Tab {
     Help,
 }
 
+/// What the export tab shows and edits, borrowed from the app
+pub struct ExportView<'a> {
+    pub exported_data: &'a str,
+    pub import_mode: &'a mut ImportMode,
+    pub import_text: &'a mut String,
+    pub import_errors: &'a [ValidationError],
+    pub workflow_metadata: &'a super::app::WorkflowMetadata,
+}
+
 pub struct Tabs {
     current_tab: Tab,
 }
@@ -23,72 +39,75 @@ impl Tabs {
         &mut self,
         ui: &mut egui::Ui,
         node_graph: &mut SimpleNodeGraph,
-        status_callback: &mut dyn FnMut(&str, f32),
-        export_callback: &mut dyn FnMut(),
-        import_callback: &mut dyn FnMut(),
-        workflow_metadata: &super::app::WorkflowMetadata,
-    ) {
+        settings: &mut EditorSettings,
+        export_view: ExportView,
+    ) -> Vec<AppAction> {
+        let mut actions = Vec::new();
         match self.current_tab {
-            Tab::Editor => self.draw_editor_tab(ui, node_graph, status_callback),
-            Tab::Export => self.draw_export_tab(ui, export_callback, import_callback, workflow_metadata),
-            Tab::Settings => self.draw_settings_tab(ui, status_callback),
+            Tab::Editor => self.draw_editor_tab(ui, node_graph, &mut actions),
+            Tab::Export => self.draw_export_tab(ui, &mut actions, export_view),
+            Tab::Settings => self.draw_settings_tab(ui, settings, &mut actions),
             Tab::Help => self.draw_help_tab(ui),
         }
+        actions
     }
 
     fn draw_editor_tab(
         &mut self,
         ui: &mut egui::Ui,
         node_graph: &mut SimpleNodeGraph,
-        status_callback: &mut dyn FnMut(&str, f32),
+        actions: &mut Vec<AppAction>,
     ) {
+        let palette = Theme::current(ui.ctx()).palette();
+        let language = Language::current(ui.ctx());
+        
         // Style for the content area
         ui.spacing_mut().item_spacing = egui::vec2(12.0, 12.0); // Match Tailwind spacing
         
         // Add top toolbar with tailwind-like styling
         egui::Frame::none()
-            .fill(egui::Color32::from_rgb(55, 65, 81)) // bg-gray-700
+            .fill(palette.surface)
             .rounding(egui::Rounding::same(6.0))
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
-                    ui.add(egui::Label::new(egui::RichText::new("Масштаб:")
-                        .color(egui::Color32::from_rgb(209, 213, 219)))); // gray-300
+                    ui.add(egui::Label::new(egui::RichText::new(language.tr("editor.zoom"))
+                        .color(palette.text)));
                     
                     // Button styling
                     let button_style = |ui: &mut egui::Ui| {
-                        ui.visuals_mut().widgets.inactive.bg_fill = egui::Color32::from_rgb(75, 85, 99); // gray-600
-                        ui.visuals_mut().widgets.active.bg_fill = egui::Color32::from_rgb(59, 130, 246); // blue-500
-                        ui.visuals_mut().widgets.hovered.bg_fill = egui::Color32::from_rgb(96, 165, 250); // blue-400
+                        ui.visuals_mut().widgets.inactive.bg_fill = palette.border;
+                        ui.visuals_mut().widgets.active.bg_fill = palette.accent;
+                        ui.visuals_mut().widgets.hovered.bg_fill = palette.accent_hover;
                     };
                     
                     // Apply button style
                     button_style(ui);
                     
                     if ui.button("➖").clicked() {
-                        status_callback("Уменьшение масштаба пока не реализовано", 2.0);
+                        actions.push(AppAction::Status(language.tr("status.zoom_out_unavailable").to_string(), 2.0));
                     }
                     
                     if ui.button("🔍").clicked() {
-                        status_callback("Сброс масштаба пока не реализован", 2.0);
+                        actions.push(AppAction::Status(language.tr("status.zoom_reset_unavailable").to_string(), 2.0));
                     }
                     
                     if ui.button("➕").clicked() {
-                        status_callback("Увеличение масштаба пока не реализовано", 2.0);
+                        actions.push(AppAction::Status(language.tr("status.zoom_in_unavailable").to_string(), 2.0));
                     }
                     
                     ui.separator();
                     
                     // More buttons with same styling
-                    if ui.button("📋 Копировать").clicked() {
-                        status_callback("Копирование узла пока не реализовано", 2.0);
+                    if ui.button(language.tr("editor.copy")).clicked() {
+                        actions.push(AppAction::Status(language.tr("status.copy_unavailable").to_string(), 2.0));
                     }
                     
-                    if ui.button("📋 Вставить").clicked() {
-                        status_callback("Вставка узла пока не реализована", 2.0);
+                    if ui.button(language.tr("editor.paste")).clicked() {
+                        actions.push(AppAction::Status(language.tr("status.paste_unavailable").to_string(), 2.0));
                     }
                     
-                    if ui.button("🗑️ Удалить").clicked() {
-                        status_callback("Удаление узла пока не реализовано", 2.0);
+                    if ui.button(language.tr("editor.delete")).clicked() {
+                        actions.push(AppAction::Status(language.tr("status.delete_unavailable").to_string(), 2.0));
                     }
                 });
             });
@@ -97,75 +116,82 @@ impl Tabs {
         
         // Create a frame for the node graph area
         egui::Frame::none()
-            .fill(egui::Color32::from_rgb(31, 41, 55)) // bg-gray-800 
+            .fill(palette.panel)
             .rounding(egui::Rounding::same(8.0))
-            .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(55, 65, 81))) // gray-700 border
+            .stroke(egui::Stroke::new(1.0, palette.surface))
             .inner_margin(egui::style::Margin::same(12.0))
             .show(ui, |ui| {
                 // Draw the node graph
                 node_graph.draw(ui);
             });
+        
+        if let Some(error) = node_graph.take_paste_error() {
+            actions.push(AppAction::Status(language.tr_args("status.paste_error", &[("error", &error)]), 3.0));
+        }
     }
 
     fn draw_export_tab(
         &mut self,
         ui: &mut egui::Ui,
-        export_callback: &mut dyn FnMut(),
-        import_callback: &mut dyn FnMut(),
-        workflow_metadata: &super::app::WorkflowMetadata,
+        actions: &mut Vec<AppAction>,
+        export_view: ExportView,
     ) {
+        let ExportView { exported_data, import_mode, import_text, import_errors, workflow_metadata } = export_view;
+        let palette = Theme::current(ui.ctx()).palette();
+        let language = Language::current(ui.ctx());
+        
         // Style ui for Tailwind-like appearance
         ui.spacing_mut().item_spacing = egui::vec2(12.0, 12.0);
         
         ui.vertical(|ui| {
-            ui.add(egui::Label::new(egui::RichText::new("Экспорт / Импорт данных")
+            ui.add(egui::Label::new(egui::RichText::new(language.tr("export.title"))
                 .size(24.0)
-                .color(egui::Color32::from_rgb(209, 213, 219)))); // gray-300
+                .color(palette.text)));
             
             ui.add_space(8.0);
             
             // Workflow metadata editor with tailwind-like styling
-            egui::CollapsingHeader::new("Метаданные проекта")
+            egui::CollapsingHeader::new(language.tr("export.metadata"))
                 .default_open(true)
                 .show(ui, |ui| {
                     ui.spacing_mut().item_spacing = egui::vec2(8.0, 8.0);
                     
                     let field_label_style = |text: &str| -> egui::RichText {
-                        egui::RichText::new(text).color(egui::Color32::from_rgb(209, 213, 219))
+                        egui::RichText::new(text).color(palette.text)
                     };
                     
                     ui.horizontal(|ui| {
-                        ui.add(egui::Label::new(field_label_style("Автор:")));
+                        ui.add(egui::Label::new(field_label_style(language.tr("export.author"))));
                         ui.label(&workflow_metadata.author);
                     });
                     
                     ui.horizontal(|ui| {
-                        ui.add(egui::Label::new(field_label_style("Описание:")));
+                        ui.add(egui::Label::new(field_label_style(language.tr("export.description"))));
                         ui.label(&workflow_metadata.description);
                     });
                     
                     ui.horizontal(|ui| {
-                        ui.add(egui::Label::new(field_label_style("Теги:")));
-                        ui.label(&workflow_metadata.tags.join(", "));
+                        ui.add(egui::Label::new(field_label_style(language.tr("export.tags"))));
+                        ui.label(workflow_metadata.tags.join(", "));
                     });
                     
                     ui.horizontal(|ui| {
-                        ui.add(egui::Label::new(field_label_style("Создан:")));
+                        ui.add(egui::Label::new(field_label_style(language.tr("export.created"))));
                         ui.label(&workflow_metadata.created_at);
                     });
                     
                     ui.horizontal(|ui| {
-                        ui.add(egui::Label::new(field_label_style("Изменен:")));
+                        ui.add(egui::Label::new(field_label_style(language.tr("export.modified"))));
                         ui.label(&workflow_metadata.modified_at);
                     });
                 });
             
             ui.add_space(10.0);
-            ui.label("Экспортированные данные:");
+            ui.label(language.tr("export.data"));
             
             // Add a scrollable text area for the exported JSON
             ui.add(
-                egui::TextEdit::multiline(&mut String::new())
+                egui::TextEdit::multiline(&mut { exported_data })
                     .desired_width(f32::INFINITY)
                     .desired_rows(15)
                     .font(egui::TextStyle::Monospace.resolve(ui.style()))
@@ -175,35 +201,87 @@ impl Tabs {
             
             // Styled button
             let button = egui::Button::new(
-                egui::RichText::new("Обновить экспорт")
-                    .color(egui::Color32::WHITE)
+                egui::RichText::new(language.tr("export.refresh"))
+                    .color(Palette::text_on(palette.accent))
             )
-            .fill(egui::Color32::from_rgb(59, 130, 246)) // blue-500
+            .fill(palette.accent)
             .rounding(egui::Rounding::same(6.0));
             
             if ui.add(button).clicked() {
-                export_callback();
+                actions.push(AppAction::Export);
             }
             
             ui.separator();
-            ui.heading("Импорт данных");
+            ui.heading(language.tr("export.import_title"));
+            
+            // Lines with validation errors get a red background
+            let error_lines: Vec<usize> = import_errors.iter().filter_map(|e| e.line).collect();
+            let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+                let font_id = egui::TextStyle::Monospace.resolve(ui.style());
+                let mut job = egui::text::LayoutJob::default();
+                
+                for (index, line) in text.split_inclusive('\n').enumerate() {
+                    let background = if error_lines.contains(&(index + 1)) {
+                        palette.error.gamma_multiply(0.35)
+                    } else {
+                        egui::Color32::TRANSPARENT
+                    };
+                    
+                    job.append(line, 0.0, egui::TextFormat {
+                        font_id: font_id.clone(),
+                        color: palette.text,
+                        background,
+                        ..Default::default()
+                    });
+                }
+                
+                job.wrap.max_width = wrap_width;
+                ui.fonts(|f| f.layout_job(job))
+            };
             
             // Add a scrollable text area for the imported JSON
             ui.add(
-                egui::TextEdit::multiline(&mut String::new())
+                egui::TextEdit::multiline(import_text)
                     .desired_width(f32::INFINITY)
                     .desired_rows(15)
-                    .hint_text("Вставьте JSON данные для импорта...")
+                    .hint_text(language.tr("export.import_hint"))
                     .font(egui::TextStyle::Monospace.resolve(ui.style()))
+                    .layouter(&mut layouter)
             );
             
+            // List every validation problem with its JSON pointer
+            if !import_errors.is_empty() {
+                egui::Frame::none()
+                    .fill(palette.error.gamma_multiply(0.2))
+                    .rounding(egui::Rounding::same(6.0))
+                    .inner_margin(egui::style::Margin::same(8.0))
+                    .show(ui, |ui| {
+                        ui.spacing_mut().item_spacing = egui::vec2(4.0, 4.0);
+                        ui.label(egui::RichText::new(language.tr_args("export.errors_found", &[("count", &import_errors.len())]))
+                            .color(palette.error));
+                        
+                        for error in import_errors {
+                            ui.label(egui::RichText::new(error.to_string())
+                                .monospace()
+                                .color(palette.text));
+                        }
+                    });
+            }
+            
+            ui.horizontal(|ui| {
+                ui.label(language.tr("export.import_mode"));
+                for mode in [ImportMode::Replace, ImportMode::Append, ImportMode::Merge] {
+                    ui.radio_value(import_mode, mode, mode.label(language));
+                }
+            });
+            
             ui.horizontal(|ui| {
-                if ui.button("Импортировать").clicked() {
-                    import_callback();
+                if ui.button(language.tr("export.import")).clicked() {
+                    actions.push(AppAction::Import);
                 }
                 
-                if ui.button("Очистить").clicked() {
-                    // TODO: Clear import text
+                if ui.button(language.tr("export.clear")).clicked() {
+                    import_text.clear();
                 }
             });
         });
@@ -212,93 +290,128 @@ impl Tabs {
     fn draw_settings_tab(
         &mut self,
         ui: &mut egui::Ui,
-        status_callback: &mut dyn FnMut(&str, f32),
+        settings: &mut EditorSettings,
+        actions: &mut Vec<AppAction>,
     ) {
-        ui.heading("Настройки");
+        let language = Language::current(ui.ctx());
+        ui.heading(language.tr("settings.title"));
         
         ui.add_space(10.0);
         
-        ui.collapsing("Внешний вид", |ui| {
+        ui.collapsing(language.tr("settings.appearance"), |ui| {
+            // Interface language, applied from the next frame on
+            ui.horizontal(|ui| {
+                ui.label(language.tr("settings.language"));
+                for option in Language::ALL {
+                    ui.selectable_value(&mut settings.language, option, option.label());
+                }
+            });
+            
             // Theme settings
             ui.horizontal(|ui| {
-                ui.label("Тема:");
-                ui.selectable_value(&mut String::from("dark"), "dark".to_string(), "Темная");
-                ui.selectable_value(&mut String::from("light"), "light".to_string(), "Светлая");
+                ui.label(language.tr("settings.theme"));
+                for theme in Theme::ALL {
+                    ui.selectable_value(&mut settings.theme, theme, theme.label(language));
+                }
             });
             
-            // Font size
+            // Font size scales the whole interface
             ui.horizontal(|ui| {
-                ui.label("Размер шрифта:");
-                if ui.button("-").clicked() {
-                    status_callback("Уменьшение размера шрифта пока не реализовано", 2.0);
+                ui.label(language.tr("settings.font_size"));
+                let (min, max) = (*EditorSettings::UI_SCALE_RANGE.start(), *EditorSettings::UI_SCALE_RANGE.end());
+                if ui.add_enabled(settings.ui_scale > min, egui::Button::new("-")).clicked() {
+                    settings.ui_scale = (settings.ui_scale - EditorSettings::UI_SCALE_STEP).max(min);
                 }
-                ui.label("100%");
-                if ui.button("+").clicked() {
-                    status_callback("Увеличение размера шрифта пока не реализовано", 2.0);
+                ui.label(format!("{:.0}%", settings.ui_scale * 100.0));
+                if ui.add_enabled(settings.ui_scale < max, egui::Button::new("+")).clicked() {
+                    settings.ui_scale = (settings.ui_scale + EditorSettings::UI_SCALE_STEP).min(max);
                 }
             });
         });
         
-        ui.collapsing("Граф", |ui| {
+        ui.collapsing(language.tr("settings.graph"), |ui| {
             // Grid settings
-            ui.checkbox(&mut bool::default(), "Показывать сетку");
-            ui.checkbox(&mut bool::default(), "Привязка к сетке");
+            ui.checkbox(&mut settings.show_grid, language.tr("settings.show_grid"));
+            ui.checkbox(&mut settings.snap_to_grid, language.tr("settings.snap_to_grid"))
+                .on_hover_text(language.tr("settings.snap_to_grid_hint"));
             
             // Connection style
             ui.horizontal(|ui| {
-                ui.label("Стиль соединений:");
-                ui.selectable_value(&mut String::from("bezier"), "bezier".to_string(), "Кривые Безье");
-                ui.selectable_value(&mut String::from("straight"), "straight".to_string(), "Прямые линии");
+                ui.label(language.tr("settings.connection_style"));
+                for style in [ConnectionStyle::Bezier, ConnectionStyle::Straight] {
+                    ui.selectable_value(&mut settings.connection_style, style, style.label(language));
+                }
+            });
+            
+            // Undo history
+            ui.horizontal(|ui| {
+                ui.label(language.tr("settings.history_depth"));
+                ui.add(egui::Slider::new(&mut settings.history_depth, EditorSettings::HISTORY_DEPTH_RANGE));
             });
         });
         
-        ui.collapsing("Дополнительно", |ui| {
-            if ui.button("Сбросить все настройки").clicked() {
-                status_callback("Сброс настроек пока не реализован", 2.0);
+        ui.collapsing(language.tr("settings.advanced"), |ui| {
+            if ui.button(language.tr("settings.reset")).clicked() {
+                *settings = EditorSettings::default();
+                actions.push(AppAction::Status(language.tr("status.settings_reset").to_string(), 2.0));
             }
             
-            if ui.button("Очистить кэш").clicked() {
-                status_callback("Очистка кэша пока не реализована", 2.0);
+            // Window positions, sizes and open sections are stored along with the settings
+            if ui.button(language.tr("settings.clear_cache")).clicked() {
+                ui.ctx().memory_mut(|mem| *mem = Default::default());
+                // The theme and the language are kept in the memory as well
+                settings.theme.apply(ui.ctx());
+                settings.language.apply(ui.ctx());
+                actions.push(AppAction::Status(language.tr("status.cache_cleared").to_string(), 2.0));
             }
         });
     }
 
     fn draw_help_tab(&mut self, ui: &mut egui::Ui) {
-        ui.heading("О программе");
+        let language = Language::current(ui.ctx());
+        ui.heading(language.tr("help.about"));
         
         ui.add_space(10.0);
         
-        ui.label("ВГАУ Бот Редактор v1.0");
-        ui.label("Редактор структуры меню и FAQ для телеграм-бота Верхневолжского государственного аграрного университета");
+        ui.label(language.tr("help.version"));
+        ui.label(language.tr("help.description"));
         
         ui.add_space(20.0);
         
-        ui.heading("Возможности");
+        ui.heading(language.tr("help.features"));
         
-        ui.label("• Интуитивный редактор на основе узлов с интерактивными элементами");
-        ui.label("• Drag-and-drop интерфейс для создания и соединения компонентов");
-        ui.label("• Визуальное редактирование структуры меню телеграм-бота");
-        ui.label("• Управление вопросами и ответами (FAQ) через узловые компоненты");
-        ui.label("• Экспорт и импорт данных в формате JSON");
-        ui.label("• Контекстные меню и панель инструментов для быстрого доступа к функциям");
-        ui.label("• Масштабирование и панорамирование рабочей области");
-        ui.label("• Подробная настройка параметров бота через свойства узлов");
-        ui.label("• История изменений с функциями отмены и повтора действий");
+        for key in [
+            "help.feature.nodes",
+            "help.feature.drag_and_drop",
+            "help.feature.menu",
+            "help.feature.faq",
+            "help.feature.json",
+            "help.feature.menus",
+            "help.feature.zoom",
+            "help.feature.parameters",
+            "help.feature.history",
+        ] {
+            ui.label(language.tr(key));
+        }
         
         ui.add_space(20.0);
         
-        ui.heading("Горячие клавиши");
+        ui.heading(language.tr("help.shortcuts"));
         
-        ui.label("• Ctrl+Z — Отменить последнее действие");
-        ui.label("• Ctrl+Y или Ctrl+Shift+Z — Повторить отмененное действие");
-        ui.label("• Delete — Удалить выбранный узел");
-        ui.label("• Ctrl+колесо мыши — Изменить масштаб");
-        ui.label("• Перетаскивание с зажатым ЛКМ — Перемещение узлов");
-        ui.label("• Перетаскивание с зажатым СКМ/Alt — Панорамирование рабочей области");
+        for key in [
+            "help.shortcut.undo",
+            "help.shortcut.redo",
+            "help.shortcut.delete",
+            "help.shortcut.zoom",
+            "help.shortcut.move",
+            "help.shortcut.pan",
+        ] {
+            ui.label(language.tr(key));
+        }
         
         ui.add_space(20.0);
         
-        if ui.button("Закрыть").clicked() {
+        if ui.button(language.tr("help.close")).clicked() {
             self.current_tab = Tab::Editor;
         }
     }
@@ -1,72 +1,34 @@
-use eframe::egui;
-
-pub fn apply_tailwind_style(ctx: &egui::Context) {
-    let mut style = (*ctx.style()).clone();
-    
-    // Rounded corners like in Tailwind
-    style.visuals.window_rounding = egui::Rounding::same(8.0);
-    style.visuals.window_shadow.extrusion = 8.0;
-    style.visuals.menu_rounding = egui::Rounding::same(6.0);
+/// Current UTC time as an ISO 8601 string, e.g. "2024-05-01T12:30:00.000Z"
+#[cfg(target_arch = "wasm32")]
+pub fn now_iso() -> String {
+    js_sys::Date::new_0().to_iso_string().as_string()
+        .unwrap_or_else(|| "Unknown date".to_string())
+}
+
+/// Current UTC time as an ISO 8601 string, e.g. "2024-05-01T12:30:00.000Z"
+#[cfg(not(target_arch = "wasm32"))]
+pub fn now_iso() -> String {
+    let Ok(since_epoch) = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) else {
+        return "Unknown date".to_string();
+    };
+    let seconds = since_epoch.as_secs();
+    let (days, time) = (seconds / 86_400, seconds % 86_400);
     
-    // Colors similar to Tailwind blue shades
-    style.visuals.widgets.active.bg_fill = egui::Color32::from_rgb(59, 130, 246); // blue-500
-    style.visuals.widgets.hovered.bg_fill = egui::Color32::from_rgb(96, 165, 250); // blue-400
-    style.visuals.widgets.inactive.bg_fill = egui::Color32::from_rgb(30, 58, 138); // blue-900
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
     
-    // Apply the style
-    ctx.set_style(style);
-}
-
-pub fn create_button(text: &str, color: egui::Color32) -> egui::Button {
-    egui::Button::new(
-        egui::RichText::new(text)
-            .color(egui::Color32::WHITE)
-    )
-    .fill(color)
-    .rounding(egui::Rounding::same(6.0))
-}
-
-pub fn create_label(text: &str, color: egui::Color32) -> egui::Label {
-    egui::Label::new(
-        egui::RichText::new(text)
-            .color(color)
-    )
-}
-
-pub fn create_heading(text: &str) -> egui::Label {
-    egui::Label::new(
-        egui::RichText::new(text)
-            .size(24.0)
-            .color(egui::Color32::from_rgb(209, 213, 219)) // gray-300
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year, month, day,
+        time / 3600, time % 3600 / 60, time % 60,
+        since_epoch.subsec_millis()
     )
 }
-
-pub fn create_frame() -> egui::Frame {
-    egui::Frame::none()
-        .fill(egui::Color32::from_rgb(31, 41, 55)) // bg-gray-800
-        .rounding(egui::Rounding::same(8.0))
-        .stroke(egui::Stroke::new(1.0, egui::Color32::from_rgb(55, 65, 81))) // gray-700 border
-        .inner_margin(egui::style::Margin::same(12.0))
-}
-
-pub fn create_collapsing_header(text: &str) -> egui::CollapsingHeader {
-    egui::CollapsingHeader::new(text)
-        .default_open(true)
-        .frame(create_frame())
-}
-
-pub fn create_text_edit() -> egui::TextEdit {
-    egui::TextEdit::multiline(&mut String::new())
-        .desired_width(f32::INFINITY)
-        .desired_rows(15)
-        .font(egui::TextStyle::Monospace)
-}
-
-pub fn create_separator() -> egui::Separator {
-    egui::Separator::default()
-        .spacing(8.0)
-}
-
-pub fn create_space() -> egui::Space {
-    egui::Space::new(egui::Vec2::new(8.0, 8.0))
-} 
\ No newline at end of file
@@ -0,0 +1,204 @@
+use tg_menu_editor_wasm::bot_config::{BotMenuConfig, MenuItem, CURRENT_SCHEMA_VERSION};
+use tg_menu_editor_wasm::export_data::DataExporter;
+use tg_menu_editor_wasm::graph::{GraphState, ProjectFile, SimpleNodeGraph, WorkflowMetadata};
+use tg_menu_editor_wasm::import_data::{DataImporter, ImportMode};
+use tg_menu_editor_wasm::migrations;
+use tg_menu_editor_wasm::schema;
+
+const BOT_DATA: &str = include_str!("../static/default_bot_data.json");
+const BOT_DATA_SCHEMA: &str = include_str!("../static/bot_data.schema.json");
+
+fn import_bot_data() -> (SimpleNodeGraph, BotMenuConfig) {
+    let mut graph = SimpleNodeGraph::default();
+    let config = DataImporter::import_menu_data(&mut graph, BOT_DATA, ImportMode::Replace).expect("bot_data.json should import");
+    (graph, config)
+}
+
+/// bot_data.json as the exporter is expected to write it: same content, current schema
+fn expected_value() -> serde_json::Value {
+    migrations::migrate_to_current(serde_json::from_str(BOT_DATA).unwrap()).unwrap()
+}
+
+fn export_value(graph: &SimpleNodeGraph, title: &str) -> serde_json::Value {
+    let json = DataExporter::export_json(graph, title, WorkflowMetadata::default()).expect("graph should export");
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn bot_data_survives_import_and_export() {
+    let (graph, config) = import_bot_data();
+
+    assert_eq!(export_value(&graph, &config.title), expected_value());
+}
+
+#[test]
+fn import_keeps_top_level_items_as_roots() {
+    let (graph, config) = import_bot_data();
+
+    assert_eq!(graph.get_root_menu_nodes().len(), config.main_menu.len());
+    assert_eq!(graph.get_faq_nodes().len(), config.faq.len());
+}
+
+#[test]
+fn bot_data_survives_project_save_and_load() {
+    let (graph, config) = import_bot_data();
+
+    let project = ProjectFile::new("test".to_string(), WorkflowMetadata::default(), graph.to_graph_state());
+    let json = project.to_json().unwrap();
+
+    let mut reloaded = SimpleNodeGraph::default();
+    reloaded.load_graph_state(ProjectFile::from_json(&json).unwrap().graph);
+
+    assert_eq!(export_value(&reloaded, &config.title), export_value(&graph, &config.title));
+}
+
+#[test]
+fn merging_the_same_file_changes_nothing() {
+    let (mut graph, config) = import_bot_data();
+    let node_count = graph.to_graph_state().nodes.len();
+
+    let plan = DataImporter::plan_merge(&graph, Some(&config), config.clone());
+    assert!(plan.conflicts.is_empty());
+    assert!(plan.updates.is_empty());
+    assert_eq!(plan.new_items, 0);
+
+    DataImporter::import_config_with_mode(&mut graph, &config, ImportMode::Merge).unwrap();
+    assert_eq!(graph.to_graph_state().nodes.len(), node_count);
+    assert_eq!(export_value(&graph, &config.title), expected_value());
+}
+
+#[test]
+fn legacy_bot_data_is_upgraded_and_newer_versions_are_rejected() {
+    let (_, config) = import_bot_data();
+    assert_eq!(config.schema_version, CURRENT_SCHEMA_VERSION);
+
+    let mut newer: serde_json::Value = serde_json::from_str(BOT_DATA).unwrap();
+    newer["schema_version"] = serde_json::Value::from(CURRENT_SCHEMA_VERSION + 1);
+    let error = DataImporter::parse_config(&newer.to_string()).err().expect("newer schema must be rejected");
+    assert!(error.contains("Unsupported schema_version"), "{}", error);
+}
+
+#[test]
+fn invalid_bot_data_reports_every_problem_by_pointer_and_line() {
+    let json = r#"{
+  "title": "Меню",
+  "main_menu": [
+    {
+      "text": "Документы",
+      "callback_data": "docs",
+      "documents": [
+        { "text": "Приказ", "url": 42 }
+      ]
+    },
+    {
+      "txt": "Опечатка",
+      "callback_data": "typo"
+    }
+  ]
+}"#;
+    
+    let errors = DataImporter::parse_config_checked(json).err().expect("invalid data must be rejected");
+    let found: Vec<(&str, Option<usize>)> = errors.iter().map(|e| (e.pointer.as_str(), e.line)).collect();
+    
+    assert!(found.contains(&("/main_menu/0/documents/0/url", Some(8))), "{:?}", errors);
+    assert!(found.contains(&("/main_menu/1", Some(11))), "{:?}", errors);
+    assert!(found.contains(&("/main_menu/1/txt", Some(12))), "{:?}", errors);
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+}
+
+#[test]
+fn bot_data_schema_file_is_up_to_date() {
+    // The committed copy is the one handed to the Python bot, regenerate it when the types change
+    assert_eq!(schema::bot_data_schema_json().unwrap().trim(), BOT_DATA_SCHEMA.trim());
+}
+
+#[test]
+fn content_nodes_survive_import_export_and_merge() {
+    let json = serde_json::json!({
+        "schema_version": CURRENT_SCHEMA_VERSION,
+        "title": "Меню",
+        "main_menu": [{
+            "text": "Приёмная комиссия",
+            "callback_data": "admission",
+            "images": [{ "url": "https://example.com/campus.jpg", "caption": "Главный корпус" }],
+            "links": [{ "text": "Сайт", "url": "https://example.com" }],
+            "messages": [{ "text": "Работаем с 9 до 17" }, { "text": "Суббота — выходной" }],
+            "contacts": [{ "address": "Иваново, ул. Советская, 45", "phone": "+7 (4932) 00-00-00" }]
+        }],
+        "faq": []
+    });
+    
+    let mut graph = SimpleNodeGraph::default();
+    let config = DataImporter::parse_config(&json.to_string()).unwrap();
+    DataImporter::import_config_with_mode(&mut graph, &config, ImportMode::Replace).unwrap();
+    assert_eq!(export_value(&graph, "Меню"), json);
+    
+    let plan = DataImporter::plan_merge(&graph, Some(&config), config.clone());
+    assert!(plan.conflicts.is_empty() && plan.updates.is_empty());
+    assert_eq!(plan.new_items, 0);
+}
+
+#[test]
+fn node_ids_survive_saving_and_reopening_a_project() {
+    let (graph, _) = import_bot_data();
+    let state = graph.to_graph_state();
+    let project = ProjectFile::new("test".to_string(), WorkflowMetadata::default(), graph.to_graph_state());
+    let reopened = ProjectFile::from_json(&project.to_json().unwrap()).unwrap().graph;
+    
+    let node_ids = |state: &GraphState| state.nodes.iter().map(|node| node.id()).collect::<Vec<_>>();
+    let connection_ends = |state: &GraphState| state.connections.iter()
+        .map(|connection| (connection.from_node(), connection.to_node()))
+        .collect::<Vec<_>>();
+    assert_eq!(node_ids(&reopened), node_ids(&state));
+    assert_eq!(connection_ends(&reopened), connection_ends(&state));
+}
+
+#[test]
+fn exported_menu_items_carry_their_node_ids_next_to_the_data() {
+    let (graph, config) = import_bot_data();
+    let exported = DataExporter::export_config(&graph, &config.title, WorkflowMetadata::default());
+    
+    fn count(items: &[MenuItem]) -> usize {
+        items.iter().map(|item| 1 + count(item.submenu.as_deref().unwrap_or_default())).sum()
+    }
+    assert_eq!(exported.node_ids.len(), count(&config.main_menu));
+    for (callback_data, node_id) in &exported.node_ids {
+        let node = graph.get_node_data(*node_id).expect("node ids should point into the graph");
+        assert_eq!(node.find_param("callback_data").map(|p| p.get_text()).as_ref(), Some(callback_data));
+    }
+    // bot_data.json itself is unchanged, its schema rejects unknown fields
+    assert_eq!(serde_json::to_value(&exported).unwrap(), expected_value());
+}
+
+#[test]
+fn typed_parameters_reject_invalid_values_on_import() {
+    let json = serde_json::json!({
+        "title": "Меню",
+        "main_menu": [{ "text": "Сайт", "callback_data": "site", "url": "v-gau.ru" }]
+    });
+    
+    let (mut graph, _) = import_bot_data();
+    let before = export_value(&graph, "Меню");
+    let config = DataImporter::parse_config(&json.to_string()).unwrap();
+    let error = DataImporter::import_config_with_mode(&mut graph, &config, ImportMode::Replace)
+        .expect_err("a URL without a scheme must be rejected");
+    assert!(error.contains("v-gau.ru"), "{}", error);
+    // The open graph is only replaced once the import succeeded
+    assert_eq!(export_value(&graph, "Меню"), before);
+}
+
+#[test]
+fn replacing_the_graph_is_one_undo_step() {
+    let (mut graph, config) = import_bot_data();
+    let before = export_value(&graph, &config.title);
+    let json = serde_json::json!({
+        "title": "Меню",
+        "main_menu": [{ "text": "Сайт", "callback_data": "site", "url": "https://v-gau.ru" }]
+    });
+    
+    DataImporter::import_menu_data(&mut graph, &json.to_string(), ImportMode::Replace).unwrap();
+    assert_eq!(graph.nodes().len(), 1);
+    
+    assert!(graph.undo());
+    assert_eq!(export_value(&graph, &config.title), before);
+}
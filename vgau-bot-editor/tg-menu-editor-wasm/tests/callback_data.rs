@@ -0,0 +1,84 @@
+use eframe::egui;
+use tg_menu_editor_wasm::callback_data::{slugify, transliterate, CallbackDataGenerator, GENERATED_MAX_BYTES};
+use tg_menu_editor_wasm::export_data::DataExporter;
+use tg_menu_editor_wasm::graph::{SimpleNodeGraph, WorkflowMetadata};
+
+#[test]
+fn titles_are_transliterated_by_gost_7_79() {
+    assert_eq!(transliterate("Щука и ёж"), "shhuka i yozh");
+    assert_eq!(transliterate("Цирк, лицей, цветы"), "cirk, licej, czvety");
+    assert_eq!(slugify("Правила приёма (бакалавриат)"), "pravila_priyoma_bakalavriat");
+    assert_eq!(slugify("Подъезд №5"), "podezd_5");
+    assert_eq!(slugify("!!!"), "");
+}
+
+#[test]
+fn generated_callback_data_is_unique_deterministic_and_fits() {
+    let mut graph = SimpleNodeGraph::default();
+    let bachelor = graph.add_menu_item(egui::pos2(0.0, 0.0), "Бакалавриат".to_string());
+    let college = graph.add_menu_item(egui::pos2(300.0, 0.0), "СПО".to_string());
+    let mut rules = Vec::new();
+    for (parent, x) in [(bachelor, 0.0), (college, 300.0), (college, 600.0)] {
+        let item = graph.add_menu_item(egui::pos2(x, 200.0), "Правила приёма".to_string());
+        assert!(graph.connect_nodes(parent, "sub_menu", item, "parent_menu"));
+        rules.push(item);
+    }
+    let long = graph.add_menu_item(egui::pos2(0.0, 400.0), "Очень длинное название ".repeat(5));
+    let faq_like = graph.add_menu_item(egui::pos2(0.0, 600.0), "FAQ студентам".to_string());
+    
+    let callbacks = CallbackDataGenerator::assign(&graph);
+    assert_eq!(callbacks[&rules[0]], "pravila_priyoma");
+    assert_eq!(callbacks[&rules[1]], "pravila_priyoma_spo");
+    assert_eq!(callbacks[&rules[2]], "pravila_priyoma_spo_2");
+    assert!(callbacks[&long].len() <= GENERATED_MAX_BYTES);
+    // The bot handles everything starting with faq_ itself
+    assert_eq!(callbacks[&faq_like], "item_faq_studentam");
+    
+    let export = || serde_json::to_string(&DataExporter::export_config(&graph, "Меню", WorkflowMetadata::default())).unwrap();
+    assert_eq!(export(), export());
+}
+
+#[test]
+fn pinned_callback_data_is_kept_and_can_be_regenerated() {
+    let mut graph = SimpleNodeGraph::default();
+    let first = graph.add_menu_item(egui::pos2(0.0, 0.0), "Контакты".to_string());
+    let second = graph.add_menu_item(egui::pos2(300.0, 0.0), "Контакты".to_string());
+    
+    assert!(graph.pin_callback_data(second));
+    let pinned = CallbackDataGenerator::assign(&graph)[&second].clone();
+    assert_eq!(pinned, "kontakty_2");
+    // Pinning reserves the value, the other item keeps its own
+    assert_eq!(CallbackDataGenerator::assign(&graph)[&first], "kontakty");
+    assert!(!graph.pin_callback_data(second));
+    
+    graph.get_node_mut(second).unwrap()
+        .find_param_mut("name").unwrap()
+        .set_text_value("Адреса".to_string()).unwrap();
+    assert_eq!(CallbackDataGenerator::assign(&graph)[&second], pinned);
+    
+    assert!(graph.regenerate_callback_data(second));
+    assert_eq!(CallbackDataGenerator::assign(&graph)[&second], "adresa");
+    
+    assert_eq!(graph.pin_all_callback_data(), 1);
+    assert!(graph.undo());
+    assert_eq!(graph.get_node_data(first).unwrap().find_param("callback_data").unwrap().get_text(), "");
+}
+
+#[test]
+fn long_parent_title_is_shortened_in_generated_callback_data() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Д".repeat(60));
+    let mut items = Vec::new();
+    for x in [0.0, 300.0, 600.0] {
+        let item = graph.add_menu_item(egui::pos2(x, 200.0), "Правила".to_string());
+        assert!(graph.connect_nodes(parent, "sub_menu", item, "parent_menu"));
+        items.push(item);
+    }
+    
+    let callbacks = CallbackDataGenerator::assign(&graph);
+    assert_eq!(callbacks[&items[0]], "pravila");
+    assert_eq!(callbacks[&items[1]], format!("pravila_{}", "d".repeat(GENERATED_MAX_BYTES / 2)));
+    assert!(callbacks[&items[2]].starts_with("pravila_d"));
+    assert!(callbacks[&items[2]].ends_with("_2"));
+    assert!(items.iter().all(|item| callbacks[item].len() <= GENERATED_MAX_BYTES));
+}
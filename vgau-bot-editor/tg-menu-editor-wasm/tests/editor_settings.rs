@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use eframe::egui;
+use tg_menu_editor_wasm::graph::SimpleNodeGraph;
+use tg_menu_editor_wasm::i18n::Language;
+use tg_menu_editor_wasm::settings::{ConnectionStyle, EditorSettings, STORAGE_KEY};
+use tg_menu_editor_wasm::theme::Theme;
+
+/// In-memory stand-in for localStorage
+#[derive(Default)]
+struct MemoryStorage(HashMap<String, String>);
+
+impl eframe::Storage for MemoryStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        self.0.get(key).cloned()
+    }
+    
+    fn set_string(&mut self, key: &str, value: String) {
+        self.0.insert(key.to_string(), value);
+    }
+    
+    fn flush(&mut self) {}
+}
+
+#[test]
+fn settings_survive_a_restart() {
+    assert_eq!(EditorSettings::load(None), EditorSettings::default());
+    
+    let settings = EditorSettings {
+        language: Language::English,
+        theme: Theme::Light,
+        ui_scale: 1.25,
+        show_grid: false,
+        snap_to_grid: true,
+        connection_style: ConnectionStyle::Straight,
+        history_depth: 80,
+    };
+    let mut storage = MemoryStorage::default();
+    settings.save(&mut storage);
+    assert_eq!(EditorSettings::load(Some(&storage)), settings);
+    
+    // Settings stored by an older version lack fields, broken values are brought back into range
+    storage.0.insert(STORAGE_KEY.to_string(), "(theme: Light, ui_scale: 40.0, history_depth: 0)".to_string());
+    let loaded = EditorSettings::load(Some(&storage));
+    assert_eq!(loaded.theme, Theme::Light);
+    assert_eq!(loaded.language, Language::Russian);
+    assert_eq!(loaded.ui_scale, *EditorSettings::UI_SCALE_RANGE.end());
+    assert_eq!(loaded.history_depth, *EditorSettings::HISTORY_DEPTH_RANGE.start());
+    assert!(loaded.show_grid);
+}
+
+#[test]
+fn history_depth_limits_undo_steps() {
+    let mut graph = SimpleNodeGraph::default();
+    graph.apply_settings(&EditorSettings {
+        history_depth: 5,
+        ..Default::default()
+    });
+    
+    for i in 0..10 {
+        graph.add_menu_item(egui::pos2(0.0, i as f32 * 100.0), format!("Пункт {}", i));
+    }
+    
+    let mut undone = 0;
+    while graph.undo() {
+        undone += 1;
+    }
+    assert_eq!(undone, 4, "the oldest kept snapshot is the state undo stops at");
+    assert_eq!(graph.nodes().len(), 6);
+}
@@ -0,0 +1,368 @@
+use eframe::egui;
+use tg_menu_editor_wasm::bot_config::MenuItem;
+use tg_menu_editor_wasm::export_data::DataExporter;
+use tg_menu_editor_wasm::graph::{GraphState, GraphValidator, SimpleNodeGraph, WorkflowMetadata};
+use tg_menu_editor_wasm::graph::alignment::Alignment;
+use tg_menu_editor_wasm::graph::models::{NodeId, NodeType, ParameterValue};
+use tg_menu_editor_wasm::graph::rendering::GraphRenderer;
+use tg_menu_editor_wasm::settings::EditorSettings;
+
+#[test]
+fn group_operations_are_single_undo_steps() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Раздел".to_string());
+    let child = graph.add_menu_item(egui::pos2(0.0, 200.0), "Подраздел".to_string());
+    let far = graph.add_menu_item(egui::pos2(1000.0, 1000.0), "Другой".to_string());
+    assert!(graph.connect_nodes(parent, "sub_menu", child, "parent_menu"));
+    
+    // Rubber band around the first two nodes
+    graph.select_nodes_in_rect(egui::Rect::from_min_max(egui::pos2(-10.0, -10.0), egui::pos2(300.0, 300.0)), false);
+    assert_eq!(graph.selected_nodes(), vec![parent, child]);
+    
+    let copies = graph.duplicate_selected_nodes();
+    assert_eq!(copies.len(), 2);
+    assert_eq!(graph.nodes().len(), 5);
+    assert_eq!(graph.connections().len(), 2, "the connection inside the group is copied");
+    assert_eq!(graph.selected_nodes(), copies);
+    
+    graph.move_selected_nodes(egui::vec2(50.0, 0.0));
+    assert_eq!(graph.get_node_data(copies[0]).unwrap().position(), egui::pos2(80.0, 30.0));
+    assert!(graph.undo());
+    assert_eq!(graph.get_node_data(copies[0]).unwrap().position(), egui::pos2(30.0, 30.0));
+    
+    assert!(graph.undo());
+    assert_eq!(graph.nodes().len(), 3);
+    assert!(graph.selected_nodes().is_empty(), "undone copies leave the selection");
+    
+    graph.select_all_nodes();
+    assert_eq!(graph.active_node, Some(graph.nodes()[0].id()));
+    graph.toggle_node_selection(far);
+    assert_eq!(graph.delete_selected_nodes(), 2);
+    assert_eq!(graph.nodes().len(), 1);
+    assert!(graph.connections().is_empty());
+    
+    assert!(graph.undo());
+    assert_eq!(graph.nodes().len(), 3);
+    assert_eq!(graph.connections().len(), 1);
+}
+
+#[test]
+fn copied_subtree_is_pasted_with_fresh_ids_and_callback_data() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Абитуриенту".to_string());
+    let child = graph.add_menu_item(egui::pos2(300.0, 0.0), "Правила приёма".to_string());
+    let doc = graph.add_document(egui::pos2(600.0, 0.0), "Приказ".to_string()).unwrap();
+    let image = graph.add_content_node(NodeType::Image, egui::pos2(300.0, 200.0), "Фото".to_string()).unwrap();
+    graph.get_node_mut(doc).unwrap().find_param_mut("url").unwrap()
+        .set_text_value("https://vgau.ru/prikaz.pdf".to_string()).unwrap();
+    graph.get_node_mut(image).unwrap().find_param_mut("url").unwrap()
+        .set_text_value("https://vgau.ru/photo.jpg".to_string()).unwrap();
+    assert!(graph.connect_nodes(parent, "sub_menu", child, "parent_menu"));
+    assert!(graph.connect_nodes(child, "documents", doc, "parent_menu"));
+    assert!(graph.connect_nodes(parent, "content", image, "parent_menu"));
+    
+    graph.select_node(parent, false);
+    graph.select_node(child, true);
+    let fragment = graph.copy_selection().unwrap();
+    // The child is part of the parent's subtree and not copied twice
+    let item: MenuItem = serde_json::from_str(&fragment).unwrap();
+    assert_eq!(item.submenu.as_ref().unwrap()[0].documents.as_ref().unwrap().len(), 1);
+    
+    // Paste into another project
+    let mut other = SimpleNodeGraph::default();
+    let pasted = other.paste_fragment(&fragment, None, egui::pos2(0.0, 0.0)).unwrap();
+    assert_eq!(pasted.len(), 1);
+    assert_eq!(other.nodes().len(), 4);
+    assert_eq!(other.connections().len(), 3);
+    assert!(other.nodes().iter().all(|node| graph.get_node_data(node.id()).is_none()));
+    
+    // Pasting next to the original gets unique callback_data
+    graph.paste_fragment(&fragment, None, egui::pos2(0.0, 500.0)).unwrap();
+    assert_eq!(graph.nodes().len(), 8);
+    let config = DataExporter::export_config(&graph, "Меню", WorkflowMetadata::default());
+    assert_eq!(config.main_menu.len(), 2);
+    assert_ne!(config.main_menu[0].callback_data, config.main_menu[1].callback_data);
+    assert!(!GraphValidator::has_errors(&GraphValidator::validate(&graph)));
+    
+    // The whole paste is one undo step
+    assert!(graph.undo());
+    assert_eq!(graph.nodes().len(), 4);
+}
+
+#[test]
+fn copying_a_subtree_with_a_cycle_below_is_refused() {
+    let mut graph = SimpleNodeGraph::default();
+    let root = graph.add_menu_item(egui::pos2(0.0, 0.0), "Студентам".to_string());
+    let schedule = graph.add_menu_item(egui::pos2(300.0, 0.0), "Расписание".to_string());
+    let exams = graph.add_menu_item(egui::pos2(600.0, 0.0), "Сессия".to_string());
+    assert!(graph.connect_nodes(root, "sub_menu", schedule, "parent_menu"));
+    assert!(graph.connect_nodes(schedule, "sub_menu", exams, "parent_menu"));
+    // The cycle does not go through the copied item
+    assert!(graph.connect_nodes(exams, "sub_menu", schedule, "parent_menu"));
+    
+    graph.select_node(root, false);
+    let error = graph.copy_selection().expect_err("a cycle below the copied item has no end");
+    assert!(error.contains("cycle"), "{}", error);
+    
+    // The unchecked export stops at the cycle instead of recursing forever
+    let config = DataExporter::export_config(&graph, "Меню", WorkflowMetadata::default());
+    let schedule_item = &config.main_menu[0].submenu.as_ref().unwrap()[0];
+    assert!(schedule_item.submenu.as_ref().unwrap()[0].submenu.is_none());
+}
+
+#[test]
+fn raw_menu_item_json_is_pasted_under_a_parent() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Раздел".to_string());
+    let from_chat = r#"{"text": "Контакты", "callback_data": "contacts", "description": "Телефон приёмной комиссии"}"#;
+    
+    let pasted = graph.paste_fragment(from_chat, Some(parent), egui::pos2(0.0, 0.0)).unwrap();
+    assert_eq!(graph.get_child_menu_nodes(parent), pasted);
+    let node = graph.get_node_data(pasted[0]).unwrap();
+    assert_eq!(node.find_param("callback_data").unwrap().get_text(), "");
+    assert_eq!(node.find_param("description").unwrap().get_text(), "Телефон приёмной комиссии");
+    
+    assert!(graph.paste_fragment(r#"{"txt": "Опечатка"}"#, None, egui::pos2(0.0, 0.0)).is_err());
+    assert!(graph.paste_fragment("просто текст", None, egui::pos2(0.0, 0.0)).is_err());
+}
+
+#[test]
+fn tree_layout_separates_subtrees_and_is_one_undo_step() {
+    let mut graph = SimpleNodeGraph::default();
+    let root = graph.add_menu_item(egui::pos2(0.0, 0.0), "Абитуриенту".to_string());
+    let left = graph.add_menu_item(egui::pos2(0.0, 0.0), "Бакалавриат".to_string());
+    let right = graph.add_menu_item(egui::pos2(0.0, 0.0), "Магистратура".to_string());
+    let leaves: Vec<_> = (0..3)
+        .map(|i| graph.add_menu_item(egui::pos2(0.0, 0.0), format!("Направление {}", i)))
+        .collect();
+    let doc = graph.add_document(egui::pos2(0.0, 0.0), "Правила приёма".to_string()).unwrap();
+    let faq = graph.add_faq_item(egui::pos2(0.0, 0.0), "Вопрос".to_string());
+    assert!(graph.connect_nodes(root, "sub_menu", left, "parent_menu"));
+    assert!(graph.connect_nodes(root, "sub_menu", right, "parent_menu"));
+    for leaf in &leaves {
+        assert!(graph.connect_nodes(left, "sub_menu", *leaf, "parent_menu"));
+    }
+    assert!(graph.connect_nodes(left, "documents", doc, "parent_menu"));
+    
+    assert!(graph.auto_layout(false));
+    graph.finish_layout_animation();
+    
+    let ids: Vec<_> = graph.nodes().iter().map(|node| node.id()).collect();
+    for (i, a) in ids.iter().enumerate() {
+        for b in &ids[i + 1..] {
+            assert!(!rect(&graph, *a).intersects(rect(&graph, *b)), "nodes overlap after layout");
+        }
+    }
+    
+    let corner = graph.nodes().iter().map(|node| node.position()).reduce(|a, b| a.min(b));
+    assert_eq!(corner, Some(egui::pos2(0.0, 0.0)), "the layout keeps its top-left corner");
+    assert_eq!(rect(&graph, left).top(), rect(&graph, right).top());
+    assert!(rect(&graph, left).top() > rect(&graph, root).bottom());
+    assert!((rect(&graph, root).center().x - (rect(&graph, left).center().x + rect(&graph, right).center().x) / 2.0).abs() < 0.01);
+    assert!((rect(&graph, left).center().x - rect(&graph, leaves[1]).center().x).abs() < 0.01);
+    assert_eq!(rect(&graph, doc).top(), rect(&graph, left).top(), "documents are stacked beside their item");
+    assert!(rect(&graph, doc).left() > rect(&graph, left).right());
+    assert!(rect(&graph, faq).top() > rect(&graph, leaves[0]).bottom(), "FAQ goes below the tree");
+    
+    assert!(graph.undo());
+    assert!(graph.nodes().iter().all(|node| node.position() == egui::pos2(0.0, 0.0)));
+    
+    // Only the selection moves, from where it was
+    graph.select_node(left, false);
+    for leaf in &leaves {
+        graph.select_node(*leaf, true);
+    }
+    assert!(graph.auto_layout(true));
+    graph.finish_layout_animation();
+    assert_eq!(rect(&graph, right).min, egui::pos2(0.0, 0.0));
+    assert_eq!(rect(&graph, doc).min, egui::pos2(0.0, 0.0), "documents outside the selection stay");
+    assert_eq!(rect(&graph, left).top(), 0.0);
+    assert!(rect(&graph, leaves[0]).top() > rect(&graph, left).bottom());
+}
+
+#[test]
+fn shared_parameter_is_set_on_all_nodes_as_one_undo_step() {
+    let mut graph = SimpleNodeGraph::default();
+    let items: Vec<NodeId> = (0..3)
+        .map(|i| graph.add_menu_item(egui::pos2(0.0, i as f32 * 150.0), format!("Пункт {}", i)))
+        .collect();
+    let faq = graph.add_faq_item(egui::pos2(300.0, 0.0), "Вопрос".to_string());
+    let description = |graph: &SimpleNodeGraph, id: NodeId| {
+        graph.get_node_data(id).unwrap().find_param("description").unwrap().get_text()
+    };
+    
+    let mut targets = items.clone();
+    targets.push(faq);
+    let value = ParameterValue::Text("Приём документов до 20 июля".to_string());
+    assert_eq!(graph.set_parameter_value(&targets, "description", &value), 3, "FAQ items have no description");
+    assert!(graph.set_node_title(items[0], "Сроки".to_string()));
+    graph.save_state();
+    
+    assert!(items.iter().all(|id| description(&graph, *id) == "Приём документов до 20 июля"));
+    assert_eq!(graph.get_node_data(items[0]).unwrap().get_title(), "Сроки");
+    
+    assert!(graph.undo());
+    assert!(items.iter().all(|id| description(&graph, *id).is_empty()));
+    assert_eq!(graph.get_node_data(items[0]).unwrap().get_title(), "Пункт 0");
+}
+
+fn rect(graph: &SimpleNodeGraph, node_id: NodeId) -> egui::Rect {
+    let node = graph.get_node_data(node_id).unwrap();
+    egui::Rect::from_min_size(node.position(), node.size())
+}
+
+#[test]
+fn fit_commands_bring_nodes_into_view() {
+    let mut graph = SimpleNodeGraph::default();
+    let near = graph.add_menu_item(egui::pos2(-500.0, 0.0), "Слева".to_string());
+    let far = graph.add_faq_item(egui::pos2(2500.0, 1500.0), "Справа".to_string());
+    
+    assert!(graph.fit_all());
+    let visible = graph.visible_rect();
+    assert!(visible.contains_rect(rect(&graph, near)));
+    assert!(visible.contains_rect(rect(&graph, far)));
+    assert!(graph.bounds().is_some_and(|bounds| (bounds.center() - visible.center()).length() < 0.01));
+    
+    graph.select_node(far, false);
+    assert!(graph.fit_selection());
+    assert_eq!(graph.visible_rect().size(), egui::vec2(800.0, 600.0), "fitting one node stops at the normal scale");
+    assert!((rect(&graph, far).center() - graph.visible_rect().center()).length() < 0.01);
+    
+    assert!(graph.undo());
+    assert!(graph.visible_rect().contains_rect(rect(&graph, near)), "each fit is one undo step");
+    
+    graph.clear();
+    assert!(!graph.fit_all());
+}
+
+#[test]
+fn dragged_nodes_snap_to_neighbours_and_grid() {
+    let mut graph = SimpleNodeGraph::default();
+    let anchor = graph.add_menu_item(egui::pos2(0.0, 0.0), "Раздел".to_string());
+    let moved = graph.add_menu_item(egui::pos2(300.0, 300.0), "Подраздел".to_string());
+    
+    // Left edges 3 units apart stick together and show a guide
+    graph.select_node(moved, false);
+    graph.drag_selected_nodes(egui::vec2(-297.0, 0.0), false);
+    assert_eq!(rect(&graph, moved).min.x, 0.0);
+    assert!(graph.drag_guides().iter().any(|g| g.vertical && g.position == 0.0));
+    
+    // The snapping distance follows the pointer, further away the node moves freely
+    graph.drag_selected_nodes(egui::vec2(20.0, 0.0), false);
+    assert_eq!(rect(&graph, moved).min.x, 23.0);
+    graph.drag_selected_nodes(egui::vec2(-20.0, 0.0), true);
+    assert_eq!(rect(&graph, moved).min.x, 3.0, "free dragging ignores the guides");
+    graph.finish_node_drag();
+    assert!(graph.drag_guides().is_empty());
+    assert!(graph.undo());
+    assert_eq!(rect(&graph, moved).min, egui::pos2(300.0, 300.0), "the whole drag is one undo step");
+    
+    graph.apply_settings(&EditorSettings {
+        snap_to_grid: true,
+        ..Default::default()
+    });
+    graph.drag_selected_nodes(egui::vec2(313.0, 1007.0), false);
+    graph.finish_node_drag();
+    let position = rect(&graph, moved).min;
+    assert_eq!(position, egui::pos2(625.0, 1300.0));
+    assert_eq!(rect(&graph, anchor).min, egui::pos2(0.0, 0.0));
+}
+
+#[test]
+fn align_and_distribute_selected_nodes() {
+    let mut graph = SimpleNodeGraph::default();
+    let ids = [
+        graph.add_menu_item(egui::pos2(40.0, 0.0), "Первый".to_string()),
+        graph.add_menu_item(egui::pos2(10.0, 500.0), "Второй".to_string()),
+        graph.add_menu_item(egui::pos2(90.0, 150.0), "Третий".to_string()),
+    ];
+    graph.select_all_nodes();
+    
+    assert!(graph.align_selected(Alignment::Left));
+    assert!(ids.iter().all(|id| rect(&graph, *id).min.x == 10.0));
+    assert!(!graph.align_selected(Alignment::Left), "aligned nodes do not move again");
+    
+    assert!(graph.distribute_selected_vertically());
+    let height = rect(&graph, ids[0]).height();
+    let gap = (500.0 - 2.0 * height) / 2.0;
+    assert_eq!(rect(&graph, ids[0]).min.y, 0.0);
+    assert_eq!(rect(&graph, ids[2]).min.y, height + gap);
+    assert_eq!(rect(&graph, ids[1]).min.y, 500.0);
+    
+    assert!(graph.undo());
+    assert_eq!(rect(&graph, ids[2]).min.y, 150.0);
+    assert!(graph.align_selected(Alignment::Top));
+    assert!(ids.iter().all(|id| rect(&graph, *id).min.y == 0.0));
+}
+
+#[test]
+fn children_keep_an_explicit_order() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Раздел".to_string());
+    let children: Vec<NodeId> = ["Первый", "Второй", "Третий"].iter().enumerate()
+        .map(|(i, title)| graph.add_menu_item(egui::pos2(i as f32 * 300.0, 300.0), title.to_string()))
+        .collect();
+    // Wires drawn in a different order than the buttons should appear in
+    for index in [2, 0, 1] {
+        assert!(graph.connect_nodes(parent, "sub_menu", children[index], "parent_menu"));
+    }
+    assert_eq!(graph.get_child_menu_nodes(parent), vec![children[2], children[0], children[1]]);
+    
+    assert!(graph.move_child(parent, "sub_menu", children[0], 0));
+    assert!(graph.move_child(parent, "sub_menu", children[2], 10), "indexes past the end move to the end");
+    assert!(!graph.move_child(parent, "sub_menu", children[2], 2));
+    assert_eq!(graph.get_child_menu_nodes(parent), children);
+    
+    let config = DataExporter::export_config(&graph, "Меню", WorkflowMetadata::default());
+    let texts: Vec<&str> = config.main_menu[0].submenu.as_ref().unwrap().iter().map(|item| item.text.as_str()).collect();
+    assert_eq!(texts, ["Первый", "Второй", "Третий"]);
+    
+    // Projects saved before the order existed keep the order the connections were made in
+    let mut state = serde_json::to_value(graph.to_graph_state()).unwrap();
+    for connection in state["connections"].as_array_mut().unwrap() {
+        connection.as_object_mut().unwrap().remove("order");
+    }
+    let mut old = SimpleNodeGraph::default();
+    old.load_graph_state(serde_json::from_value::<GraphState>(state).unwrap());
+    assert_eq!(old.get_child_menu_nodes(parent), vec![children[2], children[0], children[1]]);
+    
+    assert!(graph.undo());
+    assert_eq!(graph.get_child_menu_nodes(parent), vec![children[0], children[2], children[1]], "every move is an undo step");
+}
+
+#[test]
+fn connections_are_picked_selected_retargeted_and_deleted() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Раздел".to_string());
+    let child = graph.add_menu_item(egui::pos2(0.0, 400.0), "Подраздел".to_string());
+    let other = graph.add_menu_item(egui::pos2(600.0, 0.0), "Другой".to_string());
+    assert!(graph.connect_nodes(parent, "sub_menu", child, "parent_menu"));
+    
+    let from = GraphRenderer::get_port_position(graph.get_node_data(parent).unwrap(), "sub_menu", false).unwrap();
+    let to = GraphRenderer::get_port_position(graph.get_node_data(child).unwrap(), "parent_menu", true).unwrap();
+    let middle = from + (to - from) / 2.0;
+    let picked = graph.connection_at(middle + egui::vec2(3.0, 0.0)).cloned().unwrap();
+    assert_eq!((picked.from_node(), picked.to_node()), (parent, child));
+    assert!(graph.connection_at(middle + egui::vec2(40.0, 0.0)).is_none());
+    assert!(graph.connection_at(to + egui::vec2(0.0, 10.0)).is_none(), "the node covers the connection");
+    
+    graph.select_node(parent, false);
+    graph.select_connection(&picked);
+    assert!(graph.selected_nodes().is_empty(), "a connection is selected instead of nodes");
+    
+    // The connection can't be moved onto its own ends
+    let candidates = graph.retarget_candidates(&picked);
+    assert!(candidates.contains(&other) && !candidates.contains(&parent) && !candidates.contains(&child));
+    assert!(!graph.retarget_connection(&picked, parent));
+    assert!(graph.retarget_connection(&picked, other));
+    assert_eq!(graph.get_child_menu_nodes(parent), vec![other]);
+    assert_eq!(graph.selected_connection().map(|conn| conn.to_node()), Some(other));
+    
+    assert!(graph.delete_selected_connection());
+    assert!(graph.connections().is_empty());
+    assert!(graph.selected_connection().is_none());
+    
+    assert!(graph.undo());
+    assert!(graph.undo());
+    assert_eq!(graph.get_child_menu_nodes(parent), vec![child]);
+}
@@ -0,0 +1,56 @@
+use eframe::egui;
+use tg_menu_editor_wasm::graph::SimpleNodeGraph;
+use tg_menu_editor_wasm::graph::search::{fuzzy_score, GraphSearch};
+
+#[test]
+fn fuzzy_score_prefers_substrings_and_word_starts() {
+    assert!(fuzzy_score("", "Правила приёма").is_none());
+    assert!(fuzzy_score("пвх", "Правила приёма").is_none());
+    
+    let substring = fuzzy_score("приём", "Правила приёма").unwrap();
+    let scattered = fuzzy_score("прм", "Правила приёма").unwrap();
+    assert!(substring > scattered);
+    
+    let words = fuzzy_score("прав пр", "Правила приёма").unwrap();
+    let inside = fuzzy_score("прав пр", "Справка о переводе").unwrap();
+    assert!(words > inside);
+}
+
+#[test]
+fn search_finds_nodes_by_parameters_with_their_path() {
+    let mut graph = SimpleNodeGraph::default();
+    let mut parent = None;
+    for title in ["Абитуриенту", "Информация о приёме", "Бакалавриат", "Очная форма"] {
+        let item = graph.add_menu_item(egui::pos2(0.0, 0.0), title.to_string());
+        if let Some(parent) = parent {
+            assert!(graph.connect_nodes(parent, "sub_menu", item, "parent_menu"));
+        }
+        parent = Some(item);
+    }
+    let doc = graph.add_document(egui::pos2(0.0, 0.0), "Правила".to_string()).unwrap();
+    graph.get_node_mut(doc).unwrap().find_param_mut("url").unwrap()
+        .set_text_value("https://vsau.ru/files/pravila-priema-2025.pdf".to_string()).unwrap();
+    assert!(graph.connect_nodes(parent.unwrap(), "documents", doc, "parent_menu"));
+    
+    let faq = graph.add_faq_item(egui::pos2(0.0, 0.0), "Где общежитие?".to_string());
+    graph.get_node_mut(faq).unwrap().find_param_mut("answer").unwrap()
+        .set_text_value("Общежитие №3 на улице Мичурина".to_string()).unwrap();
+    
+    let results = GraphSearch::search(&graph, "priema-2025.pdf", 10);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].node_id, doc);
+    assert_eq!(results[0].field, "search.field.url");
+    assert_eq!(results[0].path.len(), 4);
+    assert_eq!(results[0].breadcrumb(), "Абитуриенту › … › Бакалавриат › Очная форма");
+    
+    let results = GraphSearch::search(&graph, "мичурина", 10);
+    assert_eq!(results[0].node_id, faq);
+    assert_eq!(results[0].field, "search.field.answer");
+    assert_eq!(results[0].breadcrumb(), "FAQ");
+    
+    let results = GraphSearch::search(&graph, "информация", 10);
+    assert_eq!(results[0].field, "search.field.title");
+    assert_eq!(results[0].breadcrumb(), "Абитуриенту");
+    
+    assert!(GraphSearch::search(&graph, "   ", 10).is_empty());
+}
@@ -0,0 +1,201 @@
+use eframe::egui;
+use tg_menu_editor_wasm::export_data::DataExporter;
+use tg_menu_editor_wasm::graph::{GraphValidator, Severity, SimpleNodeGraph, WorkflowMetadata};
+use tg_menu_editor_wasm::graph::models::{NodeId, NodeType};
+use tg_menu_editor_wasm::graph::validation::runtime::{RuntimeAnalyzer, RuntimeProfile};
+use tg_menu_editor_wasm::graph::validation::telegram;
+
+fn set_param(graph: &mut SimpleNodeGraph, node_id: NodeId, param: &str, value: &str) {
+    graph.get_node_mut(node_id).unwrap()
+        .find_param_mut(param).unwrap()
+        .set_text_value(value.to_string()).unwrap();
+}
+
+#[test]
+fn duplicate_callback_data_and_cycles_block_export() {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Раздел".to_string());
+    let child = graph.add_menu_item(egui::pos2(300.0, 0.0), "Подраздел".to_string());
+    set_param(&mut graph, parent, "callback_data", "section");
+    set_param(&mut graph, child, "callback_data", "section");
+    assert!(graph.connect_nodes(parent, "sub_menu", child, "parent_menu"));
+    assert!(graph.connect_nodes(child, "sub_menu", parent, "parent_menu"));
+    
+    let diagnostics = GraphValidator::validate(&graph);
+    let errors: Vec<_> = diagnostics.iter().filter(|d| d.severity == Severity::Error).collect();
+    
+    assert_eq!(errors.iter().filter(|d| d.message.contains("section")).count(), 2, "{:?}", diagnostics);
+    assert!(errors.iter().any(|d| d.message.starts_with("Цикл в подменю")), "{:?}", diagnostics);
+    assert!(DataExporter::export_json(&graph, "Меню", WorkflowMetadata::default()).is_err());
+}
+
+#[test]
+fn content_dropped_by_the_exporter_is_reported_as_warnings() {
+    let mut graph = SimpleNodeGraph::default();
+    let orphan_document = graph.add_document(egui::pos2(0.0, 0.0), "Приказ".to_string()).unwrap();
+    let faq = graph.add_faq_item(egui::pos2(0.0, 200.0), "Вопрос".to_string());
+    
+    let diagnostics = GraphValidator::validate(&graph);
+    let warnings_for = |node_id| diagnostics.iter()
+        .filter(|d| d.severity == Severity::Warning && d.node_id == node_id)
+        .count();
+    
+    // Not connected and no URL
+    assert_eq!(warnings_for(orphan_document), 2, "{:?}", diagnostics);
+    // No answer
+    assert_eq!(warnings_for(faq), 1, "{:?}", diagnostics);
+    assert!(!GraphValidator::has_errors(&diagnostics));
+}
+
+#[test]
+fn cached_diagnostics_follow_edits_and_undo() {
+    let mut graph = SimpleNodeGraph::default();
+    let item = graph.add_menu_item(egui::pos2(0.0, 0.0), String::new());
+    let errors = |graph: &mut SimpleNodeGraph| graph.diagnostics().iter()
+        .filter(|d| d.severity == Severity::Error && d.node_id == item)
+        .count();
+    assert_eq!(errors(&mut graph), 1);
+    
+    // Nothing changed, nothing to recompute
+    let revision = graph.revision();
+    assert_eq!(errors(&mut graph), 1);
+    assert_eq!(graph.revision(), revision);
+    
+    set_param(&mut graph, item, "name", "Расписание");
+    assert_eq!(errors(&mut graph), 0);
+    
+    graph.save_state();
+    assert!(graph.undo());
+    assert_eq!(errors(&mut graph), 1);
+    assert_eq!(graph.diagnostics().len(), GraphValidator::validate(&graph).len());
+}
+
+#[test]
+fn telegram_limits_are_reported_with_measured_sizes() {
+    let mut graph = SimpleNodeGraph::default();
+    let item = graph.add_menu_item(egui::pos2(0.0, 0.0), "Расписание".to_string());
+    // 48 Cyrillic letters fit in 64 characters but take 96 bytes
+    set_param(&mut graph, item, "callback_data", &"р".repeat(48));
+    set_param(&mut graph, item, "text_content", &"ы".repeat(4096));
+    let image = graph.add_content_node(NodeType::Image, egui::pos2(0.0, 200.0), "Фото".to_string()).unwrap();
+    set_param(&mut graph, image, "caption", &"a".repeat(1025));
+    
+    let diagnostics = GraphValidator::validate(&graph);
+    let messages_for = |node_id| diagnostics.iter()
+        .filter(|d| d.severity == Severity::Error && d.node_id == node_id)
+        .map(|d| d.message.clone())
+        .collect::<Vec<_>>();
+    
+    let item_errors = messages_for(item);
+    assert!(item_errors.iter().any(|m| m.contains("callback_data занимает 96 байт")), "{:?}", item_errors);
+    // Title, blank line and the text itself
+    assert!(item_errors.iter().any(|m| m.contains("сообщение занимает 4108 символов")), "{:?}", item_errors);
+    assert!(messages_for(image).iter().any(|m| m.contains("1025 символов")), "{:?}", diagnostics);
+}
+
+#[test]
+fn generated_callback_data_fits_telegram_limit_in_bytes() {
+    let mut graph = SimpleNodeGraph::default();
+    graph.add_menu_item(egui::pos2(0.0, 0.0), "Очень длинное название раздела, которое не поместится в кнопку".to_string());
+    
+    let config = DataExporter::export_config(&graph, "Меню", WorkflowMetadata::default());
+    let callback_data = &config.main_menu[0].callback_data;
+    
+    assert!(callback_data.len() <= telegram::CALLBACK_DATA_MAX_BYTES, "{} bytes", callback_data.len());
+    assert!(callback_data.starts_with("ochen_dlinnoe"), "{}", callback_data);
+}
+
+#[test]
+fn callback_data_of_items_with_documents_is_measured_with_the_page_wrapper() {
+    let mut graph = SimpleNodeGraph::default();
+    let with_documents = graph.add_menu_item(egui::pos2(0.0, 0.0), "Приказы".to_string());
+    let without_documents = graph.add_menu_item(egui::pos2(300.0, 0.0), "Новости".to_string());
+    // 56 bytes fit on their own, doc_page_{callback}_2 takes 67
+    set_param(&mut graph, with_documents, "callback_data", &"a".repeat(56));
+    set_param(&mut graph, without_documents, "callback_data", &"b".repeat(56));
+    for i in 0..6 {
+        let doc = graph.add_document(egui::pos2(100.0 * i as f32, 200.0), format!("Приказ {}", i)).unwrap();
+        set_param(&mut graph, doc, "url", &format!("https://vgau.ru/{}.pdf", i));
+        assert!(graph.connect_nodes(with_documents, "documents", doc, "parent_menu"));
+    }
+    
+    let diagnostics = GraphValidator::validate(&graph);
+    let errors_for = |node_id| diagnostics.iter()
+        .filter(|d| d.severity == Severity::Error && d.node_id == node_id)
+        .map(|d| d.message.clone())
+        .collect::<Vec<_>>();
+    
+    assert!(errors_for(with_documents).iter().any(|m| m.contains("67 байт")), "{:?}", diagnostics);
+    assert!(errors_for(without_documents).is_empty(), "{:?}", diagnostics);
+}
+
+#[test]
+fn pinned_callback_data_handled_by_the_bot_is_an_error() {
+    let mut graph = SimpleNodeGraph::default();
+    let values = ["back_to_main", "back_to_faq", "faq_admission", "back_to_rules", "doc_page_rules", "pagination"];
+    let items: Vec<NodeId> = values.iter().enumerate().map(|(i, value)| {
+        let item = graph.add_menu_item(egui::pos2(300.0 * i as f32, 0.0), format!("Пункт {}", i));
+        set_param(&mut graph, item, "callback_data", value);
+        item
+    }).collect();
+    
+    let diagnostics = GraphValidator::validate(&graph);
+    let reserved = |node_id| diagnostics.iter()
+        .any(|d| d.severity == Severity::Error && d.node_id == node_id && d.message.contains("обрабатывается самим ботом"));
+    
+    for (item, value) in items.iter().zip(values) {
+        assert_eq!(reserved(*item), value != "pagination", "{}: {:?}", value, diagnostics);
+    }
+    assert!(DataExporter::export_json(&graph, "Меню", WorkflowMetadata::default()).is_err());
+}
+
+#[test]
+fn python_bot_profile_reports_unreachable_nodes_and_click_depth() {
+    let mut graph = SimpleNodeGraph::default();
+    let admission = graph.add_menu_item(egui::pos2(0.0, 0.0), "Абитуриенту".to_string());
+    let rules = graph.add_menu_item(egui::pos2(0.0, 200.0), "Правила".to_string());
+    let college = graph.add_menu_item(egui::pos2(300.0, 200.0), "СПО".to_string());
+    let places = graph.add_menu_item(egui::pos2(300.0, 400.0), "Места".to_string());
+    let budget = graph.add_menu_item(egui::pos2(300.0, 600.0), "Бюджет".to_string());
+    let intercepted = graph.add_menu_item(egui::pos2(600.0, 200.0), "Вопросы".to_string());
+    set_param(&mut graph, intercepted, "callback_data", "faq_admission");
+    set_param(&mut graph, intercepted, "description", "Частые вопросы");
+    set_param(&mut graph, budget, "description", "Бюджетные места");
+    for (parent, child) in [(admission, rules), (admission, college), (college, places), (places, budget), (admission, intercepted)] {
+        assert!(graph.connect_nodes(parent, "sub_menu", child, "parent_menu"));
+    }
+    
+    let documents: Vec<NodeId> = (0..7).map(|i| {
+        let doc = graph.add_document(egui::pos2(100.0 * i as f32, 800.0), format!("Приказ {}", i)).unwrap();
+        set_param(&mut graph, doc, "url", &format!("https://vgau.ru/{}.pdf", i));
+        assert!(graph.connect_nodes(rules, "documents", doc, "parent_menu"));
+        doc
+    }).collect();
+    let image = graph.add_content_node(NodeType::Image, egui::pos2(0.0, 1000.0), "Фото".to_string()).unwrap();
+    set_param(&mut graph, image, "url", "https://vgau.ru/photo.jpg");
+    assert!(graph.connect_nodes(admission, "content", image, "parent_menu"));
+    
+    let python = RuntimeProfile::python_bot();
+    let report = RuntimeAnalyzer::analyze(&graph, &python);
+    let flagged = |node_id| report.diagnostics.iter().any(|d| d.node_id == node_id);
+    
+    assert_eq!(report.depths[&admission], 1);
+    assert_eq!(report.depths[&rules], 2);
+    assert_eq!(report.depths[&documents[0]], 3);
+    assert_eq!(report.depths[&places], 3);
+    // Third level items without a link lead nowhere, fourth level has no buttons
+    assert!(flagged(places), "{:?}", report.diagnostics);
+    assert!(flagged(budget) && !report.depths.contains_key(&budget));
+    // Only the first page of documents can be opened
+    assert!(!flagged(documents[4]) && flagged(documents[5]) && flagged(documents[6]));
+    assert!(flagged(image));
+    assert!(report.diagnostics.iter().any(|d| d.node_id == intercepted && d.message.contains("faq_")));
+    assert!(report.diagnostics.iter().all(|d| d.severity == Severity::Warning));
+    
+    let unrestricted = RuntimeProfile::unrestricted();
+    let report = RuntimeAnalyzer::analyze(&graph, &unrestricted);
+    assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+    assert_eq!(report.depths[&budget], 4);
+    assert_eq!(report.depths[&documents[6]], 4);
+    assert_eq!(report.max_depth(), 4);
+}
@@ -0,0 +1,199 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use eframe::egui;
+use tg_menu_editor_wasm::bot_config::DEFAULT_MENU_TITLE;
+use tg_menu_editor_wasm::i18n::{translate, Catalog, Language};
+
+/// Texts written into the bot data rather than shown by the editor
+const BOT_CONTENT: &[&str] = &[DEFAULT_MENU_TITLE];
+
+/// `{name}` placeholders of a text
+fn placeholders(text: &str) -> HashSet<&str> {
+    text.split('{').skip(1).filter_map(|part| part.split_once('}')).map(|(name, _)| name).collect()
+}
+
+/// String literals of the sources that look like message keys, e.g. `"menu.file.open"`
+fn keys_in_sources(dir: &Path, namespaces: &HashSet<&str>, keys: &mut Vec<(String, String)>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            if !path.ends_with("i18n") {
+                keys_in_sources(&path, namespaces, keys);
+            }
+            continue;
+        }
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        let source = std::fs::read_to_string(&path).unwrap();
+        for literal in source.split('"').skip(1).step_by(2) {
+            let looks_like_key = literal.contains('.')
+                && literal.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
+                && literal.split('.').next().is_some_and(|namespace| namespaces.contains(namespace));
+            if looks_like_key {
+                keys.push((path.display().to_string(), literal.to_string()));
+            }
+        }
+    }
+}
+
+/// String literals of a source file with the line each starts on, comments are skipped
+fn string_literals(source: &str) -> Vec<(usize, String)> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut literals = Vec::new();
+    let (mut i, mut line) = (0, 1);
+    let at = |i: usize, text: &str| text.chars().enumerate().all(|(n, c)| chars.get(i + n) == Some(&c));
+    // Hashes of a raw string starting at `i`, raw identifiers such as r#type are not strings
+    let raw_string = |i: usize| {
+        let hashes = chars[i + 1..].iter().take_while(|c| **c == '#').count();
+        let starts = chars[i] == 'r' && (i == 0 || !chars[i - 1].is_alphanumeric()) && at(i + 1 + hashes, "\"");
+        starts.then_some(hashes)
+    };
+    
+    while i < chars.len() {
+        let start_line = line;
+        if at(i, "//") {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if at(i, "/*") {
+            while i < chars.len() && !at(i, "*/") {
+                line += usize::from(chars[i] == '\n');
+                i += 1;
+            }
+            i += 2;
+        } else if let Some(hashes) = raw_string(i) {
+            let close = format!("\"{}", "#".repeat(hashes));
+            i += 2 + hashes;
+            let mut literal = String::new();
+            while i < chars.len() && !at(i, &close) {
+                line += usize::from(chars[i] == '\n');
+                literal.push(chars[i]);
+                i += 1;
+            }
+            i += close.len();
+            literals.push((start_line, literal));
+        } else if chars[i] == '"' {
+            i += 1;
+            let mut literal = String::new();
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    literal.push(chars[i]);
+                    i += 1;
+                }
+                line += usize::from(chars[i] == '\n');
+                literal.push(chars[i]);
+                i += 1;
+            }
+            i += 1;
+            literals.push((start_line, literal));
+        } else if chars[i] == '\'' {
+            // A char literal such as '"' or '\'', otherwise a lifetime
+            match (chars.get(i + 1), chars.get(i + 2)) {
+                (Some('\\'), _) => i += 3 + chars[i + 3..].iter().position(|c| *c == '\'').unwrap_or(0) + 1,
+                (Some(_), Some('\'')) => i += 3,
+                _ => i += 1,
+            }
+        } else {
+            line += usize::from(chars[i] == '\n');
+            i += 1;
+        }
+    }
+    literals
+}
+
+/// Rust files under `dir` except the catalogs
+fn sources(dir: &Path, files: &mut Vec<std::path::PathBuf>) {
+    for entry in std::fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            if !path.ends_with("i18n") {
+                sources(&path, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+#[test]
+fn every_catalog_has_every_key() {
+    let reference: Vec<&str> = Language::Russian.catalog().iter().map(|(key, _)| *key).collect();
+    for language in Language::ALL {
+        let catalog = language.catalog();
+        assert_eq!(language.missing_keys(), Vec::<&str>::new(), "{:?} lacks texts", language);
+        
+        let mut seen = HashSet::new();
+        for (key, text) in catalog {
+            assert!(seen.insert(key), "{:?}: {} is there twice", language, key);
+            assert!(reference.contains(key), "{:?}: {} is not in the Russian catalog", language, key);
+            assert!(!text.trim().is_empty(), "{:?}: {} is empty", language, key);
+            assert_eq!(
+                placeholders(text),
+                placeholders(Language::Russian.tr(key)),
+                "{:?}: {} has other placeholders than in Russian", language, key,
+            );
+        }
+    }
+}
+
+#[test]
+fn every_key_in_the_code_has_a_text() {
+    let namespaces: HashSet<&str> = Language::Russian.catalog().iter()
+        .filter_map(|(key, _)| key.split('.').next())
+        .collect();
+    let mut keys = Vec::new();
+    keys_in_sources(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &namespaces, &mut keys);
+    assert!(keys.len() > 100, "the sources are read: {} keys", keys.len());
+    
+    let unknown: Vec<_> = keys.iter()
+        .filter(|(_, key)| Language::Russian.get(key).is_none())
+        .collect();
+    assert!(unknown.is_empty(), "keys without texts: {:?}", unknown);
+}
+
+#[test]
+fn interface_texts_are_only_in_the_catalogs() {
+    let mut files = Vec::new();
+    sources(&Path::new(env!("CARGO_MANIFEST_DIR")).join("src"), &mut files);
+    assert!(files.len() > 20, "the sources are read: {} files", files.len());
+    
+    let mut russian = Vec::new();
+    for path in files {
+        let source = std::fs::read_to_string(&path).unwrap();
+        for (line, literal) in string_literals(&source) {
+            let cyrillic = literal.chars().any(|c| matches!(c, 'А'..='я' | 'Ё' | 'ё'));
+            if cyrillic && !BOT_CONTENT.contains(&literal.as_str()) {
+                russian.push(format!("{}:{}: {}", path.display(), line, literal));
+            }
+        }
+    }
+    assert!(russian.is_empty(), "texts outside src/i18n:\n{}", russian.join("\n"));
+}
+
+#[test]
+fn missing_texts_fall_back_to_russian_and_then_to_the_key() {
+    const PARTIAL: Catalog = &[("menu.file", "File")];
+    assert_eq!(translate(PARTIAL, "menu.file"), "File");
+    assert_eq!(translate(PARTIAL, "menu.edit"), "Правка");
+    assert_eq!(translate(PARTIAL, "no.such.key"), "no.such.key");
+    
+    assert_eq!(Language::English.tr("menu.edit"), "Edit");
+    assert_eq!(Language::English.get("no.such.key"), None);
+    assert_eq!(Language::English.tr_args("workflow.zoom", &[("percent", &150)]), "Zoom: 150%");
+    assert_eq!(Language::Russian.tr_args("status.import_error", &[("error", &"нет данных")]), "Ошибка импорта: нет данных");
+}
+
+#[test]
+fn language_is_switched_at_runtime() {
+    let ctx = egui::Context::default();
+    assert_eq!(Language::current(&ctx), Language::Russian);
+    
+    Language::English.apply(&ctx);
+    assert_eq!(Language::current(&ctx), Language::English);
+    assert_eq!(Language::current(&ctx).tr("menu.file"), "File");
+    
+    Language::Russian.apply(&ctx);
+    assert_eq!(Language::current(&ctx).tr("menu.file"), "Файл");
+}
@@ -0,0 +1,56 @@
+use eframe::egui;
+use tg_menu_editor_wasm::graph::SimpleNodeGraph;
+use tg_menu_editor_wasm::graph::rendering::export::{ExportScope, GraphImage, MAX_PNG_SIDE};
+use tg_menu_editor_wasm::theme::Theme;
+
+fn sample_graph() -> SimpleNodeGraph {
+    let mut graph = SimpleNodeGraph::default();
+    let parent = graph.add_menu_item(egui::pos2(0.0, 0.0), "Абитуриенту <2025>".to_string());
+    let child = graph.add_menu_item(egui::pos2(0.0, 300.0), "Правила & сроки".to_string());
+    graph.add_faq_item(egui::pos2(400.0, 0.0), "Вопрос".to_string());
+    assert!(graph.connect_nodes(parent, "sub_menu", child, "parent_menu"));
+    graph
+}
+
+/// Width and height from the IHDR chunk of a PNG file
+fn png_size(png: &[u8]) -> (u32, u32) {
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[12..16], b"IHDR");
+    let number = |at: usize| u32::from_be_bytes(png[at..at + 4].try_into().unwrap());
+    (number(16), number(20))
+}
+
+#[test]
+fn graph_is_exported_without_a_window() {
+    let graph = sample_graph();
+    let image = GraphImage::new(&graph, ExportScope::All, Theme::Light).unwrap();
+    let size = image.size();
+    assert!(size.x > 400.0 && size.y > 300.0, "all nodes fit: {:?}", size);
+    
+    let svg = image.to_svg();
+    assert!(svg.starts_with("<svg"));
+    assert!(svg.contains("Абитуриенту &lt;2025&gt;"), "titles are escaped");
+    assert!(svg.contains("Правила &amp; сроки"));
+    // Three nodes with title bars and one connection
+    assert_eq!(svg.matches("<path").count(), 3 * 2 + 1);
+    
+    let png = image.to_png(2.0).unwrap();
+    assert_eq!(png_size(&png), ((size.x * 2.0).ceil() as u32, (size.y * 2.0).ceil() as u32));
+    
+    let too_large = MAX_PNG_SIDE as f32 / size.x.min(size.y) * 2.0;
+    assert!(image.to_png(too_large).is_err());
+}
+
+#[test]
+fn selection_is_exported_alone() {
+    let mut graph = sample_graph();
+    assert!(GraphImage::new(&graph, ExportScope::Selection, Theme::Dark).is_err(), "nothing is selected");
+    assert!(GraphImage::new(&SimpleNodeGraph::default(), ExportScope::All, Theme::Dark).is_err());
+    
+    let faq = graph.nodes()[2].id();
+    graph.select_node(faq, false);
+    let svg = GraphImage::new(&graph, ExportScope::Selection, Theme::Dark).unwrap().to_svg();
+    assert!(svg.contains("Вопрос"));
+    assert!(!svg.contains("Абитуриенту"));
+    assert_eq!(svg.matches("<path").count(), 2, "the connection of unselected nodes is left out");
+}
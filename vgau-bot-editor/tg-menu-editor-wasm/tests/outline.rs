@@ -0,0 +1,157 @@
+use eframe::egui;
+use tg_menu_editor_wasm::graph::SimpleNodeGraph;
+use tg_menu_editor_wasm::graph::models::NodeId;
+use tg_menu_editor_wasm::graph::outline::{DropPlace, Outline, OutlineKind, OutlineMove, OutlineRow, OutlineStep};
+
+struct Menu {
+    graph: SimpleNodeGraph,
+    students: NodeId,
+    schedule: NodeId,
+    grants: NodeId,
+    rules: NodeId,
+    applicants: NodeId,
+    faq: NodeId,
+    second_faq: NodeId,
+}
+
+/// Студентам › (Расписание, Стипендии, Правила.pdf), Абитуриентам, two FAQ items
+fn menu() -> Menu {
+    let mut graph = SimpleNodeGraph::default();
+    let students = graph.add_menu_item(egui::pos2(0.0, 0.0), "Студентам".to_string());
+    let schedule = graph.add_menu_item(egui::pos2(300.0, 0.0), "Расписание".to_string());
+    let grants = graph.add_menu_item(egui::pos2(300.0, 150.0), "Стипендии".to_string());
+    let rules = graph.add_document(egui::pos2(300.0, 300.0), "Правила.pdf".to_string()).unwrap();
+    let applicants = graph.add_menu_item(egui::pos2(0.0, 450.0), "Абитуриентам".to_string());
+    let faq = graph.add_faq_item(egui::pos2(0.0, 600.0), "Где деканат?".to_string());
+    let second_faq = graph.add_faq_item(egui::pos2(0.0, 750.0), "Когда сессия?".to_string());
+    assert!(graph.connect_nodes(students, "sub_menu", schedule, "parent_menu"));
+    assert!(graph.connect_nodes(students, "sub_menu", grants, "parent_menu"));
+    assert!(graph.connect_nodes(students, "documents", rules, "parent_menu"));
+    
+    Menu { graph, students, schedule, grants, rules, applicants, faq, second_faq }
+}
+
+fn ids(rows: &[OutlineRow]) -> Vec<NodeId> {
+    rows.iter().map(|row| row.node_id).collect()
+}
+
+fn row(rows: &[OutlineRow], node_id: NodeId) -> OutlineRow {
+    rows.iter().find(|row| row.node_id == node_id).cloned().unwrap()
+}
+
+#[test]
+fn rows_follow_the_menu_tree_and_the_keyboard() {
+    let Menu { graph, students, schedule, grants, rules, applicants, faq, second_faq } = menu();
+    let mut outline = Outline::new();
+    
+    let rows = outline.rows(&graph);
+    assert_eq!(ids(&rows), vec![students, schedule, grants, rules, applicants, faq, second_faq]);
+    assert_eq!(rows.iter().map(|row| row.depth).collect::<Vec<_>>(), vec![0, 1, 1, 1, 0, 0, 0]);
+    assert_eq!(rows[3].kind, OutlineKind::Document);
+    assert_eq!(rows[3].parent, Some(students));
+    assert_eq!((rows[4].index, rows[6].index), (1, 1));
+    assert_eq!(rows[6].kind, OutlineKind::Faq);
+    assert!(rows[0].expanded && !rows[4].has_children);
+    
+    // Down to the schedule, left to its parent, left again collapses it
+    let cursor = outline.step(&rows, 0, OutlineStep::Down);
+    assert_eq!(cursor, 1);
+    let cursor = outline.step(&rows, cursor, OutlineStep::Left);
+    assert_eq!(cursor, 0);
+    assert_eq!(outline.step(&rows, cursor, OutlineStep::Left), 0);
+    let rows = outline.rows(&graph);
+    assert_eq!(ids(&rows), vec![students, applicants, faq, second_faq]);
+    assert_eq!(outline.step(&rows, 0, OutlineStep::Last), 3);
+    assert_eq!(outline.step(&rows, 3, OutlineStep::Down), 3);
+    
+    // Right expands, right again goes to the first child
+    assert_eq!(outline.step(&rows, 0, OutlineStep::Right), 0);
+    let rows = outline.rows(&graph);
+    assert_eq!(outline.step(&rows, 0, OutlineStep::Right), 1);
+    
+    // A node picked on the canvas gets the menu items above it expanded
+    outline.set_expanded(students, false);
+    outline.reveal(&graph, rules);
+    assert!(outline.rows(&graph).iter().any(|row| row.node_id == rules));
+}
+
+#[test]
+fn dropped_rows_are_reordered_and_rewired() {
+    let Menu { mut graph, students, schedule, grants, rules, applicants, faq, second_faq } = menu();
+    let mut outline = Outline::new();
+    
+    // Below its sibling
+    let rows = outline.rows(&graph);
+    assert!(outline.drop_row(&mut graph, &row(&rows, schedule), &row(&rows, grants), DropPlace::After));
+    assert_eq!(graph.get_child_menu_nodes(students), vec![grants, schedule]);
+    
+    // Into another menu item, which has to be expanded to show it
+    let rows = outline.rows(&graph);
+    assert!(outline.drop_row(&mut graph, &row(&rows, rules), &row(&rows, applicants), DropPlace::Inside));
+    assert!(graph.get_documents_for_node(students).is_empty());
+    assert_eq!(graph.get_documents_for_node(applicants), vec![rules]);
+    assert_eq!(row(&outline.rows(&graph), rules).parent, Some(applicants));
+    assert_eq!(graph.connections().len(), 3, "the old connection is replaced");
+    
+    // A submenu to the top level, before the first item
+    let rows = outline.rows(&graph);
+    assert!(outline.drop_row(&mut graph, &row(&rows, grants), &row(&rows, students), DropPlace::Before));
+    assert_eq!(graph.get_root_menu_nodes(), vec![grants, students, applicants]);
+    assert_eq!(graph.get_child_menu_nodes(students), vec![schedule]);
+    
+    // Each drop is one undo step
+    assert!(graph.undo());
+    assert_eq!(graph.get_root_menu_nodes(), vec![students, applicants]);
+    assert_eq!(graph.get_child_menu_nodes(students), vec![grants, schedule]);
+    
+    // FAQ items only move among themselves
+    let rows = outline.rows(&graph);
+    assert!(outline.drop_row(&mut graph, &row(&rows, faq), &row(&rows, second_faq), DropPlace::After));
+    assert_eq!(graph.get_faq_nodes(), vec![second_faq, faq]);
+    let rows = outline.rows(&graph);
+    assert!(!outline.drop_row(&mut graph, &row(&rows, faq), &row(&rows, students), DropPlace::Inside));
+    
+    // Nothing goes below itself and documents need a menu item
+    assert!(!outline.drop_row(&mut graph, &row(&rows, students), &row(&rows, schedule), DropPlace::Inside));
+    assert!(!outline.drop_row(&mut graph, &row(&rows, rules), &row(&rows, students), DropPlace::Before));
+    assert!(!graph.move_in_tree(students, None, Some(students), 0));
+    assert_eq!(graph.get_root_menu_nodes(), vec![students, applicants]);
+}
+
+#[test]
+fn keyboard_moves_change_order_and_level() {
+    let Menu { mut graph, students, schedule, grants, applicants, .. } = menu();
+    let mut outline = Outline::new();
+    let cursor = |rows: &[OutlineRow], node_id: NodeId| rows.iter().position(|row| row.node_id == node_id).unwrap();
+    
+    let rows = outline.rows(&graph);
+    assert!(outline.move_row(&mut graph, &rows, cursor(&rows, grants), OutlineMove::Up));
+    assert_eq!(graph.get_child_menu_nodes(students), vec![grants, schedule]);
+    let rows = outline.rows(&graph);
+    assert!(!outline.move_row(&mut graph, &rows, cursor(&rows, grants), OutlineMove::Up), "already first");
+    
+    // Out of the menu item, right after it
+    assert!(outline.move_row(&mut graph, &rows, cursor(&rows, grants), OutlineMove::Outdent));
+    assert_eq!(graph.get_root_menu_nodes(), vec![students, grants, applicants]);
+    
+    // Into the menu item above, as its last child
+    let rows = outline.rows(&graph);
+    assert!(outline.move_row(&mut graph, &rows, cursor(&rows, applicants), OutlineMove::Indent));
+    assert_eq!(graph.get_child_menu_nodes(grants), vec![applicants]);
+    assert_eq!(graph.get_root_menu_nodes(), vec![students, grants]);
+    let rows = outline.rows(&graph);
+    assert_eq!(row(&rows, applicants).depth, 1);
+    assert!(!outline.move_row(&mut graph, &rows, cursor(&rows, students), OutlineMove::Indent), "nothing above it");
+}
+
+#[test]
+fn view_is_only_panned_to_nodes_out_of_sight() {
+    let Menu { mut graph, students, .. } = menu();
+    let far = graph.add_menu_item(egui::pos2(3000.0, 3000.0), "Далеко".to_string());
+    
+    assert!(!graph.scroll_to_node(students));
+    assert!(graph.scroll_to_node(far));
+    let far_rect = graph.get_node_data(far).unwrap().rect();
+    assert!(graph.visible_rect().contains_rect(far_rect));
+    assert!(!graph.scroll_to_node(far));
+}
@@ -0,0 +1,67 @@
+use eframe::egui;
+use tg_menu_editor_wasm::graph::models::{NodeType, PortType};
+use tg_menu_editor_wasm::theme::{Palette, Theme};
+
+const NODE_TYPES: [NodeType; 10] = [
+    NodeType::MenuItem,
+    NodeType::FaqItem,
+    NodeType::Process,
+    NodeType::Input,
+    NodeType::Output,
+    NodeType::Document,
+    NodeType::Image,
+    NodeType::Link,
+    NodeType::TextContent,
+    NodeType::Contacts,
+];
+
+const PORT_TYPES: [PortType; 4] = [PortType::String, PortType::Number, PortType::Object, PortType::Action];
+
+/// WCAG contrast ratio of two opaque colours
+fn contrast(a: egui::Color32, b: egui::Color32) -> f32 {
+    let luminance = |c: egui::Color32| {
+        let rgba = egui::Rgba::from(c);
+        0.2126 * rgba.r() + 0.7152 * rgba.g() + 0.0722 * rgba.b()
+    };
+    let (a, b) = (luminance(a), luminance(b));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+#[test]
+fn every_theme_keeps_text_and_colours_apart() {
+    for theme in Theme::ALL {
+        let palette = theme.palette();
+        assert_eq!(theme.visuals().dark_mode, palette.dark, "{:?}", theme);
+        assert!(contrast(palette.text, palette.canvas) >= 4.5, "{:?}: text on the canvas", theme);
+        assert!(contrast(palette.text, palette.panel) >= 4.5, "{:?}: text on panels", theme);
+        
+        let nodes: Vec<_> = NODE_TYPES.iter().map(|t| palette.node_color(t)).collect();
+        let ports: Vec<_> = PORT_TYPES.iter().map(|t| palette.port_color(t)).collect();
+        for colors in [&nodes, &ports] {
+            for (i, a) in colors.iter().enumerate() {
+                assert!(!colors[i + 1..].contains(a), "{:?}: {:?} is used twice", theme, a);
+            }
+        }
+        for node in &nodes {
+            assert!(contrast(Palette::text_on(*node), *node) >= 4.5, "{:?}: title on {:?}", theme, node);
+        }
+    }
+    
+    let high_contrast = Theme::HighContrast.palette();
+    assert!(high_contrast.connection_width > Theme::Dark.palette().connection_width);
+    assert!(contrast(high_contrast.text, high_contrast.canvas) > 20.0, "white on black");
+}
+
+#[test]
+fn applied_theme_is_what_the_graph_is_drawn_with() {
+    let ctx = egui::Context::default();
+    assert_eq!(Theme::current(&ctx), Theme::Dark);
+    
+    Theme::Light.apply(&ctx);
+    assert_eq!(Theme::current(&ctx), Theme::Light);
+    assert!(!ctx.style().visuals.dark_mode);
+    
+    Theme::HighContrast.apply(&ctx);
+    assert_eq!(Theme::current(&ctx), Theme::HighContrast);
+    assert!(ctx.style().visuals.dark_mode);
+}